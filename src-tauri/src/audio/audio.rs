@@ -1,10 +1,12 @@
 use crate::audio::clean_recording::strip_and_record;
 use crate::audio::helpers::{cleanup_recordings, ensure_recordings_dir, generate_unique_wav_name};
 use crate::audio::recorder::AudioRecorder;
+use crate::audio::system_audio::{labelled_transcript, remote_wav_path, speaker_turns};
 use crate::audio::types::{AudioState, RecorderStartError, RecordingMode, RecordingTrigger};
 use crate::audio::ChunkPipeline;
 use crate::clipboard;
-use crate::engine::ParakeetEngine;
+use crate::engine::transcription_engine::TranscriptionWord;
+use crate::http_api::events::publish_event;
 use crate::model::Model;
 use crate::overlay::overlay;
 use anyhow::Result;
@@ -12,6 +14,11 @@ use log::{debug, error, info, warn};
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager};
 
+/// How long the overlay shows "did you mean" after a dictation.
+const ALTERNATIVES_DISPLAY_MS: u64 = 3000;
+/// Words the overlay suggests alternatives for, at most.
+const OVERLAY_ALTERNATIVE_WORDS: usize = 2;
+
 pub fn record_audio(app: &AppHandle, mode: RecordingMode) {
     let state = app.state::<AudioState>();
     let my_gen = state.begin_session();
@@ -24,10 +31,16 @@ pub fn record_audio(app: &AppHandle, mode: RecordingMode) {
         crate::llm::warmup_ollama_model_background(app);
     }
 
+    warm_engine_in_background(app);
+
     let settings = crate::settings::load_settings(app);
     let preview =
         crate::audio::chunking::PreviewLink::from_state(&state, settings.streaming_preview);
     *state.chunk_pipeline.lock() = Some(ChunkPipeline::start(app, preview));
+    // The system side has no preview: the overlay follows the microphone.
+    *state.remote_pipeline.lock() = settings
+        .dual_capture
+        .then(|| ChunkPipeline::start_headless(app, Arc::default(), Arc::default(), Vec::new()));
 
     internal_record_audio(app, my_gen);
 }
@@ -47,11 +60,17 @@ fn internal_record_audio(app: &AppHandle, my_gen: u64) {
                 overlay::show_recording_overlay(app);
             }
             crate::overlay::tray::set_tray_recording(app);
+            publish_event(
+                app,
+                "recording-started",
+                &serde_json::json!({ "mode": state.get_recording_mode().as_str() }),
+            );
             crate::audio::streaming::start_streaming(app, &state, sample_rate);
         }
         Err(RecorderStartError::InitFailed) => notify_recording_error(app),
         Err(RecorderStartError::Superseded) => {
             let _ = state.chunk_pipeline.lock().take();
+            let _ = state.remote_pipeline.lock().take();
             debug!("Recorder start superseded by a concurrent stop; aborted");
         }
         Err(_) => {}
@@ -123,10 +142,16 @@ fn show_recording_notification(app: &AppHandle, event: &'static str, payload: St
 fn notify_recording_error(app: &AppHandle) {
     let s = crate::settings::load_settings(app);
     let mic_name = s.mic_label.or(s.mic_id).unwrap_or_default();
+    publish_event(
+        app,
+        "recording-error",
+        &serde_json::json!({ "microphone": mic_name }),
+    );
     show_recording_notification(app, "recording-error", mic_name);
 }
 
 pub fn notify_recording_limit(app: &AppHandle) {
+    publish_event(app, "recording-limit-reached", &serde_json::json!({}));
     show_recording_notification(app, "recording-limit-reached", String::new());
 }
 
@@ -134,6 +159,8 @@ pub fn stop_recording(app: &AppHandle) -> Option<std::path::PathBuf> {
     debug!("Stopping audio recording...");
     let state = app.state::<AudioState>();
     state.invalidate_session();
+    publish_event(app, "recording-stopped", &serde_json::json!({}));
+    let _ = app.emit("recording-paused", None::<u64>);
 
     crate::audio::sound::prewarm(app);
 
@@ -192,16 +219,42 @@ fn finalize_chunked_session(
 ) {
     let mode = state.get_recording_mode();
     let _ = app.emit("llm-processing-start", ());
-    let accumulated = pipeline.finalize();
+    let (accumulated, words) = pipeline.finalize_with_words();
+    let accumulated = match state.remote_pipeline.lock().take() {
+        Some(remote) => {
+            let (_, remote_words) = remote.finalize_with_words();
+            labelled_transcript(app, &speaker_turns(words.clone(), remote_words))
+        }
+        None => accumulated,
+    };
     let _ = app.emit("llm-processing-end", ());
 
     match crate::audio::pipeline::merge_all_chunks(app, accumulated, path, mode) {
         Ok(result) => {
             let text = strip_and_record(app, state, result.text);
+            let suggestions = overlay_alternatives(&words, &text);
+            if !suggestions.is_empty() {
+                let _ = app.emit("transcription-alternatives", &suggestions);
+            }
+            // Words the model hesitated on, for "did you mean" suggestions.
+            // They come from the raw decode, before post-processing.
+            let alternatives: Vec<_> = words
+                .into_iter()
+                .filter(|word| !word.alternatives.is_empty())
+                .collect();
+            publish_event(
+                app,
+                "transcription",
+                &serde_json::json!({
+                    "text": text,
+                    "mode": mode.as_str(),
+                    "alternatives": alternatives,
+                }),
+            );
             if let Err(e) = write_transcription(app, &text) {
                 error!("Failed to use clipboard: {}", e);
             }
-            finish_recording_ui(app, result.llm_error);
+            finish_recording_ui(app, result.llm_error, !suggestions.is_empty());
         }
         Err(e) => {
             error!("Finalize failed: {}", e);
@@ -210,20 +263,84 @@ fn finalize_chunked_session(
     }
 }
 
-fn finish_recording_ui(app: &AppHandle, llm_error: Option<String>) {
+/// The overlay's "did you mean" line for a dictation: the least confident
+/// words that have alternatives and still read as is in the final text, so a
+/// word the corrections or the LLM rewrote is not offered.
+fn overlay_alternatives(words: &[TranscriptionWord], text: &str) -> Vec<serde_json::Value> {
+    let final_words: Vec<&str> = text.split_whitespace().collect();
+    let mut hesitant: Vec<&TranscriptionWord> = words
+        .iter()
+        .filter(|word| !word.alternatives.is_empty() && final_words.contains(&word.text.as_str()))
+        .collect();
+    hesitant.sort_by(|a, b| {
+        a.confidence
+            .partial_cmp(&b.confidence)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    hesitant
+        .into_iter()
+        .take(OVERLAY_ALTERNATIVE_WORDS)
+        .map(|word| {
+            let alternatives: Vec<&str> =
+                word.alternatives.iter().map(|a| a.word.as_str()).collect();
+            serde_json::json!({ "word": word.text, "alternatives": alternatives })
+        })
+        .collect()
+}
+
+/// `show_alternatives` keeps the overlay up while it shows the suggestions.
+fn finish_recording_ui(app: &AppHandle, llm_error: Option<String>, show_alternatives: bool) {
     match llm_error {
         Some(llm_err) => {
+            publish_event(app, "llm-error", &serde_json::json!({ "message": llm_err }));
             let _ = app.emit("llm-error", llm_err);
             reset_recording_ui_delayed(app, 3000);
         }
+        None if show_alternatives => reset_recording_ui_delayed(app, ALTERNATIVES_DISPLAY_MS),
         None => reset_recording_ui(app),
     }
 }
 
+/// Pause the current recording, or resume it when paused. The session stays
+/// open: stopping while paused finalizes it as usual.
+pub fn toggle_pause_recording(app: &AppHandle) {
+    let state = app.state::<AudioState>();
+    let (paused, recorded) = {
+        let mut recorder_guard = state.recorder.lock();
+        let Some(recorder) = recorder_guard.as_mut() else {
+            debug!("Pause ignored: not recording");
+            return;
+        };
+        let paused = !recorder.is_paused();
+        if paused {
+            recorder.pause();
+        } else {
+            recorder.resume();
+        }
+        (paused, recorder.recorded_duration())
+    };
+
+    let event = if paused {
+        "recording-paused"
+    } else {
+        "recording-resumed"
+    };
+    let elapsed_ms = recorded.as_millis() as u64;
+    info!("{} after {} ms of recording", event, elapsed_ms);
+    publish_event(app, event, &serde_json::json!({ "elapsed_ms": elapsed_ms }));
+    // The overlay gets the recorded time while paused, null otherwise.
+    let _ = app.emit("recording-paused", paused.then_some(elapsed_ms));
+    if paused {
+        let _ = app.emit("mic-level", 0.0f32);
+    }
+}
+
 pub fn cancel_recording(app: &AppHandle) {
     info!("Cancelling audio recording...");
     let state = app.state::<AudioState>();
     state.invalidate_session();
+    publish_event(app, "recording-cancelled", &serde_json::json!({}));
+    let _ = app.emit("recording-paused", None::<u64>);
 
     crate::audio::sound::prewarm(app);
     crate::audio::streaming::stop_streaming(app, &state);
@@ -244,6 +361,13 @@ pub fn cancel_recording(app: &AppHandle) {
     if let Some(pipeline) = state.chunk_pipeline.lock().take() {
         pipeline.cancel();
     }
+    let dual_capture = match state.remote_pipeline.lock().take() {
+        Some(pipeline) => {
+            pipeline.cancel();
+            true
+        }
+        None => false,
+    };
 
     // Remove temporary WAV file
     let file_name_opt = state.current_file_name.lock().take();
@@ -253,6 +377,9 @@ pub fn cancel_recording(app: &AppHandle) {
             if let Err(e) = std::fs::remove_file(&path) {
                 error!("Failed to remove cancelled recording file: {}", e);
             }
+            if dual_capture {
+                let _ = std::fs::remove_file(remote_wav_path(&path));
+            }
         }
     }
 
@@ -351,22 +478,54 @@ pub fn write_last_transcription(app: &AppHandle, transcription: &str) -> Result<
     Ok(())
 }
 
+/// Load the engine if it is not loaded yet. The overlay shows a "warming
+/// up" state through `model-warming-start` and `model-warming-end`.
 pub fn preload_engine(app: &AppHandle) -> Result<()> {
     let state = app.state::<AudioState>();
     let mut engine = state.engine.lock();
 
     if engine.is_none() {
-        let model = app.state::<Arc<Model>>();
-        let model_path = model
-            .get_model_path()
-            .map_err(|e| anyhow::anyhow!("Failed to get model path: {}", e))?;
-
-        let new_engine = ParakeetEngine::load_int8(&model_path, model.get_tokenizer_path())
-            .map_err(|e| anyhow::anyhow!("Failed to load model: {}", e))?;
+        state.set_engine_warming(true);
+        let _ = app.emit("model-warming-start", ());
+        let loaded = app.state::<Arc<Model>>().load_engine();
+        state.set_engine_warming(false);
+        let _ = app.emit("model-warming-end", ());
 
-        *engine = Some(new_engine);
+        *engine = Some(loaded?);
         info!("Model loaded and cached in memory");
     }
+    state.touch_engine();
 
     Ok(())
 }
+
+// A recording is coming: reload an engine dropped by the idle unload now,
+// so it is ready, or close to, when the recording stops.
+fn warm_engine_in_background(app: &AppHandle) {
+    let state = app.state::<AudioState>();
+    state.touch_engine();
+    // A held lock means the engine is in use or already loading.
+    let unloaded = state
+        .engine
+        .try_lock()
+        .is_some_and(|engine| engine.is_none());
+    if unloaded {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            if let Err(e) = preload_engine(&app) {
+                warn!("Failed to load the speech model: {}", e);
+            }
+        });
+    }
+}
+
+/// Drop the loaded engine and load the active model in the background.
+pub fn reload_engine(app: &AppHandle) {
+    *app.state::<AudioState>().engine.lock() = None;
+    let app = app.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = preload_engine(&app) {
+            warn!("Failed to load the speech model: {}", e);
+        }
+    });
+}
@@ -1,6 +1,9 @@
 use crate::audio::pipeline::{process_chunk, ChunkOutcome};
-use crate::audio::types::{AudioState, PreviewSnapshot};
-use crate::audio::vad::{AdaptiveVad, VoiceActivity};
+use crate::audio::timestamps::offset_words;
+use crate::audio::types::{AudioState, ChunkProgress, PreviewSnapshot};
+use crate::audio::vad::{VoiceActivity, VoiceDetector};
+use crate::engine::transcription_engine::TranscriptionWord;
+use crate::engine::AsrBackend;
 use crate::formatting_rules;
 use crate::formatting_rules::highlighter::{
     apply_formatting_with_highlights_and_original, HighlightRange,
@@ -18,15 +21,14 @@ use tauri::{AppHandle, Emitter, Manager};
 const MERGE_WINDOW_WORDS: usize = 6;
 
 const PREVIEW_FIRST_TICK_SECS: u32 = 1;
+// The preview only decodes the chunk from its last pause on, so ticks stay
+// cheap on long chunks and need no backoff.
 const PREVIEW_TICK_INTERVAL_SECS: u32 = 2;
-const PREVIEW_TICK_INTERVAL_LONG_SECS: u32 = 10;
-const PREVIEW_TICK_BACKOFF_THRESHOLD_SECS: u32 = 15;
 
 fn next_preview_tick_secs(last_tick: u32) -> u32 {
     match last_tick {
         0 => PREVIEW_FIRST_TICK_SECS,
-        t if t < PREVIEW_TICK_BACKOFF_THRESHOLD_SECS => t + PREVIEW_TICK_INTERVAL_SECS,
-        t => t + PREVIEW_TICK_INTERVAL_LONG_SECS,
+        t => t + PREVIEW_TICK_INTERVAL_SECS,
     }
 }
 
@@ -58,6 +60,11 @@ impl PreviewLink {
 struct PreviewObserver {
     snapshot: Arc<PlMutex<PreviewSnapshot>>,
     last_tick_secs: u32,
+    /// Silence since the last voiced tick, counted even before the chunk is
+    /// armed.
+    pause_run: usize,
+    /// End of the last pause long enough to cut a chunk, in chunk samples.
+    freeze_point: usize,
 }
 
 /// Default arm length: once the current chunk reaches this, a detected silence cuts it.
@@ -66,6 +73,9 @@ const CHUNK_SILENCE_ARM_SECS: u32 = 15;
 const CHUNK_SILENCE_CUT_MS: u64 = 500;
 /// Hard cut applied when no silence has been detected by this length.
 const CHUNK_FORCE_CUT_SECS: u32 = 60;
+/// Whisper hears 30 s windows, so its chunks are cut earlier, leaving room
+/// for the inference padding: a word is never split across two windows.
+const WHISPER_FORCE_CUT_SECS: u32 = crate::engine::whisper::WINDOW_SECS - 2;
 /// Tail kept as the next chunk's head so a word straddling a forced cut can be deduped.
 const CHUNK_FORCED_OVERLAP_SECS: f32 = 1.0;
 
@@ -75,6 +85,8 @@ pub enum ChunkJob {
         samples: Vec<f32>,
         sample_rate: u32,
         overlap_prefix: usize,
+        /// Position of the chunk's first sample in the session, in samples.
+        offset: usize,
     },
     Finalize,
 }
@@ -87,6 +99,7 @@ static PIPELINE_EPOCH: AtomicU64 = AtomicU64::new(0);
 pub struct ChunkPipeline {
     tx: Sender<ChunkJob>,
     accumulated: Arc<Mutex<String>>,
+    words: Arc<Mutex<Vec<TranscriptionWord>>>,
     worker: Option<JoinHandle<()>>,
     cancelled: Arc<AtomicBool>,
 }
@@ -94,11 +107,25 @@ pub struct ChunkPipeline {
 impl ChunkPipeline {
     pub fn start(app: &AppHandle, preview: Option<PreviewLink>) -> Self {
         let epoch = PIPELINE_EPOCH.fetch_add(1, Ordering::SeqCst) + 1;
-        Self::start_inner(app, preview, Some(epoch), Arc::new(AtomicBool::new(false)))
+        Self::start_inner(
+            app,
+            preview,
+            Some(epoch),
+            Arc::new(AtomicBool::new(false)),
+            None,
+            Vec::new(),
+        )
     }
 
-    pub fn start_headless(app: &AppHandle, cancelled: Arc<AtomicBool>) -> Self {
-        Self::start_inner(app, None, None, cancelled)
+    /// File transcription pipeline: no preview, no UI events. `boost_words`
+    /// are added to the dictionary for this pipeline's chunks only.
+    pub fn start_headless(
+        app: &AppHandle,
+        cancelled: Arc<AtomicBool>,
+        progress: Arc<ChunkProgress>,
+        boost_words: Vec<String>,
+    ) -> Self {
+        Self::start_inner(app, None, None, cancelled, Some(progress), boost_words)
     }
 
     fn start_inner(
@@ -106,20 +133,27 @@ impl ChunkPipeline {
         preview: Option<PreviewLink>,
         epoch: Option<u64>,
         cancelled: Arc<AtomicBool>,
+        progress: Option<Arc<ChunkProgress>>,
+        boost_words: Vec<String>,
     ) -> Self {
         let (tx, rx) = mpsc::channel::<ChunkJob>();
         let accumulated = Arc::new(Mutex::new(String::new()));
+        let words = Arc::new(Mutex::new(Vec::new()));
         let worker = spawn_worker(
             app.clone(),
             rx,
             accumulated.clone(),
+            words.clone(),
             preview,
             cancelled.clone(),
             epoch,
+            progress,
+            boost_words,
         );
         Self {
             tx,
             accumulated,
+            words,
             worker: Some(worker),
             cancelled,
         }
@@ -139,27 +173,40 @@ impl ChunkPipeline {
         }
     }
 
-    pub fn finalize(mut self) -> String {
+    /// Wait for the queued chunks and return the accumulated text with the word
+    /// timings on the session timeline.
+    pub fn finalize_with_words(mut self) -> (String, Vec<TranscriptionWord>) {
         self.submit(ChunkJob::Finalize);
         if let Some(handle) = self.worker.take() {
             if handle.join().is_err() {
                 error!("Chunk pipeline: worker thread panicked, returning text accumulated so far");
             }
         }
-        self.accumulated
+        let text = self
+            .accumulated
             .lock()
             .map(|guard| guard.clone())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        let words = self
+            .words
+            .lock()
+            .map(|mut guard| std::mem::take(&mut *guard))
+            .unwrap_or_default();
+        (text, words)
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_worker(
     app: AppHandle,
     rx: Receiver<ChunkJob>,
     accumulated: Arc<Mutex<String>>,
+    words: Arc<Mutex<Vec<TranscriptionWord>>>,
     preview: Option<PreviewLink>,
     cancelled: Arc<AtomicBool>,
     epoch: Option<u64>,
+    progress: Option<Arc<ChunkProgress>>,
+    boost_words: Vec<String>,
 ) -> JoinHandle<()> {
     std::thread::spawn(move || {
         let freeze_settings = preview.as_ref().map(|_| load_formatting_settings(&app));
@@ -175,6 +222,7 @@ fn spawn_worker(
                     samples,
                     sample_rate,
                     overlap_prefix,
+                    offset,
                 } => {
                     debug_assert_eq!(seq, expected_seq, "chunk seq must be monotonic");
                     expected_seq = expected_seq.saturating_add(1);
@@ -187,19 +235,28 @@ fn spawn_worker(
                     if let Some(link) = preview.as_ref() {
                         link.inference_active.store(true, Ordering::SeqCst);
                     }
-                    let outcome = process_chunk(&app, samples, sample_rate);
+                    let outcome = process_chunk(&app, samples, sample_rate, &boost_words);
                     if let Some(link) = preview.as_ref() {
                         link.inference_active.store(false, Ordering::SeqCst);
                     }
 
-                    let (cleaned_text, corrected_text) = match outcome {
-                        ChunkOutcome::Text { cleaned, corrected } => (cleaned, corrected),
-                        ChunkOutcome::Empty => (String::new(), String::new()),
+                    let (cleaned_text, corrected_text, chunk_words) = match outcome {
+                        ChunkOutcome::Text {
+                            cleaned,
+                            corrected,
+                            words,
+                        } => (cleaned, corrected, words),
+                        ChunkOutcome::Empty => (String::new(), String::new(), Vec::new()),
                         ChunkOutcome::Failed => {
                             if owns_ui() {
+                                crate::http_api::events::publish_event(
+                                    &app,
+                                    "transcription-chunk-error",
+                                    &serde_json::json!({}),
+                                );
                                 let _ = app.emit("transcription-chunk-error", ());
                             }
-                            (String::new(), String::new())
+                            (String::new(), String::new(), Vec::new())
                         }
                     };
 
@@ -212,6 +269,13 @@ fn spawn_worker(
 
                     let delta =
                         merge_chunk(&accumulated, &cleaned_text, &corrected_text, overlap_prefix);
+                    if !delta.corrected.trim().is_empty() {
+                        let offset_secs = offset as f32 / sample_rate.max(1) as f32;
+                        merge_words(&words, chunk_words, overlap_prefix > 0, offset_secs);
+                    }
+                    if let Some(progress) = progress.as_ref() {
+                        progress.done.fetch_add(1, Ordering::SeqCst);
+                    }
                     let trimmed_corrected = delta.corrected.trim();
                     if !trimmed_corrected.is_empty() && owns_ui() {
                         if let Some(settings) = freeze_settings.as_ref() {
@@ -260,6 +324,7 @@ fn emit_freeze_segment(
         text: formatted.text,
         highlights: formatted.highlights,
     };
+    crate::http_api::events::publish_event(app, "freeze-segment", &payload);
     if let Some(window) = app.get_webview_window("recording_overlay") {
         let _ = window.emit("freeze-segment", &payload);
     }
@@ -270,6 +335,32 @@ struct ChunkDelta {
     cleaned: String,
 }
 
+/// Appends a chunk's words to the session timeline. With `dedupe`, the head
+/// words repeating the timeline's tail are skipped. The overlap is matched on
+/// the raw words, as dictionary corrections may change the word count of the
+/// text.
+fn merge_words(
+    words: &Arc<Mutex<Vec<TranscriptionWord>>>,
+    chunk_words: Vec<TranscriptionWord>,
+    dedupe: bool,
+    offset_secs: f32,
+) {
+    let Ok(mut acc) = words.lock() else {
+        error!("Chunk pipeline: word timings mutex poisoned, dropping chunk timings");
+        return;
+    };
+    let dropped_head = if dedupe {
+        let tail = &acc[acc.len().saturating_sub(MERGE_WINDOW_WORDS)..];
+        let acc_words: Vec<&str> = tail.iter().map(|w| w.text.as_str()).collect();
+        let new_words: Vec<&str> = chunk_words.iter().map(|w| w.text.as_str()).collect();
+        overlap_len(&acc_words, &new_words)
+    } else {
+        0
+    };
+    let kept = chunk_words.into_iter().skip(dropped_head).collect();
+    acc.extend(offset_words(kept, offset_secs));
+}
+
 fn merge_chunk(
     accumulated: &Arc<Mutex<String>>,
     cleaned_text: &str,
@@ -320,25 +411,25 @@ fn remove_overlap_duplication(cumulated: &str, new_chunk: &str) -> (String, usiz
     }
 
     let acc_words: Vec<&str> = cumulated.split_whitespace().collect();
-    let max_overlap = MERGE_WINDOW_WORDS.min(new_words.len()).min(acc_words.len());
-
-    let mut matched = 0;
-    for len in (1..=max_overlap).rev() {
-        let acc_tail = &acc_words[acc_words.len() - len..];
-        let new_head = &new_words[..len];
-        let tails_match = acc_tail
-            .iter()
-            .zip(new_head.iter())
-            .all(|(a, b)| normalize_text(a) == normalize_text(b));
-        if tails_match {
-            matched = len;
-            break;
-        }
-    }
-
+    let matched = overlap_len(&acc_words, &new_words);
     (new_words[matched..].join(" "), matched)
 }
 
+/// Longest head of `new_words` repeating the tail of `acc_words`, within
+/// MERGE_WINDOW_WORDS.
+fn overlap_len(acc_words: &[&str], new_words: &[&str]) -> usize {
+    let max_overlap = MERGE_WINDOW_WORDS.min(new_words.len()).min(acc_words.len());
+    (1..=max_overlap)
+        .rev()
+        .find(|&len| {
+            acc_words[acc_words.len() - len..]
+                .iter()
+                .zip(&new_words[..len])
+                .all(|(a, b)| normalize_text(a) == normalize_text(b))
+        })
+        .unwrap_or(0)
+}
+
 fn drop_head_words(text: &str, count: usize) -> String {
     if count == 0 {
         return text.trim().to_string();
@@ -363,9 +454,11 @@ pub(super) struct Chunker {
     silence_run: usize,
     last_tick_len: usize,
     seq: u64,
+    /// Session position of `samples[0]`, carried by each job as its offset.
+    chunk_start: usize,
     samples: Vec<f32>,
     overlap_prefix: usize,
-    vad: AdaptiveVad,
+    vad: VoiceDetector,
     preview: Option<PreviewObserver>,
 }
 
@@ -374,30 +467,50 @@ impl Chunker {
         tx: Sender<ChunkJob>,
         sample_rate: u32,
         preview: Option<PreviewLink>,
+        vad: VoiceDetector,
+        backend: AsrBackend,
     ) -> Self {
         let sr = sample_rate as usize;
+        let force_cut_secs = match backend {
+            AsrBackend::Parakeet => CHUNK_FORCE_CUT_SECS,
+            AsrBackend::Whisper => WHISPER_FORCE_CUT_SECS,
+        };
         Self {
             tx,
             sample_rate,
             arm_samples: CHUNK_SILENCE_ARM_SECS as usize * sr,
-            force_samples: CHUNK_FORCE_CUT_SECS as usize * sr,
+            force_samples: force_cut_secs as usize * sr,
             overlap_samples: (CHUNK_FORCED_OVERLAP_SECS * sample_rate as f32) as usize,
             silence_cut_samples: (CHUNK_SILENCE_CUT_MS as usize * sr / 1000).max(1),
             silence_run: 0,
             last_tick_len: 0,
             seq: 0,
+            chunk_start: 0,
             samples: Vec::new(),
             overlap_prefix: 0,
-            vad: AdaptiveVad::new(),
+            vad,
             preview: preview.map(|link| PreviewObserver {
                 snapshot: link.snapshot,
                 last_tick_secs: 0,
+                pause_run: 0,
+                freeze_point: 0,
             }),
         }
     }
 
     pub(super) fn push_samples(&mut self, mono: &[f32]) {
         self.samples.extend_from_slice(mono);
+        self.vad.push_samples(mono);
+    }
+
+    pub(super) fn vad(&self) -> &VoiceDetector {
+        &self.vad
+    }
+
+    /// Speech probability the chunker read on this tick, for a detector
+    /// that follows its model.
+    pub(super) fn take_speech_probability(&mut self) -> Option<f32> {
+        self.vad.take_last_probability()
     }
 
     pub(super) fn on_throttle_tick(&mut self, rms: f32) {
@@ -406,17 +519,17 @@ impl Chunker {
             return;
         }
 
-        self.update_preview_snapshot();
         let voice_activity = self.vad.update(rms);
+        let delta = self.samples.len().saturating_sub(self.last_tick_len);
+        self.last_tick_len = self.samples.len();
+
+        self.track_freeze_point(voice_activity, delta);
+        self.update_preview_snapshot();
 
         if self.samples.len() < self.arm_samples {
-            self.last_tick_len = self.samples.len();
             return;
         }
 
-        let delta = self.samples.len().saturating_sub(self.last_tick_len);
-        self.last_tick_len = self.samples.len();
-
         match voice_activity {
             VoiceActivity::Silent => {
                 self.silence_run += delta;
@@ -429,6 +542,24 @@ impl Chunker {
         }
     }
 
+    /// A pause that would cut an armed chunk is where the chunk would have
+    /// been frozen: the preview commits the words that end before it.
+    fn track_freeze_point(&mut self, voice_activity: VoiceActivity, delta: usize) {
+        let Some(observer) = self.preview.as_mut() else {
+            return;
+        };
+        match voice_activity {
+            VoiceActivity::Silent => {
+                observer.pause_run += delta;
+                if observer.pause_run >= self.silence_cut_samples {
+                    observer.freeze_point = self.samples.len();
+                }
+            }
+            VoiceActivity::Active => observer.pause_run = 0,
+            VoiceActivity::NotStarted => {}
+        }
+    }
+
     fn update_preview_snapshot(&mut self) {
         let Some(observer) = self.preview.as_mut() else {
             return;
@@ -442,6 +573,7 @@ impl Chunker {
             let mut snapshot = observer.snapshot.lock();
             snapshot.queue = self.samples.clone();
             snapshot.generation = self.seq;
+            snapshot.freeze_point = observer.freeze_point;
             snapshot.revision = snapshot.revision.saturating_add(1);
             observer.last_tick_secs = next_tick;
         }
@@ -473,32 +605,60 @@ impl Chunker {
         let prefix = self.overlap_prefix;
         self.emit(samples, prefix);
         // The retained tail becomes both the next chunk's head and its overlap.
+        self.chunk_start -= tail.len();
         self.overlap_prefix = tail.len();
         self.samples = tail;
         self.reset_silence_state();
     }
 
-    pub(super) fn flush_remaining(mut self) {
+    /// Cut where the recording is paused, so the audio on either side of the
+    /// pause is never decoded as one stretch. A chunk holding only the overlap
+    /// of a forced cut is kept as the head of the next one.
+    pub(super) fn cut_at_pause(&mut self) {
+        if self.samples.len() <= self.overlap_prefix {
+            return;
+        }
+        let samples = std::mem::take(&mut self.samples);
+        debug!(
+            "Standard chunking: pause cut at {:.1}s ({} samples, seq {})",
+            samples.len() as f32 / self.sample_rate.max(1) as f32,
+            samples.len(),
+            self.seq
+        );
+        let prefix = self.overlap_prefix;
+        self.emit(samples, prefix);
+        self.overlap_prefix = 0;
+        self.reset_silence_state();
+    }
+
+    /// Emits the last partial chunk and returns the number of chunks emitted.
+    pub(super) fn flush_remaining(mut self) -> u64 {
         if !self.samples.is_empty() {
             let samples = std::mem::take(&mut self.samples);
             let prefix = self.overlap_prefix;
             self.emit(samples, prefix);
         }
+        self.seq
     }
 
     fn emit(&mut self, samples: Vec<f32>, overlap_prefix: usize) {
+        let len = samples.len();
         let job = ChunkJob::Audio {
             seq: self.seq,
             samples,
             sample_rate: self.sample_rate,
             overlap_prefix,
+            offset: self.chunk_start,
         };
+        self.chunk_start += len;
         if let Err(e) = self.tx.send(job) {
             error!("Chunking: failed to push chunk (seq {}): {}", self.seq, e);
         }
         self.seq = self.seq.saturating_add(1);
         if let Some(observer) = self.preview.as_mut() {
             observer.last_tick_secs = 0;
+            observer.pause_run = 0;
+            observer.freeze_point = 0;
         }
     }
 
@@ -530,7 +690,13 @@ mod tests {
 
     fn drive_chunker_jobs(samples: &[f32]) -> Vec<ChunkJob> {
         let (tx, rx) = mpsc::channel::<ChunkJob>();
-        let mut chunker = Chunker::new(tx, SR, None);
+        let mut chunker = Chunker::new(
+            tx,
+            SR,
+            None,
+            VoiceDetector::adaptive(),
+            AsrBackend::Parakeet,
+        );
         let window = (SR as usize * 33 / 1000).max(1);
         for win in samples.chunks(window) {
             chunker.push_samples(win);
@@ -586,10 +752,46 @@ mod tests {
         assert!(first_chunk_secs < CHUNK_FORCE_CUT_SECS as f32);
     }
 
+    #[test]
+    fn chunker_reports_pauses_as_preview_freeze_points() {
+        let (tx, _rx) = mpsc::channel::<ChunkJob>();
+        let snapshot = Arc::new(PlMutex::new(PreviewSnapshot::default()));
+        let link = PreviewLink {
+            snapshot: snapshot.clone(),
+            inference_active: Arc::new(AtomicBool::new(false)),
+        };
+        let mut chunker = Chunker::new(
+            tx,
+            SR,
+            Some(link),
+            VoiceDetector::adaptive(),
+            AsrBackend::Parakeet,
+        );
+        let mut samples = speech(3.0);
+        samples.extend(silence(1.0));
+        samples.extend(speech(2.0));
+        let window = (SR as usize * 33 / 1000).max(1);
+        for win in samples.chunks(window) {
+            chunker.push_samples(win);
+            chunker.on_throttle_tick(rms(win));
+        }
+
+        // Too short to be armed, so the pause froze nothing but the preview.
+        let freeze_secs = snapshot.lock().freeze_point as f32 / SR as f32;
+        assert!((3.5..4.0).contains(&freeze_secs), "{}", freeze_secs);
+        assert_eq!(chunker.seq, 0);
+    }
+
     #[test]
     fn chunker_preserves_noise_floor_after_silence_reset() {
         let (tx, _rx) = mpsc::channel::<ChunkJob>();
-        let mut chunker = Chunker::new(tx, SR, None);
+        let mut chunker = Chunker::new(
+            tx,
+            SR,
+            None,
+            VoiceDetector::adaptive(),
+            AsrBackend::Parakeet,
+        );
         for _ in 0..TICKS_PER_SECOND_FOR_TEST * 2 {
             chunker.vad.observe(0.0012);
         }
@@ -600,6 +802,113 @@ mod tests {
         assert_eq!(chunker.vad.speech_threshold(), calibrated_threshold);
     }
 
+    #[test]
+    fn chunker_offsets_account_for_forced_overlap() {
+        let jobs = drive_chunker_jobs(&speech(70.0));
+        let offsets: Vec<usize> = jobs
+            .iter()
+            .filter_map(|job| match job {
+                ChunkJob::Audio { offset, .. } => Some(*offset),
+                ChunkJob::Finalize => None,
+            })
+            .collect();
+
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets[0], 0);
+        let force = CHUNK_FORCE_CUT_SECS as usize * SR as usize;
+        let overlap = (CHUNK_FORCED_OVERLAP_SECS * SR as f32) as usize;
+        assert!(offsets[1] >= force - overlap && offsets[1] < force);
+    }
+
+    #[test]
+    fn chunker_fits_whisper_chunks_in_one_window() {
+        let (tx, rx) = mpsc::channel::<ChunkJob>();
+        let mut chunker =
+            Chunker::new(tx, SR, None, VoiceDetector::adaptive(), AsrBackend::Whisper);
+        let window = SR as usize * 33 / 1000;
+        for win in speech(70.0).chunks(window) {
+            chunker.push_samples(win);
+            chunker.on_throttle_tick(rms(win));
+        }
+        chunker.flush_remaining();
+
+        let lengths: Vec<usize> = rx
+            .try_iter()
+            .filter_map(|job| match job {
+                ChunkJob::Audio { samples, .. } => Some(samples.len()),
+                ChunkJob::Finalize => None,
+            })
+            .collect();
+        assert_eq!(lengths.len(), 3);
+        let window_samples = crate::engine::whisper::WINDOW_SECS as usize * SR as usize;
+        assert!(lengths.iter().all(|&len| len < window_samples));
+    }
+
+    #[test]
+    fn merge_words_skips_deduped_head_and_applies_offset() {
+        let words = Arc::new(Mutex::new(vec![
+            TranscriptionWord::new("je", 0.0, 0.5),
+            TranscriptionWord::new("vais", 1.0, 1.5),
+            TranscriptionWord::new("au", 2.0, 2.5),
+            TranscriptionWord::new("marché", 3.0, 3.5),
+        ]));
+        let chunk = vec![
+            TranscriptionWord::new("au", 0.0, 0.5),
+            TranscriptionWord::new("Marché,", 1.0, 1.5),
+            TranscriptionWord::new("acheter", 2.0, 2.5),
+        ];
+        merge_words(&words, chunk, true, 59.0);
+
+        let merged = words.lock().unwrap();
+        assert_eq!(merged.len(), 5);
+        assert_eq!(merged[4].text, "acheter");
+        assert!((merged[4].start - 61.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn merge_words_keeps_head_without_overlap() {
+        let words = Arc::new(Mutex::new(Vec::new()));
+        let chunk = vec![TranscriptionWord::new("bonjour", 0.0, 0.5)];
+        merge_words(&words, chunk.clone(), true, 0.0);
+        merge_words(&words, chunk, false, 10.0);
+
+        let merged = words.lock().unwrap();
+        assert_eq!(merged.len(), 2);
+        assert!((merged[1].start - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn chunker_cuts_at_pause_and_keeps_offsets() {
+        let (tx, rx) = mpsc::channel::<ChunkJob>();
+        let mut chunker = Chunker::new(
+            tx,
+            SR,
+            None,
+            VoiceDetector::adaptive(),
+            AsrBackend::Parakeet,
+        );
+        chunker.push_samples(&speech(3.0));
+        chunker.cut_at_pause();
+        // Pausing again right away has nothing left to cut.
+        chunker.cut_at_pause();
+        chunker.push_samples(&speech(2.0));
+        chunker.flush_remaining();
+
+        let chunks: Vec<(usize, usize)> = rx
+            .try_iter()
+            .filter_map(|job| match job {
+                ChunkJob::Audio {
+                    samples, offset, ..
+                } => Some((offset, samples.len())),
+                ChunkJob::Finalize => None,
+            })
+            .collect();
+        assert_eq!(
+            chunks,
+            vec![(0, 3 * SR as usize), (3 * SR as usize, 2 * SR as usize)]
+        );
+    }
+
     #[test]
     fn chunker_no_silence_cut_below_arm() {
         let mut samples = speech(5.0);
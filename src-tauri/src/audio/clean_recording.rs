@@ -107,6 +107,14 @@ pub(super) fn strip_fillers_and_repeats(text: &str) -> String {
     result.join(" ")
 }
 
+/// `strip_fillers_and_repeats` on each line, keeping the line breaks.
+pub(super) fn strip_fillers_and_repeats_by_line(text: &str) -> String {
+    text.lines()
+        .map(strip_fillers_and_repeats)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +224,14 @@ mod tests {
         assert_eq!(strip_fillers_and_repeats("je je je je vais"), "je je vais");
     }
 
+    #[test]
+    fn dedup_by_line_keeps_speaker_turns_apart() {
+        assert_eq!(
+            strip_fillers_and_repeats_by_line("Me: oui oui\nRemote: oui euh d'accord"),
+            "Me: oui oui\nRemote: oui d'accord"
+        );
+    }
+
     #[test]
     fn dedup_two_kept_unchanged() {
         assert_eq!(strip_fillers_and_repeats("oui oui"), "oui oui");
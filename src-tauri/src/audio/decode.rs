@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use log::warn;
+use std::fs::File;
+use std::path::Path;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{CodecType, DecoderOptions, CODEC_TYPE_NULL, CODEC_TYPE_OPUS};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+/// Decode a compressed audio file (FLAC, MP3, M4A/AAC, Ogg/Vorbis, and WAV
+/// variants hound rejects) to mono f32 at its native sample rate. Ogg/Opus is
+/// out of scope: symphonia has no Opus decoder, and libopus would add a C
+/// build dependency for every platform.
+pub fn decode_mono_native(path: &Path) -> Result<(Vec<f32>, u32)> {
+    let file = File::open(path).context("Failed to open audio file")?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Unsupported audio format. Supported formats: WAV, FLAC, MP3, M4A (AAC), Ogg (Vorbis)"
+            )
+        })?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| anyhow::anyhow!("Audio file contains no audio track"))?;
+    let track_id = track.id;
+    let mut sample_rate = track.codec_params.sample_rate;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|_| unsupported_codec(track.codec_params.codec))?;
+
+    let mut mono: Vec<f32> = Vec::new();
+    let mut sample_buffer: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(e).context("Failed to read audio file"),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(e)) => {
+                warn!("Skipping undecodable audio packet: {}", e);
+                continue;
+            }
+            Err(e) => return Err(e).context("Failed to decode audio file"),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        sample_rate.get_or_insert(spec.rate);
+        let needed = decoded.capacity() * channels;
+        if sample_buffer
+            .as_ref()
+            .is_none_or(|buffer| buffer.capacity() < needed)
+        {
+            sample_buffer = None;
+        }
+        let buffer =
+            sample_buffer.get_or_insert_with(|| SampleBuffer::new(decoded.capacity() as u64, spec));
+        buffer.copy_interleaved_ref(decoded);
+        mono.extend(
+            buffer
+                .samples()
+                .chunks_exact(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+    }
+
+    let sample_rate =
+        sample_rate.ok_or_else(|| anyhow::anyhow!("Audio file has no sample rate"))?;
+    Ok((mono, sample_rate))
+}
+
+fn unsupported_codec(codec: CodecType) -> anyhow::Error {
+    if codec == CODEC_TYPE_OPUS {
+        return anyhow::anyhow!(
+            "Unsupported audio codec: Opus. Convert the file to FLAC, MP3, M4A or Ogg Vorbis"
+        );
+    }
+    let name = symphonia::default::get_codecs()
+        .get_codec(codec)
+        .map(|descriptor| descriptor.short_name.to_string())
+        .unwrap_or_else(|| codec.to_string());
+    anyhow::anyhow!("Unsupported audio codec: {}", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_rejects_unknown_format_with_supported_list() {
+        let path = std::env::temp_dir().join(format!("murmure-decode-{}.bin", std::process::id()));
+        std::fs::write(&path, b"definitely not audio").unwrap();
+        let err = decode_mono_native(&path).unwrap_err().to_string();
+        let _ = std::fs::remove_file(&path);
+        assert!(err.contains("Unsupported audio format"), "{err}");
+        assert!(err.contains("FLAC"), "{err}");
+    }
+
+    #[test]
+    fn decode_reads_float_wav_as_mono() {
+        let path = std::env::temp_dir().join(format!("murmure-decode-{}.wav", std::process::id()));
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 22050,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for _ in 0..2205 {
+            writer.write_sample(0.5f32).unwrap();
+            writer.write_sample(-0.1f32).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let (samples, rate) = decode_mono_native(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(rate, 22050);
+        assert_eq!(samples.len(), 2205);
+        assert!(samples.iter().all(|s| (s - 0.2).abs() < 1e-4));
+    }
+}
@@ -0,0 +1,290 @@
+use crate::audio::helpers::rms;
+use realfft::num_complex::Complex;
+use realfft::RealFftPlanner;
+
+/// STFT frame of 32 ms at 16 kHz, with 50% overlap.
+const FRAME_SAMPLES: usize = 512;
+const HOP_SAMPLES: usize = FRAME_SAMPLES / 2;
+/// Share of the quietest frames averaged into the noise spectrum.
+const NOISE_FRAME_SHARE: f32 = 0.1;
+/// Energy ratio between the loudest and the quietest frames below which the
+/// buffer has no pauses to learn the noise from, and is left as is (10 dB).
+const MIN_PAUSE_CONTRAST: f32 = 10.0;
+/// Weight of the previous frame in the a priori SNR (decision-directed).
+const SNR_SMOOTHING: f32 = 0.98;
+/// Floor of the suppression gain (-20 dB). Deeper suppression leaves
+/// artifacts that hurt recognition more than the noise does.
+const MIN_SUPPRESSION_GAIN: f32 = 0.1;
+
+/// Gain control frame, 20 ms at 16 kHz.
+const GAIN_FRAME_SAMPLES: usize = 320;
+/// Speech level the gain control aims at (-20 dBFS).
+const TARGET_LEVEL: f32 = 0.1;
+/// Frames below this level (-50 dBFS) are pauses and leave the gain as is.
+const GATE_LEVEL: f32 = 0.003;
+const MIN_GAIN: f32 = 0.25;
+const MAX_GAIN: f32 = 10.0;
+/// Per-frame smoothing of the speech level, about half a second.
+const LEVEL_SMOOTHING: f32 = 0.04;
+/// Peaks above this are compressed towards full scale instead of clipping.
+const LIMITER_THRESHOLD: f32 = 0.8;
+
+/// Clean up 16 kHz mono audio before inference: stationary noise is
+/// suppressed, then the speech level is brought to a common loudness.
+pub fn enhance(samples: &[f32]) -> Vec<f32> {
+    normalize_loudness(&suppress_noise(samples))
+}
+
+/// `enhance` when `enabled`, the samples untouched otherwise.
+pub fn enhance_if(enabled: bool, samples: Vec<f32>) -> Vec<f32> {
+    if enabled {
+        enhance(&samples)
+    } else {
+        samples
+    }
+}
+
+/// Wiener filter over an STFT. The noise spectrum is the mean of the
+/// quietest frames of the buffer, which are the pauses between words.
+fn suppress_noise(samples: &[f32]) -> Vec<f32> {
+    if samples.len() < FRAME_SAMPLES * 4 {
+        return samples.to_vec();
+    }
+
+    // Square root of a periodic Hann for analysis and synthesis: the
+    // overlapping products sum to one, so unfiltered audio comes back as is.
+    let window: Vec<f32> = (0..FRAME_SAMPLES)
+        .map(|i| {
+            let hann =
+                0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / FRAME_SAMPLES as f32).cos();
+            hann.sqrt()
+        })
+        .collect();
+
+    // One hop of padding on each side so every sample is covered by two frames.
+    let padded_len = (samples.len() + 2 * HOP_SAMPLES).div_ceil(HOP_SAMPLES) * HOP_SAMPLES;
+    let mut padded = vec![0.0; padded_len];
+    padded[HOP_SAMPLES..HOP_SAMPLES + samples.len()].copy_from_slice(samples);
+    let frame_count = padded_len / HOP_SAMPLES - 1;
+
+    let mut planner = RealFftPlanner::<f32>::new();
+    let forward = planner.plan_fft_forward(FRAME_SAMPLES);
+    let inverse = planner.plan_fft_inverse(FRAME_SAMPLES);
+    let mut frame = forward.make_input_vec();
+
+    let mut spectra = Vec::with_capacity(frame_count);
+    for t in 0..frame_count {
+        let start = t * HOP_SAMPLES;
+        for (i, value) in frame.iter_mut().enumerate() {
+            *value = padded[start + i] * window[i];
+        }
+        let mut spectrum = forward.make_output_vec();
+        if forward.process(&mut frame, &mut spectrum).is_err() {
+            return samples.to_vec();
+        }
+        spectra.push(spectrum);
+    }
+
+    // The first and last frames are half padding, not pauses.
+    let Some(noise) = noise_spectrum(&spectra[1..frame_count - 1]) else {
+        return samples.to_vec();
+    };
+    let mut output = vec![0.0; padded_len];
+    let mut previous_clean = vec![0.0f32; noise.len()];
+    for (t, spectrum) in spectra.iter_mut().enumerate() {
+        for (k, bin) in spectrum.iter_mut().enumerate() {
+            let power = bin.norm_sqr();
+            let noise_power = noise[k].max(1e-12);
+            let posterior = power / noise_power;
+            let prior = SNR_SMOOTHING * previous_clean[k] / noise_power
+                + (1.0 - SNR_SMOOTHING) * (posterior - 1.0).max(0.0);
+            let gain = (prior / (1.0 + prior)).max(MIN_SUPPRESSION_GAIN);
+            previous_clean[k] = gain * gain * power;
+            *bin *= gain;
+        }
+        // The DC and Nyquist bins of a real signal have no imaginary part.
+        spectrum[0].im = 0.0;
+        if let Some(last) = spectrum.last_mut() {
+            last.im = 0.0;
+        }
+        if inverse.process(spectrum, &mut frame).is_err() {
+            return samples.to_vec();
+        }
+        let start = t * HOP_SAMPLES;
+        for (i, &value) in frame.iter().enumerate() {
+            output[start + i] += value * window[i] / FRAME_SAMPLES as f32;
+        }
+    }
+
+    output[HOP_SAMPLES..HOP_SAMPLES + samples.len()].to_vec()
+}
+
+/// Mean power spectrum of the quietest frames, `None` when they are not
+/// clearly quieter than the loudest ones.
+fn noise_spectrum(spectra: &[Vec<Complex<f32>>]) -> Option<Vec<f32>> {
+    let mut by_energy: Vec<(f32, usize)> = spectra
+        .iter()
+        .enumerate()
+        .map(|(t, spectrum)| (spectrum.iter().map(|c| c.norm_sqr()).sum(), t))
+        .collect();
+    by_energy.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let count = ((spectra.len() as f32 * NOISE_FRAME_SHARE) as usize).max(1);
+    let quiet: Vec<usize> = by_energy.iter().take(count).map(|&(_, t)| t).collect();
+    let quiet_energy = by_energy[..count].iter().map(|&(e, _)| e).sum::<f32>() / count as f32;
+    let loud_energy = by_energy[by_energy.len() - count..]
+        .iter()
+        .map(|&(e, _)| e)
+        .sum::<f32>()
+        / count as f32;
+    if loud_energy < quiet_energy * MIN_PAUSE_CONTRAST {
+        return None;
+    }
+
+    let mut noise = vec![0.0; spectra[0].len()];
+    for t in quiet {
+        for (acc, bin) in noise.iter_mut().zip(&spectra[t]) {
+            *acc += bin.norm_sqr();
+        }
+    }
+    noise.iter_mut().for_each(|p| *p /= count as f32);
+    Some(noise)
+}
+
+/// Slow gain control on the level of speech frames. It starts from the
+/// level of the whole buffer so the first words are not boosted late, and
+/// holds during pauses so background noise is not pumped up.
+fn normalize_loudness(samples: &[f32]) -> Vec<f32> {
+    let levels: Vec<f32> = samples.chunks(GAIN_FRAME_SAMPLES).map(rms).collect();
+    let active: Vec<f32> = levels.iter().copied().filter(|&l| l > GATE_LEVEL).collect();
+    if active.is_empty() {
+        return samples.to_vec();
+    }
+
+    let mut level = (active.iter().map(|l| l * l).sum::<f32>() / active.len() as f32).sqrt();
+    let mut gain = gain_for(level);
+    let mut output = Vec::with_capacity(samples.len());
+    for (frame, &frame_level) in samples.chunks(GAIN_FRAME_SAMPLES).zip(&levels) {
+        if frame_level > GATE_LEVEL {
+            level += LEVEL_SMOOTHING * (frame_level - level);
+        }
+        let target = gain_for(level);
+        // Ramp to the new gain over the frame so it does not click.
+        let len = frame.len() as f32;
+        for (i, &sample) in frame.iter().enumerate() {
+            let g = gain + (target - gain) * (i + 1) as f32 / len;
+            output.push(limit(sample * g));
+        }
+        gain = target;
+    }
+    output
+}
+
+fn gain_for(level: f32) -> f32 {
+    (TARGET_LEVEL / level).clamp(MIN_GAIN, MAX_GAIN)
+}
+
+fn limit(sample: f32) -> f32 {
+    let magnitude = sample.abs();
+    if magnitude <= LIMITER_THRESHOLD {
+        return sample;
+    }
+    let headroom = 1.0 - LIMITER_THRESHOLD;
+    sample.signum()
+        * (LIMITER_THRESHOLD + headroom * ((magnitude - LIMITER_THRESHOLD) / headroom).tanh())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(amplitude: f32, seconds: f32) -> Vec<f32> {
+        (0..(16000.0 * seconds) as usize)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin())
+            .collect()
+    }
+
+    // Deterministic white noise, uniform in [-amplitude, amplitude].
+    fn noise(amplitude: f32, len: usize) -> Vec<f32> {
+        let mut state: u32 = 0x1234_5678;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                amplitude * (state as f32 / u32::MAX as f32 * 2.0 - 1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_enhance_keeps_length_and_silence() {
+        assert!(enhance(&[]).is_empty());
+        let silence = vec![0.0; 16000];
+        assert_eq!(enhance(&silence), silence);
+        assert_eq!(enhance(&tone(0.1, 0.37)).len(), 5920);
+    }
+
+    #[test]
+    fn test_suppress_noise_lowers_pauses_and_keeps_speech() {
+        // One second of noise, then a tone over the same noise.
+        let background = noise(0.02, 32000);
+        let mut signal = background.clone();
+        for (s, t) in signal[16000..].iter_mut().zip(tone(0.2, 1.0)) {
+            *s += t;
+        }
+        let cleaned = suppress_noise(&signal);
+        assert_eq!(cleaned.len(), signal.len());
+
+        let pause_before = rms(&signal[2000..14000]);
+        let pause_after = rms(&cleaned[2000..14000]);
+        assert!(
+            pause_after < pause_before * 0.3,
+            "{pause_after} vs {pause_before}"
+        );
+
+        let speech_before = rms(&signal[18000..30000]);
+        let speech_after = rms(&cleaned[18000..30000]);
+        assert!((speech_after / speech_before - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_suppress_noise_keeps_clean_audio() {
+        let mut signal = vec![0.0; 4000];
+        signal.extend(tone(0.3, 0.5));
+        signal.extend(vec![0.0; 4000]);
+        let cleaned = suppress_noise(&signal);
+        let error: Vec<f32> = signal.iter().zip(&cleaned).map(|(a, b)| a - b).collect();
+        assert!(rms(&error) < 1e-3, "{}", rms(&error));
+    }
+
+    #[test]
+    fn test_suppress_noise_skips_audio_without_pauses() {
+        let signal = noise(0.05, 16000);
+        assert_eq!(suppress_noise(&signal), signal);
+    }
+
+    #[test]
+    fn test_normalize_loudness_boosts_quiet_and_tames_loud_speech() {
+        let quiet = normalize_loudness(&tone(0.05, 1.0));
+        assert!((rms(&quiet) - TARGET_LEVEL).abs() < 0.005);
+
+        let whisper = tone(0.005, 1.0);
+        let boosted = normalize_loudness(&whisper);
+        assert!((rms(&boosted) / rms(&whisper) - MAX_GAIN).abs() < 0.1);
+
+        let loud = normalize_loudness(&tone(1.0, 1.0));
+        assert!(loud.iter().all(|s| s.abs() <= 1.0));
+        assert!(rms(&loud) < 0.5);
+    }
+
+    #[test]
+    fn test_normalize_loudness_ignores_pauses() {
+        let mut signal = vec![0.0005; 8000];
+        signal.extend(tone(0.05, 1.0));
+        let output = normalize_loudness(&signal);
+        // The pause gets the gain of the speech, about 0.1 / 0.035.
+        let gain = output[100] / signal[100];
+        assert!((gain - 2.83).abs() < 0.05, "{gain}");
+    }
+}
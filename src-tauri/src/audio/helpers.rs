@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 
+use crate::audio::decode::decode_mono_native;
 use hound::{WavSpec, WavWriter};
 use log::warn;
 use rubato::audioadapter_buffers::direct::InterleavedSlice;
@@ -114,8 +115,13 @@ pub fn cleanup_recordings(app: &tauri::AppHandle) -> Result<()> {
     Ok(())
 }
 
-pub fn read_wav_samples(wav_path: &Path) -> Result<Vec<f32>> {
-    let (samples_f32, sample_rate) = read_wav_mono_native(wav_path)?;
+/// Read an audio file as 16 kHz mono. 16-bit PCM WAV keeps the hound path;
+/// anything else (FLAC, MP3, M4A, Ogg, float WAV) goes through symphonia.
+pub fn read_audio_samples(path: &Path) -> Result<Vec<f32>> {
+    let (samples_f32, sample_rate) = match read_wav_mono_native(path) {
+        Ok(decoded) => decoded,
+        Err(_) => decode_mono_native(path)?,
+    };
 
     let out = if sample_rate != 16000 {
         resample(&samples_f32, sample_rate as usize, 16000)
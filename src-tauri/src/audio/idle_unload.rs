@@ -0,0 +1,60 @@
+use crate::audio::types::AudioState;
+use crate::wake_word::types::WakeWordState;
+use log::info;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+// How often the idle timeout is checked.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Longest idle timeout accepted from the settings (one day).
+pub const MAX_IDLE_UNLOAD_MINUTES: u64 = 24 * 60;
+
+/// Whether an engine idle for `idle` should be dropped. A timeout of 0
+/// keeps it loaded.
+fn should_unload(idle: Duration, timeout_minutes: u64) -> bool {
+    timeout_minutes > 0 && idle >= Duration::from_secs(timeout_minutes * 60)
+}
+
+/// Drop the engine once it has been idle for `model_idle_unload_minutes`,
+/// except while recording or listening for the wake word. The next
+/// transcription loads it again.
+pub fn spawn_idle_unload(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(CHECK_INTERVAL);
+        let timeout_minutes = crate::settings::load_settings(&app).model_idle_unload_minutes;
+        let state = app.state::<AudioState>();
+        if !should_unload(state.engine_idle_time(), timeout_minutes)
+            || app.state::<WakeWordState>().is_active()
+            || state.recorder.lock().is_some()
+        {
+            continue;
+        }
+        // A held lock means the engine is in use or loading: try again later.
+        if let Some(mut engine) = state.engine.try_lock() {
+            if engine.is_some() {
+                *engine = None;
+                info!(
+                    "Model unloaded after {} minutes without use",
+                    timeout_minutes
+                );
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_unload_after_timeout() {
+        assert!(!should_unload(Duration::from_secs(299), 5));
+        assert!(should_unload(Duration::from_secs(300), 5));
+    }
+
+    #[test]
+    fn test_should_unload_disabled_by_zero() {
+        assert!(!should_unload(Duration::from_secs(86_400), 0));
+    }
+}
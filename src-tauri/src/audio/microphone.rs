@@ -14,7 +14,8 @@ const GENERIC_MIC_NAMES: &[&str] = &[
 ];
 
 /// Lists available microphones.
-/// On Linux, uses PulseAudio/PipeWire via `pactl` for clean device names.
+/// On Linux, uses PulseAudio/PipeWire via `pactl` for clean device names, and
+/// lists the monitor sources after the microphones to record system audio.
 /// On other platforms, uses CPAL device enumeration with filtering.
 pub fn get_mic_list() -> Vec<MicInfo> {
     #[cfg(target_os = "linux")]
@@ -105,9 +106,7 @@ fn list_sources_pactl() -> Option<Vec<MicInfo>> {
             .and_then(|v| v.as_str())
             .unwrap_or("");
 
-        if device_class == "monitor" {
-            continue;
-        }
+        let is_monitor = device_class == "monitor";
 
         let name = source.get("name").and_then(|v| v.as_str()).unwrap_or("");
         let description = props
@@ -125,11 +124,14 @@ fn list_sources_pactl() -> Option<Vec<MicInfo>> {
             mics.push(MicInfo {
                 id: name.to_string(),
                 label,
+                is_monitor,
             });
         }
     }
 
     disambiguate_labels(&mut mics);
+    // Stable: the pactl order is kept within microphones and monitors.
+    mics.sort_by_key(|mic| mic.is_monitor);
 
     let default_source = get_pulse_default_source();
     if let Some(ref default) = default_source {
@@ -140,7 +142,7 @@ fn list_sources_pactl() -> Option<Vec<MicInfo>> {
     }
 
     // Fall back to CPAL if no valid sources found
-    if mics.is_empty() {
+    if mics.iter().all(|mic| mic.is_monitor) {
         return None;
     }
 
@@ -253,7 +255,11 @@ fn get_mic_list_cpal() -> Vec<MicInfo> {
                     if seen_ids.insert(id.clone()) {
                         let label = get_device_label(&device, &id);
                         debug!("Mic accepted: {} (id: {}, driver: {:?})", label, id, driver);
-                        mics.push(MicInfo { id, label });
+                        mics.push(MicInfo {
+                            id,
+                            label,
+                            is_monitor: false,
+                        });
                     }
                 }
             }
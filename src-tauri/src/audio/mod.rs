@@ -1,13 +1,21 @@
 pub mod audio;
 pub mod chunking;
 pub mod clean_recording;
+pub mod decode;
+pub mod enhancement;
 pub mod helpers;
+pub mod idle_unload;
 pub mod microphone;
+pub mod neural_vad;
 pub mod output_volume;
 pub mod pipeline;
+pub mod pre_roll;
 pub mod recorder;
 pub mod sound;
 pub mod streaming;
+pub mod subtitles;
+pub mod system_audio;
+pub mod timestamps;
 pub mod types;
 pub mod vad;
 
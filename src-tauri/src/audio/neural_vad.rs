@@ -0,0 +1,178 @@
+use anyhow::{anyhow, Result};
+use ndarray::{arr0, Array2, Array3};
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use std::path::Path;
+
+/// Silero VAD v5 model, looked up in the resources.
+pub const NEURAL_VAD_FILENAME: &str = "silero_vad.onnx";
+
+const MODEL_SAMPLE_RATE: u32 = 16000;
+/// Samples per inference at 16 kHz (32 ms).
+const FRAME_SAMPLES: usize = 512;
+/// Samples of the previous frame prepended to each frame, as the model expects.
+const CONTEXT_SAMPLES: usize = 64;
+const STATE_SIZE: usize = 128;
+
+/// Speech probability of 32 ms frames from the Silero VAD model. Audio is
+/// pushed at the microphone rate as it comes; the highest probability since
+/// the last call is read on each VAD tick.
+pub(crate) struct NeuralVad {
+    session: Session,
+    state: Array3<f32>,
+    context: Vec<f32>,
+    pending: Vec<f32>,
+    downsampler: Downsampler,
+    probability: Option<f32>,
+}
+
+impl NeuralVad {
+    pub(crate) fn load(model_path: &Path, sample_rate: u32) -> Result<Self> {
+        // One thread: the model is tiny and runs next to other audio work.
+        let session = Session::builder()?
+            .with_config_entry("session.log_severity_level", "3")?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .with_intra_threads(1)?
+            .with_inter_threads(1)?
+            .commit_from_file(model_path)?;
+        Ok(Self {
+            session,
+            state: Array3::zeros((2, 1, STATE_SIZE)),
+            context: vec![0.0; CONTEXT_SAMPLES],
+            pending: Vec::with_capacity(FRAME_SAMPLES * 2),
+            downsampler: Downsampler::new(sample_rate, MODEL_SAMPLE_RATE),
+            probability: None,
+        })
+    }
+
+    pub(crate) fn push(&mut self, samples: &[f32]) {
+        self.downsampler.push(samples, &mut self.pending);
+        while self.pending.len() >= FRAME_SAMPLES {
+            let frame: Vec<f32> = self.pending.drain(..FRAME_SAMPLES).collect();
+            match self.run_frame(&frame) {
+                Ok(p) => self.probability = Some(self.probability.map_or(p, |prev| prev.max(p))),
+                Err(e) => log::debug!("Neural VAD inference failed: {}", e),
+            }
+        }
+    }
+
+    /// Highest speech probability of the frames since the last call, `None`
+    /// when no full frame came in.
+    pub(crate) fn take_probability(&mut self) -> Option<f32> {
+        self.probability.take()
+    }
+
+    fn run_frame(&mut self, frame: &[f32]) -> Result<f32> {
+        let mut input = Vec::with_capacity(CONTEXT_SAMPLES + FRAME_SAMPLES);
+        input.extend_from_slice(&self.context);
+        input.extend_from_slice(frame);
+        let input = Array2::from_shape_vec((1, CONTEXT_SAMPLES + FRAME_SAMPLES), input)?;
+        let sr = arr0(MODEL_SAMPLE_RATE as i64);
+
+        let outputs = self.session.run(inputs![
+            "input" => TensorRef::from_array_view(input.view())?,
+            "state" => TensorRef::from_array_view(self.state.view())?,
+            "sr" => TensorRef::from_array_view(sr.view())?,
+        ])?;
+        let probability = outputs
+            .get("output")
+            .ok_or_else(|| anyhow!("Neural VAD output not found"))?
+            .try_extract_array::<f32>()?
+            .iter()
+            .copied()
+            .next()
+            .unwrap_or(0.0);
+        let state = outputs
+            .get("stateN")
+            .ok_or_else(|| anyhow!("Neural VAD state not found"))?
+            .try_extract_array::<f32>()?
+            .to_owned()
+            .into_dimensionality::<ndarray::Ix3>()?;
+
+        self.state = state;
+        self.context
+            .copy_from_slice(&frame[FRAME_SAMPLES - CONTEXT_SAMPLES..]);
+        Ok(probability)
+    }
+}
+
+/// Streaming sample-rate conversion for the VAD input: each output sample is
+/// the mean of the input samples it covers, which is enough for a detector
+/// and cheap enough to run on every buffer.
+struct Downsampler {
+    /// Input samples per output sample.
+    step: f64,
+    position: f64,
+    sum: f32,
+    count: u32,
+    last: f32,
+}
+
+impl Downsampler {
+    fn new(from_hz: u32, to_hz: u32) -> Self {
+        Self {
+            step: from_hz.max(1) as f64 / to_hz.max(1) as f64,
+            position: 0.0,
+            sum: 0.0,
+            count: 0,
+            last: 0.0,
+        }
+    }
+
+    fn push(&mut self, input: &[f32], output: &mut Vec<f32>) {
+        for &sample in input {
+            self.sum += sample;
+            self.count += 1;
+            self.position += 1.0;
+            while self.position >= self.step {
+                if self.count > 0 {
+                    self.last = self.sum / self.count as f32;
+                    self.sum = 0.0;
+                    self.count = 0;
+                }
+                output.push(self.last);
+                self.position -= self.step;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn downsample(from_hz: u32, input: &[f32]) -> Vec<f32> {
+        let mut downsampler = Downsampler::new(from_hz, MODEL_SAMPLE_RATE);
+        let mut output = Vec::new();
+        for block in input.chunks(441) {
+            downsampler.push(block, &mut output);
+        }
+        output
+    }
+
+    #[test]
+    fn downsampler_averages_integer_ratio() {
+        let input: Vec<f32> = (0..48).map(|i| i as f32).collect();
+        let output = downsample(48000, &input);
+        assert_eq!(output.len(), 16);
+        assert_eq!(output[0], 1.0);
+        assert_eq!(output[15], 46.0);
+    }
+
+    #[test]
+    fn downsampler_keeps_rate_across_blocks() {
+        let output = downsample(44100, &vec![0.5; 44100]);
+        assert!((15999..=16000).contains(&output.len()));
+        assert!(output.iter().all(|&s| (s - 0.5).abs() < 1e-6));
+    }
+
+    #[test]
+    fn downsampler_repeats_samples_below_model_rate() {
+        let output = downsample(8000, &[1.0, 2.0]);
+        assert_eq!(output, vec![1.0, 1.0, 2.0, 2.0]);
+    }
+}
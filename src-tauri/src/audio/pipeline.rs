@@ -1,15 +1,23 @@
 use crate::audio::chunking::{ChunkPipeline, Chunker};
-use crate::audio::clean_recording::strip_fillers_and_repeats;
-use crate::audio::helpers::{read_wav_samples, resample, rms};
-use crate::audio::types::{AudioState, RecordingMode};
-use crate::dictionary::{correct_transcription, sync_boost_words, Dictionary};
-use crate::engine::transcription_engine::{TranscriptionEngine, TranscriptionResult};
+use crate::audio::clean_recording::{strip_fillers_and_repeats, strip_fillers_and_repeats_by_line};
+use crate::audio::enhancement::enhance_if;
+use crate::audio::helpers::{read_audio_samples, resample, rms};
+use crate::audio::timestamps::{build_segments, TimedTranscript};
+use crate::audio::types::{
+    AudioState, ChunkProgress, ProcessOptions, RecordingMode, TranscribeOptions,
+};
+use crate::audio::vad::VoiceDetector;
+use crate::dictionary::{
+    apply_suppression, correct_alternatives, correct_transcription, suppress_words,
+    sync_boost_words, Dictionary,
+};
+use crate::engine::transcription_engine::{TranscriptionResult, TranscriptionWord};
+use crate::engine::AsrInferenceParams;
 use crate::formatting_rules;
 use crate::history;
-use crate::model::Model;
 use crate::stats;
 use anyhow::Result;
-use log::{debug, error, info, warn};
+use log::{debug, error, warn};
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -21,7 +29,12 @@ pub struct ProcessingResult {
 }
 
 pub enum ChunkOutcome {
-    Text { cleaned: String, corrected: String },
+    Text {
+        cleaned: String,
+        corrected: String,
+        /// Chunk-relative word timings, padding already removed.
+        words: Vec<TranscriptionWord>,
+    },
     Empty,
     Failed,
 }
@@ -37,9 +50,15 @@ fn pad_with_silence(samples: &[f32], sample_rate: u32) -> Vec<f32> {
     padded
 }
 
-/// Transcribes one chunk in isolation (fresh decoder state)
-pub fn process_chunk(app: &AppHandle, samples: Vec<f32>, sample_rate: u32) -> ChunkOutcome {
-    // 1. Resample to 16 kHz if needed
+/// Transcribes one chunk in isolation (fresh decoder state). `boost_words`
+/// extend the user dictionary for this chunk only.
+pub fn process_chunk(
+    app: &AppHandle,
+    samples: Vec<f32>,
+    sample_rate: u32,
+    boost_words: &[String],
+) -> ChunkOutcome {
+    // 1. Resample to 16 kHz if needed, then clean up the audio if enabled
     let resampled = if sample_rate != 16000 {
         resample(&samples, sample_rate as usize, 16000)
     } else {
@@ -48,11 +67,22 @@ pub fn process_chunk(app: &AppHandle, samples: Vec<f32>, sample_rate: u32) -> Ch
     if resampled.is_empty() {
         return ChunkOutcome::Empty;
     }
-    let padded = pad_with_silence(&resampled, 16000);
-
-    let dictionary = app.state::<Dictionary>().get();
+    let enhanced = enhance_if(
+        crate::settings::load_settings(app).audio_enhancement,
+        resampled,
+    );
+    let padded = pad_with_silence(&enhanced, 16000);
+
+    let mut dictionary = app.state::<Dictionary>().get();
+    let weights = app.state::<Dictionary>().get_weights();
+    let suppression = app.state::<Dictionary>().get_suppression();
+    for word in boost_words {
+        if !dictionary.contains(word) {
+            dictionary.push(word.clone());
+        }
+    }
     let state = app.state::<AudioState>();
-    if let Err(e) = ensure_engine_loaded(app, &state) {
+    if let Err(e) = crate::audio::preload_engine(app) {
         error!("Chunk transcription: engine not available: {}", e);
         return ChunkOutcome::Failed;
     }
@@ -63,18 +93,21 @@ pub fn process_chunk(app: &AppHandle, samples: Vec<f32>, sample_rate: u32) -> Ch
     };
 
     // 2. Sync dictionary boost words
-    sync_boost_words(engine, &dictionary);
+    sync_boost_words(engine, &dictionary, &weights, &suppression.words());
 
     // 3. Transcribe
-    match engine.transcribe_samples(padded, None) {
+    match engine.transcribe_samples(padded, final_decode_params(&state)) {
         Ok(result) => {
             let trimmed = result.text.trim();
             if trimmed.is_empty() {
                 ChunkOutcome::Empty
             } else {
-                // 4. Dictionary correction
-                let corrected =
-                    correct_transcription(trimmed, &dictionary, &result.word_confidences);
+                // 4. Dictionary correction, then the suppression list fallback
+                let corrected = apply_suppression(
+                    &correct_transcription(trimmed, &dictionary, &result.word_confidences),
+                    &suppression.words(),
+                    suppression.fallback,
+                );
                 if log::log_enabled!(log::Level::Debug) {
                     let confidences =
                         crate::dictionary::confidence_map(&result.word_confidences);
@@ -86,9 +119,19 @@ pub fn process_chunk(app: &AppHandle, samples: Vec<f32>, sample_rate: u32) -> Ch
                         debug!("FZCAND {}", candidate);
                     }
                 }
+                let words = result
+                    .words
+                    .into_iter()
+                    .map(|mut word| {
+                        word.start -= INFERENCE_SILENCE_PAD_SECS;
+                        word.end -= INFERENCE_SILENCE_PAD_SECS;
+                        word
+                    })
+                    .collect();
                 ChunkOutcome::Text {
                     cleaned: trimmed.to_string(),
                     corrected,
+                    words,
                 }
             }
         }
@@ -126,8 +169,9 @@ fn post_process_chunks(
         });
     }
 
-    // 5. Strip fillers and repeated words
-    let text = strip_fillers_and_repeats(&accumulated);
+    // 5. Strip fillers and repeated words, line by line so that the speaker
+    // turns of a dual capture stay apart
+    let text = strip_fillers_and_repeats_by_line(&accumulated);
     // 6. LLM post-processing
     let (llm_text, llm_error) = apply_llm_processing_with_error(app, text, mode)?;
     // 7. Apply formatting rules
@@ -139,18 +183,36 @@ fn post_process_chunks(
     })
 }
 
-pub fn transcribe_file_chunked(app: &AppHandle, file_path: &Path) -> Result<String> {
+pub fn transcribe_file_timed(app: &AppHandle, file_path: &Path) -> Result<TimedTranscript> {
     let never_cancelled = Arc::new(AtomicBool::new(false));
-    transcribe_file_chunked_cancellable(app, file_path, &never_cancelled)
-        .map(|text| text.unwrap_or_default())
+    transcribe_file_timed_cancellable(app, file_path, &never_cancelled)
+        .map(|transcript| transcript.unwrap_or_default())
+}
+
+pub fn transcribe_file_timed_cancellable(
+    app: &AppHandle,
+    file_path: &Path,
+    cancelled: &Arc<AtomicBool>,
+) -> Result<Option<TimedTranscript>> {
+    transcribe_file_with_progress(
+        app,
+        file_path,
+        cancelled,
+        &Arc::default(),
+        &TranscribeOptions::default(),
+    )
 }
 
-pub fn transcribe_file_chunked_cancellable(
+/// Like `transcribe_file_timed_cancellable`, reporting the chunk count into
+/// `progress` as the file is transcribed, with per-request `options`.
+pub fn transcribe_file_with_progress(
     app: &AppHandle,
     file_path: &Path,
     cancelled: &Arc<AtomicBool>,
-) -> Result<Option<String>> {
-    let samples = read_wav_samples(file_path)?;
+    progress: &Arc<ChunkProgress>,
+    options: &TranscribeOptions,
+) -> Result<Option<TimedTranscript>> {
+    let samples = read_audio_samples(file_path)?;
     if samples.is_empty() {
         return Err(anyhow::anyhow!("Audio file contains no samples"));
     }
@@ -158,22 +220,67 @@ pub fn transcribe_file_chunked_cancellable(
         return Ok(None);
     }
 
-    let pipeline = ChunkPipeline::start_headless(app, cancelled.clone());
-    let mut chunker = Chunker::new(pipeline.sender(), 16000, None);
+    let pipeline = ChunkPipeline::start_headless(
+        app,
+        cancelled.clone(),
+        progress.clone(),
+        options.boost_words.clone(),
+    );
+    let mut chunker = Chunker::new(
+        pipeline.sender(),
+        16000,
+        None,
+        VoiceDetector::from_settings(app, 16000),
+        crate::settings::load_settings(app).asr_backend,
+    );
     let window = 16000 * 33 / 1000;
     for win in samples.chunks(window) {
         chunker.push_samples(win);
         chunker.on_throttle_tick(rms(win));
     }
-    chunker.flush_remaining();
-    let accumulated = pipeline.finalize();
+    let chunk_count = chunker.flush_remaining();
+    progress.total.store(chunk_count, Ordering::SeqCst);
+    let (accumulated, words) = pipeline.finalize_with_words();
+    if !options.boost_words.is_empty() {
+        restore_boost_words(app);
+    }
+    let words = correct_timed_words(app, words, &options.boost_words);
 
     if cancelled.load(Ordering::SeqCst) {
         return Ok(None);
     }
 
-    let result = post_process_chunks(app, accumulated, RecordingMode::Standard)?;
-    Ok(Some(result.text.trim().to_string()))
+    let result =
+        process_text(app, &accumulated, &options.post_processing()).map_err(anyhow::Error::msg)?;
+    let text = result.text.trim().to_string();
+    let duration = samples.len() as f32 / 16000.0;
+    if options.history && !text.is_empty() {
+        let size_bytes = std::fs::metadata(file_path).map(|m| m.len()).unwrap_or(0);
+        record_history_and_stats(app, &text, duration as f64, size_bytes);
+    }
+    Ok(Some(TimedTranscript {
+        text,
+        duration,
+        segments: build_segments(&words),
+        words,
+        llm_error: result.llm_error,
+    }))
+}
+
+/// Put the user dictionary back on the engine after a transcription that
+/// boosted extra words, so the next caller does not inherit them.
+fn restore_boost_words(app: &AppHandle) {
+    let state = app.state::<AudioState>();
+    let mut engine_guard = state.engine.lock();
+    if let Some(engine) = engine_guard.as_mut() {
+        let dictionary = app.state::<Dictionary>();
+        sync_boost_words(
+            engine,
+            &dictionary.get(),
+            &dictionary.get_weights(),
+            &dictionary.get_suppression().words(),
+        );
+    }
 }
 
 fn apply_dictionary_correction(
@@ -182,7 +289,42 @@ fn apply_dictionary_correction(
     word_confidences: &[(String, f32)],
 ) -> Result<String> {
     let dictionary = app.state::<Dictionary>().get();
-    Ok(correct_transcription(&text, &dictionary, word_confidences))
+    let corrected = correct_transcription(&text, &dictionary, word_confidences);
+    Ok(apply_suppression_fallback(app, &corrected))
+}
+
+/// The dictionary corrections and the suppression fallback of the chunk
+/// texts, applied to the timed words so subtitles and word timings match the
+/// text. Words are corrected one by one, so a word the model split in two
+/// keeps its split spelling here. Their alternatives are corrected too.
+fn correct_timed_words(
+    app: &AppHandle,
+    words: Vec<TranscriptionWord>,
+    boost_words: &[String],
+) -> Vec<TranscriptionWord> {
+    let mut dictionary = app.state::<Dictionary>().get();
+    for word in boost_words {
+        if !dictionary.contains(word) {
+            dictionary.push(word.clone());
+        }
+    }
+    let suppression = app.state::<Dictionary>().get_suppression();
+    let suppressed = suppression.words();
+    let words = words
+        .into_iter()
+        .map(|mut word| {
+            let confidence = [(word.text.clone(), word.confidence)];
+            word.text = correct_transcription(&word.text, &dictionary, &confidence);
+            correct_alternatives(&mut word, &dictionary, &suppressed);
+            word
+        })
+        .collect();
+    suppress_words(words, &suppressed, suppression.fallback)
+}
+
+fn apply_suppression_fallback(app: &AppHandle, text: &str) -> String {
+    let suppression = app.state::<Dictionary>().get_suppression();
+    apply_suppression(text, &suppression.words(), suppression.fallback)
 }
 
 fn apply_llm_processing_with_error(
@@ -280,6 +422,62 @@ fn apply_formatting_rules(app: &AppHandle, text: String) -> String {
     }
 }
 
+/// Run the enabled post-processing stages on text from another source, in
+/// the recorder's order: fillers, dictionary, LLM, formatting. Nothing is
+/// saved to history. Errs only when `llm_mode` cannot be used; an LLM
+/// request that fails keeps the text and reports it in `llm_error`.
+pub fn process_text(
+    app: &AppHandle,
+    text: &str,
+    options: &ProcessOptions,
+) -> Result<ProcessingResult, String> {
+    let llm_index = options.llm_mode.map(|n| (n as usize).saturating_sub(1));
+    if let Some(index) = llm_index {
+        if crate::shortcuts::shortcuts::ensure_llm_mode_ready(app, index, false).is_err() {
+            return Err(format!(
+                "LLM mode {} is not configured, or LLM Connect is disabled",
+                index + 1
+            ));
+        }
+    }
+
+    let mut text = text.trim().to_string();
+    if text.is_empty() {
+        return Ok(ProcessingResult {
+            text,
+            llm_error: None,
+        });
+    }
+
+    if options.fillers {
+        text = strip_fillers_and_repeats(&text);
+    }
+    if options.dictionary {
+        // No engine confidences here: every word is eligible for correction.
+        text = correct_transcription(&text, &app.state::<Dictionary>().get(), &[]);
+        text = apply_suppression_fallback(app, &text);
+    }
+    let mut llm_error = None;
+    if let Some(index) = llm_index {
+        match tauri::async_runtime::block_on(crate::llm::post_process_with_llm_mode(
+            app,
+            text.clone(),
+            index,
+        )) {
+            Ok(llm_text) => text = llm_text,
+            Err(e) => {
+                warn!("LLM post-processing failed: {}. Using input text.", e);
+                llm_error = Some(e);
+            }
+        }
+    }
+    if options.formatting {
+        text = apply_formatting_rules(app, text);
+    }
+
+    Ok(ProcessingResult { text, llm_error })
+}
+
 fn save_stats_and_history(app: &AppHandle, file_path: &Path, text: &str) -> Result<()> {
     // Calculate duration and size
     let (duration_seconds, wav_size_bytes) = match hound::WavReader::open(file_path) {
@@ -297,19 +495,21 @@ fn save_stats_and_history(app: &AppHandle, file_path: &Path, text: &str) -> Resu
         Err(_) => (0.0, 0),
     };
 
+    record_history_and_stats(app, text, duration_seconds, wav_size_bytes);
+    Ok(())
+}
+
+fn record_history_and_stats(app: &AppHandle, text: &str, duration_seconds: f64, size_bytes: u64) {
     let word_count: u64 = text.split_whitespace().filter(|s| !s.is_empty()).count() as u64;
 
     if let Err(e) = history::add_transcription(app, text.to_string()) {
         error!("Failed to save to history: {}", e);
     }
 
-    if let Err(e) =
-        stats::add_transcription_session(app, word_count, duration_seconds, wav_size_bytes)
+    if let Err(e) = stats::add_transcription_session(app, word_count, duration_seconds, size_bytes)
     {
         error!("Failed to save stats session: {}", e);
     }
-
-    Ok(())
 }
 
 /// Process a recording from raw samples (no WAV file), used by SmartMic.
@@ -346,41 +546,38 @@ pub fn process_recording_from_samples(
 fn transcribe_samples_direct(app: &AppHandle, samples: Vec<f32>) -> Result<TranscriptionResult> {
     let _ = app.emit("llm-processing-start", ());
     let state = app.state::<AudioState>();
-    ensure_engine_loaded(app, &state)?;
+    crate::audio::preload_engine(app)?;
 
     let mut engine_guard = state.engine.lock();
     let engine = engine_guard
         .as_mut()
         .ok_or_else(|| anyhow::anyhow!("Engine not loaded"))?;
 
-    sync_boost_words(engine, &app.state::<Dictionary>().get());
-
-    let result = engine.transcribe_samples(samples, None).map_err(|e| {
-        let _ = app.emit("llm-processing-end", ());
-        anyhow::anyhow!("Transcription failed: {}", e)
-    })?;
+    let dictionary = app.state::<Dictionary>();
+    sync_boost_words(
+        engine,
+        &dictionary.get(),
+        &dictionary.get_weights(),
+        &dictionary.get_suppression().words(),
+    );
+
+    let result = engine
+        .transcribe_samples(samples, final_decode_params(&state))
+        .map_err(|e| {
+            let _ = app.emit("llm-processing-end", ());
+            anyhow::anyhow!("Transcription failed: {}", e)
+        })?;
     let _ = app.emit("llm-processing-end", ());
 
     Ok(result)
 }
 
-/// Load the transcription engine into the AudioState if not already loaded.
-fn ensure_engine_loaded(app: &AppHandle, state: &AudioState) -> Result<()> {
-    let mut engine_guard = state.engine.lock();
-    if engine_guard.is_none() {
-        let model = app.state::<Arc<Model>>();
-        let model_path = model
-            .get_model_path()
-            .map_err(|e| anyhow::anyhow!("Failed to get model path: {}", e))?;
-
-        let new_engine =
-            crate::engine::ParakeetEngine::load_int8(&model_path, model.get_tokenizer_path())
-                .map_err(|e| anyhow::anyhow!("Failed to load model: {}", e))?;
-
-        *engine_guard = Some(new_engine);
-        info!("Model loaded and cached in memory");
-    }
-    Ok(())
+/// Inference params of the decodes that produce the final text. The
+/// streaming preview and the wake word keep the greedy default.
+fn final_decode_params(state: &AudioState) -> Option<AsrInferenceParams> {
+    Some(AsrInferenceParams {
+        beam_width: state.get_beam_width(),
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,168 @@
+use crate::audio::recorder::{build_mono_stream, SendStream};
+use crate::audio::types::AudioState;
+use anyhow::{Context, Result};
+use cpal::traits::{DeviceTrait, StreamTrait};
+use log::{debug, info, warn};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+/// Longest pre-roll offered in the settings.
+pub const MAX_PRE_ROLL_MS: u64 = 1000;
+/// Audio kept beyond the pre-roll for the start beep, which delays the
+/// recording stream.
+const BEEP_MARGIN_MS: u64 = 500;
+/// A ring not fed for this long belongs to a stream that stopped (an
+/// unplugged microphone) and is not prepended.
+const STALE_AFTER: Duration = Duration::from_millis(500);
+
+/// The last samples of the microphone, at the stream rate.
+struct Ring {
+    samples: VecDeque<f32>,
+    capacity: usize,
+    last_push: Option<Instant>,
+}
+
+impl Ring {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            last_push: None,
+        }
+    }
+
+    fn push(&mut self, samples: &[f32], now: Instant) {
+        let incoming = &samples[samples.len().saturating_sub(self.capacity)..];
+        let overflow = (self.samples.len() + incoming.len()).saturating_sub(self.capacity);
+        self.samples.drain(..overflow);
+        self.samples.extend(incoming);
+        self.last_push = Some(now);
+    }
+
+    /// The last `count` samples, none when the stream stopped feeding the ring.
+    fn latest(&self, count: usize, now: Instant) -> Vec<f32> {
+        match self.last_push {
+            Some(last) if now.duration_since(last) <= STALE_AFTER => {}
+            _ => return Vec::new(),
+        }
+        let start = self.samples.len().saturating_sub(count);
+        self.samples.range(start..).copied().collect()
+    }
+}
+
+/// Keeps the microphone open between recordings so the words said just
+/// before the shortcut can be prepended to the recording. It listens to the
+/// same device as the wake word listener.
+pub struct PreRoll {
+    _stream: SendStream,
+    ring: Arc<Mutex<Ring>>,
+    sample_rate: u32,
+}
+
+impl PreRoll {
+    fn open(app: &AppHandle, duration_ms: u64) -> Result<Self> {
+        let device = crate::wake_word::wake_word::get_device(app)?;
+        let config = device
+            .default_input_config()
+            .context("No input config available")?;
+        let sample_rate = config.sample_rate();
+        let capacity = ((duration_ms + BEEP_MARGIN_MS) * sample_rate as u64 / 1000) as usize;
+        let ring = Arc::new(Mutex::new(Ring::new(capacity)));
+
+        let (tx, rx) = mpsc::channel::<Vec<f32>>();
+        let stream = build_mono_stream(&device, &config, tx)?;
+        stream.play().context("Failed to start pre-roll stream")?;
+
+        // Ends when the stream is dropped, which drops its sender.
+        let feeder_ring = ring.clone();
+        std::thread::spawn(move || {
+            while let Ok(mono) = rx.recv() {
+                feeder_ring.lock().push(&mono, Instant::now());
+            }
+        });
+
+        Ok(Self {
+            _stream: SendStream(Some(stream)),
+            ring,
+            sample_rate,
+        })
+    }
+}
+
+/// Open or close the pre-roll stream to match the settings. An open stream
+/// is reopened, so it follows a change of microphone.
+pub fn apply(app: &AppHandle) {
+    let state = app.state::<AudioState>();
+    let mut pre_roll = state.pre_roll.lock();
+    *pre_roll = None;
+
+    let duration_ms = crate::settings::load_settings(app)
+        .pre_roll_ms
+        .min(MAX_PRE_ROLL_MS);
+    if duration_ms == 0 {
+        return;
+    }
+    match PreRoll::open(app, duration_ms) {
+        Ok(opened) => {
+            info!("Pre-roll of {} ms listening", duration_ms);
+            *pre_roll = Some(opened);
+        }
+        Err(e) => warn!("Pre-roll unavailable: {}", e),
+    }
+}
+
+/// The audio of the last `duration`, to prepend to a recording at
+/// `sample_rate`. Empty when the pre-roll is off or stalled, or when its
+/// device runs at another rate than the recording.
+pub fn snapshot(app: &AppHandle, sample_rate: u32, duration: Duration) -> Vec<f32> {
+    let state = app.state::<AudioState>();
+    let pre_roll = state.pre_roll.lock();
+    let Some(pre_roll) = pre_roll.as_ref() else {
+        return Vec::new();
+    };
+    if pre_roll.sample_rate != sample_rate {
+        debug!(
+            "Pre-roll skipped: captured at {} Hz, recording at {} Hz",
+            pre_roll.sample_rate, sample_rate
+        );
+        return Vec::new();
+    }
+    let count = (duration.as_secs_f64() * sample_rate as f64) as usize;
+    let samples = pre_roll.ring.lock().latest(count, Instant::now());
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_keeps_last_samples() {
+        let now = Instant::now();
+        let mut ring = Ring::new(4);
+        ring.push(&[1.0, 2.0, 3.0], now);
+        ring.push(&[4.0, 5.0], now);
+        assert_eq!(ring.latest(10, now), vec![2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(ring.latest(2, now), vec![4.0, 5.0]);
+
+        ring.push(&[6.0, 7.0, 8.0, 9.0, 10.0], now);
+        assert_eq!(ring.latest(10, now), vec![7.0, 8.0, 9.0, 10.0]);
+    }
+
+    #[test]
+    fn test_ring_ignores_stalled_stream() {
+        let now = Instant::now();
+        let mut ring = Ring::new(4);
+        assert!(ring.latest(4, now).is_empty());
+
+        ring.push(&[1.0, 2.0], now);
+        assert_eq!(ring.latest(4, now + STALE_AFTER), vec![1.0, 2.0]);
+        assert!(ring
+            .latest(4, now + STALE_AFTER + Duration::from_millis(1))
+            .is_empty());
+    }
+}
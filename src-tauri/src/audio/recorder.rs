@@ -2,17 +2,19 @@ use crate::audio::chunking::{ChunkJob, Chunker, PreviewLink};
 use crate::audio::helpers::create_wav_writer;
 use crate::audio::output_volume::LoweredState;
 use crate::audio::sound;
+use crate::audio::system_audio::{remote_wav_path, SystemCapture};
 use crate::audio::types::RecordingTrigger;
-use crate::audio::vad::{AdaptiveVad, VoiceActivity};
+use crate::audio::vad::{VoiceActivity, VoiceDetector};
 use anyhow::{Context, Error, Result};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::Device;
 use hound::WavWriter;
-use log::{debug, error, info, trace};
+use log::{debug, error, info, trace, warn};
 use parking_lot::Mutex;
 use std::fs::File;
 use std::io::BufWriter;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::Arc;
 use std::thread::JoinHandle;
@@ -36,10 +38,22 @@ pub struct AudioRecorder {
     stream: SendStream,
     writer_thread: Option<JoinHandle<()>>,
     app_handle: AppHandle,
+    /// Start of the current stretch of recording, `None` while paused or stopped.
     start_time: Option<std::time::Instant>,
+    /// Time recorded before the current stretch.
+    recorded_before: std::time::Duration,
+    /// Shared with the writer and system capture threads, which drop the
+    /// audio while it is set.
+    paused: Arc<AtomicBool>,
     previous_default_source: Option<String>,
     lowered_output: Option<LoweredState>,
     sample_rate: u32,
+    /// Chunk sender and WAV path of the system side, until `start` opens it.
+    remote_capture: Option<(Sender<ChunkJob>, PathBuf)>,
+    system_capture: Option<SystemCapture>,
+    /// Sends the pre-roll ahead of the stream. Dropped once used so the
+    /// writer thread still ends with the stream.
+    pre_roll_tx: Option<Sender<Vec<f32>>>,
 }
 
 impl AudioRecorder {
@@ -51,6 +65,11 @@ impl AudioRecorder {
             .lock()
             .as_ref()
             .map(|pipeline| pipeline.sender());
+        let remote_capture = audio_state
+            .remote_pipeline
+            .lock()
+            .as_ref()
+            .map(|pipeline| (pipeline.sender(), remote_wav_path(file_path)));
 
         let (device, previous_default_source) = Self::get_device(app.clone())?;
         let config = match device
@@ -77,6 +96,7 @@ impl AudioRecorder {
         };
         let writer_arc = Arc::new(Mutex::new(Some(writer)));
 
+        let paused = Arc::new(AtomicBool::new(false));
         let preview_link = PreviewLink::from_state(
             &audio_state,
             crate::settings::load_settings(&app).streaming_preview,
@@ -88,9 +108,10 @@ impl AudioRecorder {
             chunk_cfg,
             preview_link,
             sample_rate: config.sample_rate(),
+            paused: paused.clone(),
         };
 
-        let (stream, writer_thread) =
+        let (stream, pre_roll_tx, writer_thread) =
             match build_stream(&device, &config, writer_arc.clone(), writer_ctx) {
                 Ok(parts) => parts,
                 Err(error) => {
@@ -101,15 +122,27 @@ impl AudioRecorder {
                 }
             };
 
+        // No pre-roll after a wake word, whose segment it would repeat, nor
+        // in dual capture, where it would shift the microphone words ahead of
+        // the system ones, which start with the stream.
+        let pre_roll_tx = (recording_trigger != RecordingTrigger::WakeWord
+            && remote_capture.is_none())
+        .then_some(pre_roll_tx);
+
         Ok(Self {
             writer: writer_arc,
             stream: SendStream(Some(stream)),
             writer_thread: Some(writer_thread),
             app_handle: app,
             start_time: None,
+            recorded_before: std::time::Duration::ZERO,
+            paused,
             previous_default_source,
             lowered_output: None,
             sample_rate: config.sample_rate(),
+            remote_capture,
+            system_capture: None,
+            pre_roll_tx,
         })
     }
 
@@ -138,6 +171,7 @@ impl AudioRecorder {
 
     pub fn start(&mut self, play_sound: bool) -> Result<()> {
         if let Some(stream) = &self.stream.0 {
+            let requested_at = std::time::Instant::now();
             let settings = crate::settings::load_settings(&self.app_handle);
             if play_sound {
                 sound::play_sound(&self.app_handle, sound::Sound::StartRecording);
@@ -145,8 +179,33 @@ impl AudioRecorder {
                     std::thread::sleep(START_BEEP_DURATION);
                 }
             }
+            // Queued before the stream plays so it comes first. It spans the
+            // beep wait too, so the beep does not cut the first words.
+            if let Some(tx) = self.pre_roll_tx.take() {
+                let window =
+                    std::time::Duration::from_millis(settings.pre_roll_ms) + requested_at.elapsed();
+                let pre_roll =
+                    crate::audio::pre_roll::snapshot(&self.app_handle, self.sample_rate, window);
+                if !pre_roll.is_empty() {
+                    debug!("Prepending {} pre-roll samples", pre_roll.len());
+                    let _ = tx.send(pre_roll);
+                }
+            }
             stream.play().context("Failed to start stream")?;
             self.start_time = Some(std::time::Instant::now());
+            if let Some((tx, wav_path)) = self.remote_capture.take() {
+                match SystemCapture::start(&self.app_handle, tx, &wav_path, self.paused.clone()) {
+                    Ok(capture) => self.system_capture = Some(capture),
+                    Err(e) => {
+                        warn!("Recording the microphone only: {}", e);
+                        // Without a system side, the transcript gets no labels.
+                        let state = self.app_handle.state::<crate::audio::types::AudioState>();
+                        if let Some(pipeline) = state.remote_pipeline.lock().take() {
+                            pipeline.cancel();
+                        }
+                    }
+                }
+            }
             if settings.lower_output_while_recording {
                 self.lowered_output = crate::audio::output_volume::lower_and_persist(
                     &self.app_handle,
@@ -157,6 +216,35 @@ impl AudioRecorder {
         Ok(())
     }
 
+    /// Stop keeping the audio until `resume`. The stream stays open so
+    /// resuming is instant.
+    pub fn pause(&mut self) {
+        if self.paused.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        if let Some(start) = self.start_time.take() {
+            self.recorded_before += start.elapsed();
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if self.paused.swap(false, Ordering::SeqCst) {
+            self.start_time = Some(std::time::Instant::now());
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Time spent recording, pauses excluded.
+    pub fn recorded_duration(&self) -> std::time::Duration {
+        self.recorded_before
+            + self
+                .start_time
+                .map_or(std::time::Duration::ZERO, |s| s.elapsed())
+    }
+
     fn restore_output_volume(&mut self) {
         if let Some(state) = self.lowered_output.take() {
             crate::audio::output_volume::restore_and_clear(&self.app_handle, &state);
@@ -165,7 +253,11 @@ impl AudioRecorder {
 
     pub fn close_input(&mut self) {
         self.stream.0 = None;
+        self.pre_roll_tx = None;
         self.start_time = None;
+        if let Some(capture) = self.system_capture.as_mut() {
+            capture.close();
+        }
     }
 
     pub fn stop(&mut self, play_sound: bool) -> Result<()> {
@@ -178,6 +270,9 @@ impl AudioRecorder {
             let _ = handle.join();
             debug!("Writer thread drained in {:?}", drain_start.elapsed());
         }
+        if let Some(capture) = self.system_capture.take() {
+            capture.stop();
+        }
 
         // Finalize writer
         let mut result = Ok(());
@@ -215,6 +310,7 @@ struct WriterThreadCtx {
     chunk_cfg: Option<Sender<ChunkJob>>,
     preview_link: Option<PreviewLink>,
     sample_rate: u32,
+    paused: Arc<AtomicBool>,
 }
 
 fn build_stream(
@@ -222,19 +318,28 @@ fn build_stream(
     config: &cpal::SupportedStreamConfig,
     writer: SharedWriter,
     ctx: WriterThreadCtx,
-) -> Result<(cpal::Stream, JoinHandle<()>)> {
+) -> Result<(cpal::Stream, Sender<Vec<f32>>, JoinHandle<()>)> {
     let (tx, rx) = mpsc::channel::<Vec<f32>>();
 
-    let stream = match config.sample_format() {
+    let stream = build_mono_stream(device, config, tx.clone())?;
+
+    let writer_thread = spawn_writer_thread(rx, writer, ctx);
+
+    Ok((stream, tx, writer_thread))
+}
+
+/// Input stream sending each callback buffer, downmixed to mono, to `tx`.
+pub(crate) fn build_mono_stream(
+    device: &cpal::Device,
+    config: &cpal::SupportedStreamConfig,
+    tx: Sender<Vec<f32>>,
+) -> Result<cpal::Stream> {
+    match config.sample_format() {
         cpal::SampleFormat::F32 => build_stream_impl::<f32>(device, config, tx),
         cpal::SampleFormat::I16 => build_stream_impl::<i16>(device, config, tx),
         cpal::SampleFormat::I32 => build_stream_impl::<i32>(device, config, tx),
         f => Err(anyhow::anyhow!("Unsupported sample format: {:?}", f)),
-    }?;
-
-    let writer_thread = spawn_writer_thread(rx, writer, ctx);
-
-    Ok((stream, writer_thread))
+    }
 }
 
 fn build_stream_impl<T>(
@@ -293,6 +398,7 @@ fn spawn_writer_thread(
         chunk_cfg,
         preview_link,
         sample_rate,
+        paused,
     } = ctx;
     std::thread::spawn(move || {
         // State for simple RMS + EMA smoothing and throttled emission
@@ -312,11 +418,36 @@ fn spawn_writer_thread(
         let mut silence_start: Option<std::time::Instant> = None;
         let mut silence_auto_stop_triggered = false;
         let mut has_speech_started = false;
-        let mut silence_auto_stop_vad = AdaptiveVad::new();
 
-        let mut chunker = chunk_cfg.map(|tx| Chunker::new(tx, sample_rate, preview_link.clone()));
+        let mut chunker = chunk_cfg.map(|tx| {
+            let vad = VoiceDetector::from_settings(&app, sample_rate);
+            let backend = crate::settings::load_settings(&app).asr_backend;
+            Chunker::new(tx, sample_rate, preview_link.clone(), vad, backend)
+        });
+        // The silence timeout reads the chunker's model when there is one,
+        // so a recording runs a single neural session.
+        let mut silence_auto_stop_vad = match chunker.as_ref() {
+            _ if !is_wake_word || silence_auto_stop_ms == 0 => VoiceDetector::adaptive(),
+            Some(chunker) => VoiceDetector::following(chunker.vad()),
+            None => VoiceDetector::from_settings(&app, sample_rate),
+        };
 
+        let mut was_paused = false;
         while let Ok(mono) = rx.recv() {
+            // Nothing is written or chunked while paused. The chunk is cut at
+            // the pause and the silence timer starts over on resume.
+            if paused.load(Ordering::SeqCst) {
+                if !was_paused {
+                    was_paused = true;
+                    if let Some(chunker) = chunker.as_mut() {
+                        chunker.cut_at_pause();
+                    }
+                }
+                silence_start = None;
+                continue;
+            }
+            was_paused = false;
+
             {
                 let mut recorder = writer.lock();
                 if let Some(writer) = recorder.as_mut() {
@@ -337,6 +468,7 @@ fn spawn_writer_thread(
             if let Some(chunker) = chunker.as_mut() {
                 chunker.push_samples(&mono);
             }
+            silence_auto_stop_vad.push_samples(&mono);
 
             // Throttle to ~30 FPS
             if last_emit.elapsed() >= std::time::Duration::from_millis(33) {
@@ -354,8 +486,14 @@ fn spawn_writer_thread(
                         let _ = overlay_window.emit("mic-level", ema_level);
                     }
 
+                    if let Some(chunker) = chunker.as_mut() {
+                        chunker.on_throttle_tick(rms);
+                    }
+                    let shared_probability =
+                        chunker.as_mut().and_then(|c| c.take_speech_probability());
+
                     if is_wake_word && !silence_auto_stop_triggered && silence_auto_stop_ms > 0 {
-                        match silence_auto_stop_vad.update(rms) {
+                        match silence_auto_stop_vad.update_with(rms, shared_probability) {
                             VoiceActivity::Active => {
                                 if !has_speech_started {
                                     info!("Wake word auto-stop: speech detected (rms={:.4})", rms);
@@ -388,10 +526,6 @@ fn spawn_writer_thread(
                         }
                     }
 
-                    if let Some(chunker) = chunker.as_mut() {
-                        chunker.on_throttle_tick(rms);
-                    }
-
                     acc_sum_squares = 0.0;
                     acc_count = 0;
                 } else {
@@ -1,26 +1,39 @@
 use crate::audio::clean_recording::strip_fillers_and_repeats;
+use crate::audio::enhancement::enhance_if;
 use crate::audio::helpers::resample;
+use crate::audio::timestamps::offset_words;
 use crate::audio::types::{AudioState, PreviewSnapshot};
-use crate::dictionary::{correct_transcription, sync_boost_words, Dictionary};
-use crate::engine::transcription_engine::TranscriptionEngine;
+use crate::dictionary::{
+    apply_suppression, correct_transcription, sync_boost_words, Dictionary, SuppressionList,
+};
+use crate::engine::transcription_engine::TranscriptionWord;
 use crate::formatting_rules;
 use crate::formatting_rules::highlighter::{
-    apply_formatting_with_highlights_and_original, HighlightRange,
+    apply_formatting_with_highlights_and_original, FormattedWithHighlights, HighlightRange,
 };
+use crate::smartmic::types::ServerMessage;
 use log::{debug, error, warn};
 use parking_lot::Mutex;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager};
 
 const LOOP_SLEEP_MS: u64 = 30;
 
+/// Audio decoded again before the first uncommitted word, for context.
+const WINDOW_CONTEXT_SECS: f32 = 1.0;
+
 #[derive(Serialize, Clone)]
 pub struct PreviewProvisional {
     pub seq: u64,
     pub text: String,
     pub highlights: Vec<HighlightRange>,
+    /// UTF-16 code units, as JavaScript counts them, at the start of `text`
+    /// that no longer change until the chunk is frozen; the rest is
+    /// tentative.
+    pub committed_len: usize,
 }
 
 pub fn start_streaming(app: &AppHandle, audio_state: &AudioState, sample_rate: u32) {
@@ -45,6 +58,9 @@ pub fn start_streaming(app: &AppHandle, audio_state: &AudioState, sample_rate: u
     };
 
     let dictionary = app.state::<Dictionary>().get();
+    let weights = app.state::<Dictionary>().get_weights();
+    let suppression = app.state::<Dictionary>().get_suppression();
+    let audio_enhancement = settings.audio_enhancement;
 
     reset_overlay_preview(app);
 
@@ -71,6 +87,9 @@ pub fn start_streaming(app: &AppHandle, audio_state: &AudioState, sample_rate: u
                 sample_rate,
                 formatting_settings,
                 dictionary,
+                weights,
+                suppression,
+                audio_enhancement,
             });
         });
 
@@ -93,6 +112,9 @@ struct StreamingLoopParams {
     sample_rate: u32,
     formatting_settings: formatting_rules::FormattingSettings,
     dictionary: Vec<String>,
+    weights: HashMap<String, f32>,
+    suppression: SuppressionList,
+    audio_enhancement: bool,
 }
 
 fn streaming_thread_loop(params: StreamingLoopParams) {
@@ -104,26 +126,51 @@ fn streaming_thread_loop(params: StreamingLoopParams) {
         sample_rate,
         formatting_settings,
         dictionary,
+        weights,
+        suppression,
+        audio_enhancement,
     } = params;
 
     let mut last_revision: u64 = 0;
+    let mut window = PreviewWindow::default();
 
     while !stop.load(Ordering::SeqCst) {
         let pending = {
             let snap = snapshot.lock();
             if snap.revision != last_revision && !inference_active.load(Ordering::SeqCst) {
-                Some((snap.queue.clone(), snap.generation, snap.revision))
+                Some((
+                    snap.queue.clone(),
+                    snap.generation,
+                    snap.revision,
+                    snap.freeze_point,
+                ))
             } else {
                 None
             }
         };
 
-        if let Some((queue, generation, revision)) = pending {
+        if let Some((queue, generation, revision, freeze_point)) = pending {
             last_revision = revision;
-            if let Some((text, corrected)) =
-                transcribe_samples(&app, &queue, sample_rate, &dictionary)
-            {
-                emit_provisional(&app, generation, &corrected, &text, &formatting_settings);
+            window.follow(generation);
+            let preview = transcribe_window(
+                &app,
+                &mut window,
+                &queue,
+                freeze_point,
+                sample_rate,
+                &dictionary,
+                &weights,
+                &suppression,
+                audio_enhancement,
+            )
+            .and_then(|words| {
+                window.format(&words, &dictionary, &suppression, &formatting_settings)
+            });
+            if let Some(preview) = preview {
+                emit_provisional(&app, &preview);
+            }
+            if let Some(committed) = window.take_committed_update() {
+                share_committed(&app, window.generation, &committed);
             }
         }
 
@@ -131,70 +178,272 @@ fn streaming_thread_loop(params: StreamingLoopParams) {
     }
 }
 
-/// Transcribe a chunk and return it raw and dictionary-corrected.
-fn transcribe_samples(
+/// Sliding window over the preview queue of one chunk. Words that end before
+/// a pause the chunker would cut at are committed, as the chunk would have
+/// been frozen there, and later ticks only decode the audio after them. The
+/// final transcription does not use it.
+#[derive(Default)]
+struct PreviewWindow {
+    generation: u64,
+    /// Committed words, timed from the start of the queue.
+    committed: Vec<TranscriptionWord>,
+    /// Position in the queue up to which the words are committed.
+    committed_end: usize,
+    /// Formatted text of the first `formatted` committed words. Later ticks
+    /// of the chunk emit it unchanged, so the committed part only grows.
+    committed_text: String,
+    committed_highlights: Vec<HighlightRange>,
+    formatted: usize,
+    /// Length of `committed_text` already sent to SmartMic.
+    shared: usize,
+}
+
+impl PreviewWindow {
+    /// Start over when the chunker has moved on to a new chunk.
+    fn follow(&mut self, generation: u64) {
+        if generation != self.generation {
+            *self = PreviewWindow {
+                generation,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Where the next decode starts in the queue.
+    fn start(&self, sample_rate: u32) -> usize {
+        let context = (WINDOW_CONTEXT_SECS * sample_rate as f32) as usize;
+        self.committed_end.saturating_sub(context)
+    }
+
+    /// Merge the words decoded from `start` to the end of the queue, commit
+    /// the ones ending before `freeze_point` and return every word of the
+    /// queue.
+    fn update(
+        &mut self,
+        start: usize,
+        freeze_point: usize,
+        sample_rate: u32,
+        words: Vec<TranscriptionWord>,
+    ) -> Vec<TranscriptionWord> {
+        let sr = sample_rate.max(1) as f32;
+        let committed_secs = self.committed_end as f32 / sr;
+        // Words of the context audio are already committed.
+        let fresh: Vec<TranscriptionWord> = offset_words(words, start as f32 / sr)
+            .into_iter()
+            .filter(|word| (word.start + word.end) / 2.0 >= committed_secs)
+            .collect();
+
+        let mut settled = 0;
+        if freeze_point > self.committed_end {
+            let limit = freeze_point as f32 / sr;
+            settled = fresh.iter().take_while(|word| word.end <= limit).count();
+            // Up to the next word, or the pause when nothing was said since.
+            let end_secs = fresh
+                .get(settled)
+                .map_or(limit, |word| word.start.min(limit));
+            self.committed_end = self.committed_end.max((end_secs * sr) as usize);
+            self.committed.extend_from_slice(&fresh[..settled]);
+        }
+
+        self.committed
+            .iter()
+            .chain(&fresh[settled..])
+            .cloned()
+            .collect()
+    }
+
+    /// Preview of `words`, as returned by `update`: the committed text, with
+    /// the words committed since the last tick appended, then the tentative
+    /// words formatted again.
+    fn format(
+        &mut self,
+        words: &[TranscriptionWord],
+        dictionary: &[String],
+        suppression: &SuppressionList,
+        formatting_settings: &formatting_rules::FormattingSettings,
+    ) -> Option<PreviewProvisional> {
+        let (committed, tentative) = words.split_at(self.committed.len().min(words.len()));
+        // The short text correction is meant for a whole transcription, not
+        // for the pieces of a longer one.
+        let mut piece_settings = formatting_settings.clone();
+        piece_settings.built_in.short_text_correction = 0;
+
+        if self.formatted < committed.len() {
+            let piece = format_words(
+                &committed[self.formatted..],
+                dictionary,
+                suppression,
+                &piece_settings,
+            );
+            let piece = FormattedWithHighlights {
+                text: piece.text.trim_end().to_string(),
+                highlights: piece.highlights,
+            };
+            append_formatted(
+                &mut self.committed_text,
+                &mut self.committed_highlights,
+                piece,
+            );
+            self.formatted = committed.len();
+        }
+
+        let tail_settings = if self.committed_text.is_empty() {
+            formatting_settings
+        } else {
+            &piece_settings
+        };
+        let mut text = self.committed_text.clone();
+        let mut highlights = self.committed_highlights.clone();
+        append_formatted(
+            &mut text,
+            &mut highlights,
+            format_words(tentative, dictionary, suppression, tail_settings),
+        );
+        if text.trim().is_empty() {
+            return None;
+        }
+        Some(PreviewProvisional {
+            seq: self.generation,
+            text,
+            highlights,
+            committed_len: self.committed_text.encode_utf16().count(),
+        })
+    }
+
+    /// The committed text, when it grew since the last call.
+    fn take_committed_update(&mut self) -> Option<String> {
+        if self.committed_text.len() <= self.shared {
+            return None;
+        }
+        self.shared = self.committed_text.len();
+        Some(self.committed_text.clone())
+    }
+}
+
+/// Clean, correct and format `words`.
+fn format_words(
+    words: &[TranscriptionWord],
+    dictionary: &[String],
+    suppression: &SuppressionList,
+    formatting_settings: &formatting_rules::FormattingSettings,
+) -> FormattedWithHighlights {
+    let (cleaned, corrected) = clean_and_correct(words, dictionary, suppression);
+    apply_formatting_with_highlights_and_original(
+        corrected,
+        cleaned,
+        formatting_settings,
+        dictionary,
+    )
+}
+
+/// Append `piece` to `text` after a space, shifting its highlights.
+fn append_formatted(
+    text: &mut String,
+    highlights: &mut Vec<HighlightRange>,
+    piece: FormattedWithHighlights,
+) {
+    if piece.text.trim().is_empty() {
+        return;
+    }
+    if !text.is_empty() {
+        text.push(' ');
+    }
+    let offset = text.chars().count();
+    text.push_str(&piece.text);
+    highlights.extend(piece.highlights.into_iter().map(|h| HighlightRange {
+        start: h.start + offset,
+        end: h.end + offset,
+    }));
+}
+
+/// Transcribe the uncommitted end of the queue and return every word of the
+/// queue.
+#[allow(clippy::too_many_arguments)]
+fn transcribe_window(
     app: &AppHandle,
-    samples: &[f32],
+    window: &mut PreviewWindow,
+    queue: &[f32],
+    freeze_point: usize,
     sample_rate: u32,
     dictionary: &[String],
-) -> Option<(String, String)> {
+    weights: &HashMap<String, f32>,
+    suppression: &SuppressionList,
+    audio_enhancement: bool,
+) -> Option<Vec<TranscriptionWord>> {
+    let start = window.start(sample_rate).min(queue.len());
+    let samples = &queue[start..];
     let resampled = if sample_rate != 16000 {
         resample(samples, sample_rate as usize, 16000)
     } else {
         samples.to_vec()
     };
+    // Same cleanup as the final chunk, so the preview matches what is pasted.
+    let enhanced = enhance_if(audio_enhancement, resampled);
 
-    let state = app.state::<AudioState>();
-    let mut engine_guard = state.engine.lock();
-    let Some(engine) = engine_guard.as_mut() else {
-        debug!("Engine not loaded for streaming transcription");
-        return None;
-    };
-    sync_boost_words(engine, dictionary);
-    match engine.transcribe_samples(resampled, None) {
-        Ok(result) => {
-            let cleaned = strip_fillers_and_repeats(result.text.trim());
-            if cleaned.is_empty() {
-                None
-            } else {
-                let corrected =
-                    correct_transcription(&cleaned, dictionary, &result.word_confidences);
-                Some((cleaned, corrected))
+    let result = {
+        let state = app.state::<AudioState>();
+        let mut engine_guard = state.engine.lock();
+        let Some(engine) = engine_guard.as_mut() else {
+            debug!("Engine not loaded for streaming transcription");
+            return None;
+        };
+        sync_boost_words(engine, dictionary, weights, &suppression.words());
+        match engine.transcribe_samples(enhanced, None) {
+            Ok(result) => result,
+            Err(e) => {
+                debug!("Streaming transcription error: {}", e);
+                return None;
             }
         }
-        Err(e) => {
-            debug!("Streaming transcription error: {}", e);
-            None
-        }
-    }
+    };
+
+    Some(window.update(start, freeze_point, sample_rate, result.words))
 }
 
-fn emit_provisional(
-    app: &AppHandle,
-    seq: u64,
-    text: &str,
-    original_text: &str,
-    formatting_settings: &formatting_rules::FormattingSettings,
-) {
-    let dictionary = app.state::<Dictionary>().get();
-    let formatted = apply_formatting_with_highlights_and_original(
-        text.to_string(),
-        original_text.to_string(),
-        formatting_settings,
-        &dictionary,
-    );
+/// Join decoded words and return the text raw and dictionary-corrected.
+pub(crate) fn clean_and_correct(
+    words: &[TranscriptionWord],
+    dictionary: &[String],
+    suppression: &SuppressionList,
+) -> (String, String) {
+    let text = words
+        .iter()
+        .map(|word| word.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let confidences: Vec<(String, f32)> = words
+        .iter()
+        .map(|word| (word.text.clone(), word.confidence))
+        .collect();
 
-    let payload = PreviewProvisional {
-        seq,
-        text: formatted.text,
-        highlights: formatted.highlights,
-    };
+    let cleaned = strip_fillers_and_repeats(&text);
+    let corrected = apply_suppression(
+        &correct_transcription(&cleaned, dictionary, &confidences),
+        &suppression.words(),
+        suppression.fallback,
+    );
+    (cleaned, corrected)
+}
 
+fn emit_provisional(app: &AppHandle, payload: &PreviewProvisional) {
+    crate::http_api::events::publish_event(app, "preview-provisional", payload);
     if let Some(window) = app.get_webview_window("recording_overlay") {
-        let _ = window.emit("preview-provisional", &payload);
+        let _ = window.emit("preview-provisional", payload);
     }
 }
 
+/// SmartMic only gets the committed text: a phone mirroring the dictation
+/// shows nothing the model may still revise.
+fn share_committed(app: &AppHandle, seq: u64, text: &str) {
+    crate::smartmic::notify_connected_device(
+        app,
+        &ServerMessage::Preview {
+            seq,
+            text: text.to_string(),
+        },
+    );
+}
+
 fn reset_overlay_preview(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("recording_overlay") {
         let _ = window.emit(
@@ -203,9 +452,11 @@ fn reset_overlay_preview(app: &AppHandle) {
                 seq: 0,
                 text: String::new(),
                 highlights: vec![],
+                committed_len: 0,
             },
         );
     }
+    share_committed(app, 0, "");
 }
 
 pub fn stop_streaming(app: &AppHandle, audio_state: &AudioState) {
@@ -229,12 +480,153 @@ pub fn stop_streaming(app: &AppHandle, audio_state: &AudioState) {
 mod tests {
     use super::*;
 
+    const SR: u32 = 16000;
+
+    fn texts(words: &[TranscriptionWord]) -> Vec<&str> {
+        words.iter().map(|w| w.text.as_str()).collect()
+    }
+
+    fn secs(s: f32) -> usize {
+        (s * SR as f32) as usize
+    }
+
+    #[test]
+    fn preview_window_decodes_whole_short_queue() {
+        let mut window = PreviewWindow::default();
+        assert_eq!(window.start(SR), 0);
+        let words = window.update(0, 0, SR, vec![TranscriptionWord::new("bonjour", 0.5, 1.0)]);
+        assert_eq!(texts(&words), ["bonjour"]);
+        assert_eq!(window.start(SR), 0);
+    }
+
+    #[test]
+    fn preview_window_commits_words_before_the_pause_and_skips_context() {
+        let mut window = PreviewWindow::default();
+        let words = window.update(
+            0,
+            secs(6.0),
+            SR,
+            vec![
+                TranscriptionWord::new("un", 1.0, 1.5),
+                TranscriptionWord::new("deux", 4.0, 4.5),
+                TranscriptionWord::new("trois", 6.5, 7.0),
+            ],
+        );
+        assert_eq!(texts(&words), ["un", "deux", "trois"]);
+        // "trois" follows the pause: committed up to 6s, decoded again from 5s.
+        let start = window.start(SR);
+        assert_eq!(start, secs(5.0));
+
+        // The context repeats "deux"; "trois" may now be revised.
+        let words = window.update(
+            start,
+            secs(6.0),
+            SR,
+            vec![
+                TranscriptionWord::new("deux", 0.0, 0.3),
+                TranscriptionWord::new("trois!", 1.0, 1.5),
+            ],
+        );
+        assert_eq!(texts(&words), ["un", "deux", "trois!"]);
+    }
+
+    #[test]
+    fn preview_window_keeps_words_tentative_until_a_pause() {
+        let mut window = PreviewWindow::default();
+        let words: Vec<TranscriptionWord> = (0..20)
+            .map(|i| TranscriptionWord::new("mot", i as f32, i as f32 + 0.8))
+            .collect();
+        assert_eq!(window.update(0, 0, SR, words).len(), 20);
+        assert_eq!(window.start(SR), 0);
+    }
+
+    #[test]
+    fn preview_window_commits_silence_and_resets_on_new_chunk() {
+        let mut window = PreviewWindow::default();
+        window.update(0, secs(8.0), SR, Vec::new());
+        assert_eq!(window.start(SR), secs(7.0));
+        window.follow(1);
+        assert_eq!(window.start(SR), 0);
+    }
+
+    #[test]
+    fn preview_committed_text_only_grows() {
+        let mut window = PreviewWindow::default();
+        let mut settings = formatting_rules::FormattingSettings::default();
+        settings.built_in.trailing_space = false;
+        // Would rewrite the committed "tout" once "le" follows it.
+        settings
+            .rules
+            .push(formatting_rules::types::FormattingRule {
+                trigger: "tout le".to_string(),
+                replacement: "toute la".to_string(),
+                enabled: true,
+                ..Default::default()
+            });
+        let suppression = SuppressionList::default();
+
+        let words = window.update(
+            0,
+            secs(6.0),
+            SR,
+            vec![
+                TranscriptionWord::new("Bonjour", 1.0, 1.5),
+                TranscriptionWord::new("tout", 4.0, 4.5),
+            ],
+        );
+        let first = window.format(&words, &[], &suppression, &settings).unwrap();
+        assert_eq!(first.text, "Bonjour tout");
+        assert_eq!(first.committed_len, 12);
+        assert_eq!(
+            window.take_committed_update().as_deref(),
+            Some("Bonjour tout")
+        );
+
+        let start = window.start(SR);
+        let words = window.update(
+            start,
+            secs(6.0),
+            SR,
+            vec![
+                TranscriptionWord::new("le", 2.0, 2.3),
+                TranscriptionWord::new("monde.", 2.5, 3.0),
+            ],
+        );
+        let second = window.format(&words, &[], &suppression, &settings).unwrap();
+        assert_eq!(second.text, "Bonjour tout le monde.");
+        assert_eq!(second.committed_len, 12);
+        assert_eq!(window.take_committed_update(), None);
+    }
+
+    #[test]
+    fn preview_committed_len_counts_utf16_units() {
+        let mut window = PreviewWindow::default();
+        let mut settings = formatting_rules::FormattingSettings::default();
+        settings.built_in.trailing_space = false;
+        let words = window.update(
+            0,
+            secs(3.0),
+            SR,
+            vec![
+                TranscriptionWord::new("Noël", 1.0, 1.5),
+                TranscriptionWord::new("🎄", 2.0, 2.5),
+            ],
+        );
+        let preview = window
+            .format(&words, &[], &SuppressionList::default(), &settings)
+            .unwrap();
+        assert_eq!(preview.text, "Noël 🎄");
+        // The overlay slices `text` with this length in JavaScript.
+        assert_eq!(preview.committed_len, 7);
+    }
+
     #[test]
     fn preview_provisional_serialization() {
         let t = PreviewProvisional {
             seq: 0,
             text: "Bonjour, je voudrais réserver".to_string(),
             highlights: vec![],
+            committed_len: 0,
         };
         let json = serde_json::to_string(&t).expect("serialize");
         assert!(json.contains("Bonjour"));
@@ -247,10 +639,12 @@ mod tests {
             seq: 3,
             text: "Bonjour Monsieur Dupont".to_string(),
             highlights: vec![HighlightRange { start: 8, end: 23 }],
+            committed_len: 7,
         };
         let json = serde_json::to_string(&t).expect("serialize");
         assert!(json.contains("\"start\":8"));
         assert!(json.contains("\"end\":23"));
         assert!(json.contains("\"seq\":3"));
+        assert!(json.contains("\"committed_len\":7"));
     }
 }
@@ -0,0 +1,235 @@
+use crate::audio::timestamps::closes_segment;
+use crate::engine::transcription_engine::TranscriptionWord;
+
+/// Longest subtitle line, the common broadcast limit.
+const MAX_LINE_CHARS: usize = 42;
+const MAX_CUE_LINES: usize = 2;
+/// Longest time a single cue stays on screen.
+const MAX_CUE_SECS: f32 = 7.0;
+/// Shortest time a cue stays on screen, unless the next cue starts earlier.
+const MIN_CUE_SECS: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: f32,
+    pub end: f32,
+    pub lines: Vec<String>,
+}
+
+impl Cue {
+    fn new(text: &str, start: f32, end: f32) -> Self {
+        Self {
+            start,
+            end,
+            lines: vec![text.to_string()],
+        }
+    }
+
+    fn accepts(&self, text: &str, end: f32) -> bool {
+        if end - self.start > MAX_CUE_SECS {
+            return false;
+        }
+        fits_on_line(self.lines.last(), text) || self.lines.len() < MAX_CUE_LINES
+    }
+
+    fn push(&mut self, text: &str, end: f32) {
+        match self.lines.last_mut() {
+            Some(line) if fits_on_line(Some(line), text) => {
+                line.push(' ');
+                line.push_str(text);
+            }
+            _ => self.lines.push(text.to_string()),
+        }
+        self.end = end;
+    }
+}
+
+fn fits_on_line(line: Option<&String>, text: &str) -> bool {
+    line.is_some_and(|line| line.chars().count() + 1 + text.chars().count() <= MAX_LINE_CHARS)
+}
+
+/// Group timed words into subtitle cues of at most `MAX_CUE_LINES` lines of
+/// `MAX_CUE_SECS`. A cue never spans two segments, so sentences and long
+/// pauses start a new cue.
+pub fn build_cues(words: &[TranscriptionWord]) -> Vec<Cue> {
+    let mut cues: Vec<Cue> = Vec::new();
+    let mut current: Option<Cue> = None;
+
+    for (i, word) in words.iter().enumerate() {
+        let text = word.text.trim();
+        match current.as_mut() {
+            Some(cue) if cue.accepts(text, word.end) => cue.push(text, word.end),
+            _ => {
+                cues.extend(current.take());
+                current = Some(Cue::new(text, word.start, word.end));
+            }
+        }
+        if closes_segment(words, i) {
+            cues.extend(current.take());
+        }
+    }
+
+    // Keep short cues readable, without overlapping the next one.
+    let next_starts: Vec<f32> = cues
+        .iter()
+        .skip(1)
+        .map(|cue| cue.start)
+        .chain(std::iter::once(f32::MAX))
+        .collect();
+    for (cue, limit) in cues.iter_mut().zip(next_starts) {
+        cue.end = cue.end.max((cue.start + MIN_CUE_SECS).min(limit));
+    }
+
+    cues
+}
+
+pub fn render_subtitles(words: &[TranscriptionWord], format: SubtitleFormat) -> String {
+    let cues = build_cues(words);
+    match format {
+        SubtitleFormat::Srt => to_srt(&cues),
+        SubtitleFormat::Vtt => to_vtt(&cues),
+    }
+}
+
+fn to_srt(cues: &[Cue]) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(cue.start, ','),
+            format_timestamp(cue.end, ','),
+            cue.lines.join("\n")
+        ));
+    }
+    out
+}
+
+fn to_vtt(cues: &[Cue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_timestamp(cue.start, '.'),
+            format_timestamp(cue.end, '.'),
+            cue.lines.join("\n")
+        ));
+    }
+    out
+}
+
+/// `HH:MM:SS,mmm` for SRT, `HH:MM:SS.mmm` for WebVTT.
+fn format_timestamp(secs: f32, millis_separator: char) -> String {
+    let total_ms = (secs.max(0.0) * 1000.0).round() as u64;
+    let (hours, rest) = (total_ms / 3_600_000, total_ms % 3_600_000);
+    let (minutes, rest) = (rest / 60_000, rest % 60_000);
+    let (seconds, millis) = (rest / 1000, rest % 1000);
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        hours, minutes, seconds, millis_separator, millis
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Evenly spaced words without punctuation or pauses.
+    fn speech(texts: &[&str], step: f32) -> Vec<TranscriptionWord> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                TranscriptionWord::new(text, i as f32 * step, i as f32 * step + step * 0.8)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn format_timestamp_uses_the_format_separator() {
+        assert_eq!(format_timestamp(3723.456, ','), "01:02:03,456");
+        assert_eq!(format_timestamp(0.5, '.'), "00:00:00.500");
+        assert_eq!(format_timestamp(-0.1, ','), "00:00:00,000");
+    }
+
+    #[test]
+    fn build_cues_wraps_lines_at_the_char_limit() {
+        let words = speech(&["abcdefghij"; 6], 0.3);
+        let cues = build_cues(&words);
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].lines.len(), 2);
+        assert!(cues[0]
+            .lines
+            .iter()
+            .all(|line| line.chars().count() <= MAX_LINE_CHARS));
+    }
+
+    #[test]
+    fn build_cues_starts_a_new_cue_past_two_lines() {
+        let words = speech(&["abcdefghij"; 12], 0.3);
+        let cues = build_cues(&words);
+        assert!(cues.len() >= 2);
+        assert!(cues.iter().all(|cue| cue.lines.len() <= MAX_CUE_LINES));
+        assert!(cues[0].end <= cues[1].start);
+    }
+
+    #[test]
+    fn build_cues_limits_cue_duration() {
+        let words = speech(&["a"; 20], 1.0);
+        let cues = build_cues(&words);
+        assert!(cues.len() >= 3);
+        assert!(cues.iter().all(|cue| cue.end - cue.start <= MAX_CUE_SECS));
+    }
+
+    #[test]
+    fn build_cues_splits_on_sentence_end() {
+        let words = [
+            TranscriptionWord::new("Bonjour.", 0.0, 0.4),
+            TranscriptionWord::new("Comment", 0.5, 0.8),
+            TranscriptionWord::new("vas-tu ?", 0.8, 1.2),
+        ];
+        let cues = build_cues(&words);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[1].lines, vec!["Comment vas-tu ?".to_string()]);
+    }
+
+    #[test]
+    fn build_cues_extends_short_cues_without_overlap() {
+        let words = [
+            TranscriptionWord::new("Oui.", 0.0, 0.2),
+            TranscriptionWord::new("Non.", 0.6, 0.8),
+        ];
+        let cues = build_cues(&words);
+        assert!((cues[0].end - 0.6).abs() < 1e-4);
+        assert!((cues[1].end - 1.6).abs() < 1e-4);
+    }
+
+    #[test]
+    fn render_srt_numbers_cues_with_comma_timestamps() {
+        let words = [TranscriptionWord::new("Bonjour.", 1.0, 2.5)];
+        assert_eq!(
+            render_subtitles(&words, SubtitleFormat::Srt),
+            "1\n00:00:01,000 --> 00:00:02,500\nBonjour.\n\n"
+        );
+    }
+
+    #[test]
+    fn render_vtt_has_header_and_dot_timestamps() {
+        let words = [TranscriptionWord::new("Bonjour.", 1.0, 2.5)];
+        assert_eq!(
+            render_subtitles(&words, SubtitleFormat::Vtt),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.500\nBonjour.\n\n"
+        );
+    }
+
+    #[test]
+    fn render_vtt_of_no_words_is_a_bare_header() {
+        assert_eq!(render_subtitles(&[], SubtitleFormat::Vtt), "WEBVTT\n\n");
+    }
+}
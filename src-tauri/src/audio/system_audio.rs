@@ -0,0 +1,329 @@
+use crate::audio::chunking::{ChunkJob, Chunker};
+use crate::audio::helpers::rms;
+use crate::audio::streaming::clean_and_correct;
+use crate::audio::vad::VoiceDetector;
+use crate::dictionary::Dictionary;
+use crate::engine::transcription_engine::TranscriptionWord;
+use crate::settings::AppSettings;
+use crate::wake_word::wake_word::normalize_text;
+use anyhow::{Context, Result};
+use hound::{WavSpec, WavWriter};
+use log::{debug, error, warn};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use tauri::{AppHandle, Manager};
+
+/// parec resamples the monitor to the model rate, so no resampling is left.
+const SAMPLE_RATE: u32 = 16000;
+/// Samples read per VAD tick, the 33 ms of the microphone writer thread.
+const TICK_SAMPLES: usize = SAMPLE_RATE as usize * 33 / 1000;
+/// A microphone word this close to the same remote word is the remote side
+/// played by the loudspeakers, not the user.
+const ECHO_WINDOW_SECS: f32 = 0.6;
+/// Labels of the microphone and remote sides, until the user renames them.
+pub const DEFAULT_SPEAKER_LABELS: (&str, &str) = ("Me", "Remote");
+/// Labels set when dual capture is first turned on in French.
+pub const FRENCH_SPEAKER_LABELS: (&str, &str) = ("Moi", "Interlocuteur");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Speaker {
+    Me,
+    Remote,
+}
+
+impl Speaker {
+    pub fn label(self, settings: &AppSettings) -> &str {
+        match self {
+            Speaker::Me => &settings.speaker_label_me,
+            Speaker::Remote => &settings.speaker_label_remote,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SpeakerTurn {
+    pub speaker: Speaker,
+    pub words: Vec<TranscriptionWord>,
+}
+
+/// Check that the system output can be captured: PulseAudio or PipeWire
+/// with `parec`, so Linux only.
+pub fn check_available() -> Result<(), String> {
+    if !cfg!(target_os = "linux") {
+        return Err("System audio capture is only available on Linux".to_string());
+    }
+    match Command::new("parec").arg("--version").output() {
+        Ok(output) if output.status.success() => Ok(()),
+        _ => Err(
+            "parec was not found. Install pulseaudio-utils to capture system audio.".to_string(),
+        ),
+    }
+}
+
+/// Where the system side of a dual capture is written, next to the
+/// microphone recording.
+pub fn remote_wav_path(path: &Path) -> PathBuf {
+    path.with_extension("remote.wav")
+}
+
+/// Records the system output (the monitor of the default sink) during a
+/// dual capture and chunks it into its own pipeline, like the microphone.
+pub struct SystemCapture {
+    child: Child,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl SystemCapture {
+    /// Nothing is kept while `paused` is set, like on the microphone side.
+    pub fn start(
+        app: &AppHandle,
+        tx: Sender<ChunkJob>,
+        wav_path: &Path,
+        paused: Arc<AtomicBool>,
+    ) -> Result<Self> {
+        let mut child = Command::new("parec")
+            .args([
+                "--device=@DEFAULT_MONITOR@",
+                "--format=float32le",
+                "--rate=16000",
+                "--channels=1",
+                "--raw",
+                "--latency-msec=50",
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .context("Failed to start parec")?;
+        let Some(stdout) = child.stdout.take() else {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow::anyhow!("parec output is not available"));
+        };
+
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: SAMPLE_RATE,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let writer = WavWriter::create(wav_path, spec)
+            .map_err(|e| warn!("System audio will not be saved: {}", e))
+            .ok();
+        let vad = VoiceDetector::from_settings(app, SAMPLE_RATE);
+        let backend = crate::settings::load_settings(app).asr_backend;
+        let chunker = Chunker::new(tx, SAMPLE_RATE, None, vad, backend);
+        debug!("System audio capture started");
+
+        Ok(Self {
+            child,
+            reader: Some(spawn_reader(stdout, chunker, writer, paused)),
+        })
+    }
+
+    /// Stop capturing. The reader sees the end of the stream and queues the
+    /// last chunk.
+    pub fn close(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+
+    /// Stop capturing and wait until every chunk is queued.
+    pub fn stop(mut self) {
+        self.close();
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
+
+impl Drop for SystemCapture {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn spawn_reader(
+    mut stdout: ChildStdout,
+    mut chunker: Chunker,
+    mut writer: Option<WavWriter<std::io::BufWriter<std::fs::File>>>,
+    paused: Arc<AtomicBool>,
+) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut bytes = vec![0u8; TICK_SAMPLES * 4];
+        let mut was_paused = false;
+        while stdout.read_exact(&mut bytes).is_ok() {
+            if paused.load(Ordering::SeqCst) {
+                if !was_paused {
+                    was_paused = true;
+                    chunker.cut_at_pause();
+                }
+                continue;
+            }
+            was_paused = false;
+            let samples: Vec<f32> = bytes
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+            if let Some(writer) = writer.as_mut() {
+                for &sample in &samples {
+                    if let Err(e) = writer.write_sample((sample * i16::MAX as f32) as i16) {
+                        error!("Error writing system audio sample: {}", e);
+                        break;
+                    }
+                }
+            }
+            chunker.push_samples(&samples);
+            chunker.on_throttle_tick(rms(&samples));
+        }
+
+        chunker.flush_remaining();
+        if let Some(writer) = writer {
+            if let Err(e) = writer.finalize() {
+                warn!("Failed to finalize system audio WAV: {}", e);
+            }
+        }
+        debug!("System audio capture stopped");
+    })
+}
+
+/// Interleave the words of both sides in time order, grouping the words a
+/// side says in a row into one turn. Microphone words repeating a remote
+/// word at the same time are the loudspeaker echo and are dropped.
+pub fn speaker_turns(
+    me: Vec<TranscriptionWord>,
+    remote: Vec<TranscriptionWord>,
+) -> Vec<SpeakerTurn> {
+    let me: Vec<TranscriptionWord> = me
+        .into_iter()
+        .filter(|word| {
+            let text = normalize_text(&word.text);
+            !remote.iter().any(|r| {
+                (r.start - word.start).abs() <= ECHO_WINDOW_SECS && normalize_text(&r.text) == text
+            })
+        })
+        .collect();
+    let mut words: Vec<(Speaker, TranscriptionWord)> = me
+        .into_iter()
+        .map(|word| (Speaker::Me, word))
+        .chain(remote.into_iter().map(|word| (Speaker::Remote, word)))
+        .collect();
+    words.sort_by(|a, b| a.1.start.total_cmp(&b.1.start));
+
+    let mut turns: Vec<SpeakerTurn> = Vec::new();
+    for (speaker, word) in words {
+        match turns.last_mut() {
+            Some(turn) if turn.speaker == speaker => turn.words.push(word),
+            _ => turns.push(SpeakerTurn {
+                speaker,
+                words: vec![word],
+            }),
+        }
+    }
+    turns
+}
+
+/// The dual capture transcript, one dictionary-corrected line per turn
+/// prefixed with its speaker.
+pub fn labelled_transcript(app: &AppHandle, turns: &[SpeakerTurn]) -> String {
+    let dictionary = app.state::<Dictionary>().get();
+    let suppression = app.state::<Dictionary>().get_suppression();
+    let settings = crate::settings::load_settings(app);
+    turns
+        .iter()
+        .filter_map(|turn| {
+            let (_, corrected) = clean_and_correct(&turn.words, &dictionary, &suppression);
+            let corrected = corrected.trim();
+            (!corrected.is_empty())
+                .then(|| format!("{}: {}", turn.speaker.label(&settings), corrected))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(turns: &[SpeakerTurn]) -> Vec<(Speaker, String)> {
+        turns
+            .iter()
+            .map(|turn| {
+                let words: Vec<&str> = turn.words.iter().map(|w| w.text.as_str()).collect();
+                (turn.speaker, words.join(" "))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_speaker_turns_interleave_sides_by_time() {
+        let me = vec![
+            TranscriptionWord::new("hello", 0.0, 0.3),
+            TranscriptionWord::new("there", 0.4, 0.7),
+            TranscriptionWord::new("great", 3.0, 3.3),
+        ];
+        let remote = vec![
+            TranscriptionWord::new("hi", 1.0, 1.3),
+            TranscriptionWord::new("how", 1.4, 1.7),
+            TranscriptionWord::new("are", 1.8, 2.1),
+        ];
+        assert_eq!(
+            texts(&speaker_turns(me, remote)),
+            vec![
+                (Speaker::Me, "hello there".to_string()),
+                (Speaker::Remote, "hi how are".to_string()),
+                (Speaker::Me, "great".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_speaker_turns_drop_loudspeaker_echo() {
+        // The microphone hears the remote side a little late, and the user
+        // answers afterwards.
+        let me = vec![
+            TranscriptionWord::new("Bonjour,", 1.2, 1.5),
+            TranscriptionWord::new("tout", 1.6, 1.9),
+            TranscriptionWord::new("salut", 3.0, 3.3),
+        ];
+        let remote = vec![
+            TranscriptionWord::new("bonjour", 1.0, 1.3),
+            TranscriptionWord::new("tout", 1.4, 1.7),
+        ];
+        assert_eq!(
+            texts(&speaker_turns(me, remote)),
+            vec![
+                (Speaker::Remote, "bonjour tout".to_string()),
+                (Speaker::Me, "salut".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_speaker_labels_come_from_settings() {
+        let mut settings = AppSettings::default();
+        assert_eq!(Speaker::Me.label(&settings), "Me");
+        settings.speaker_label_remote = "Alice".to_string();
+        assert_eq!(Speaker::Remote.label(&settings), "Alice");
+    }
+
+    #[test]
+    fn test_speaker_turns_keep_same_word_said_later() {
+        let me = vec![TranscriptionWord::new("yes", 5.0, 5.3)];
+        let remote = vec![TranscriptionWord::new("yes", 1.0, 1.3)];
+        assert_eq!(speaker_turns(me, remote).len(), 2);
+    }
+
+    #[test]
+    fn test_remote_wav_path_sits_next_to_recording() {
+        let path = Path::new("/tmp/murmure_recordings/murmure-1-0.wav");
+        assert_eq!(
+            remote_wav_path(path),
+            Path::new("/tmp/murmure_recordings/murmure-1-0.remote.wav")
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::engine::transcription_engine::TranscriptionWord;
+use serde::Serialize;
+
+/// Pause between two words that closes a segment even without punctuation.
+const SEGMENT_PAUSE_SECS: f32 = 1.0;
+
+/// A file transcription with absolute timestamps. `text` is the final,
+/// post-processed text; `segments` and `words` are the model output before
+/// dictionary correction, filler removal, LLM and formatting rules, so they
+/// may differ slightly.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TimedTranscript {
+    pub text: String,
+    pub duration: f32,
+    pub segments: Vec<TimedSegment>,
+    pub words: Vec<TranscriptionWord>,
+    /// Set when the requested LLM mode failed; `text` then skips the LLM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub llm_error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TimedSegment {
+    pub id: usize,
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+}
+
+/// Move chunk-relative words onto the session timeline. Starts before the
+/// chunk (inside the inference padding) are clamped to the chunk start.
+pub fn offset_words(words: Vec<TranscriptionWord>, offset_secs: f32) -> Vec<TranscriptionWord> {
+    words
+        .into_iter()
+        .map(|mut word| {
+            word.start = (word.start + offset_secs).max(offset_secs);
+            word.end = (word.end + offset_secs).max(word.start);
+            word
+        })
+        .collect()
+}
+
+/// Group words into segments, closing one after sentence punctuation or
+/// before a pause of at least `SEGMENT_PAUSE_SECS`.
+pub fn build_segments(words: &[TranscriptionWord]) -> Vec<TimedSegment> {
+    let mut segments: Vec<TimedSegment> = Vec::new();
+    let mut current: Vec<&TranscriptionWord> = Vec::new();
+
+    for (i, word) in words.iter().enumerate() {
+        current.push(word);
+        if closes_segment(words, i) {
+            segments.push(segment_from_words(segments.len(), &current));
+            current.clear();
+        }
+    }
+
+    segments
+}
+
+/// True when `words[i]` is the last word of its segment: it ends a sentence,
+/// a long pause follows, or it is the last word.
+pub(super) fn closes_segment(words: &[TranscriptionWord], i: usize) -> bool {
+    let word = &words[i];
+    let ends_sentence = word.text.ends_with(&['.', '?', '!'][..]);
+    let pause_follows = words
+        .get(i + 1)
+        .is_some_and(|next| next.start - word.end >= SEGMENT_PAUSE_SECS);
+    ends_sentence || pause_follows || i == words.len() - 1
+}
+
+fn segment_from_words(id: usize, words: &[&TranscriptionWord]) -> TimedSegment {
+    TimedSegment {
+        id,
+        start: words.first().map(|w| w.start).unwrap_or(0.0),
+        end: words.last().map(|w| w.end).unwrap_or(0.0),
+        text: words
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_words_shifts_onto_session_timeline() {
+        let words = offset_words(vec![TranscriptionWord::new("bonjour", 0.5, 0.9)], 60.0);
+        assert!((words[0].start - 60.5).abs() < 1e-4);
+        assert!((words[0].end - 60.9).abs() < 1e-4);
+    }
+
+    #[test]
+    fn offset_words_clamps_padding_to_chunk_start() {
+        let words = offset_words(vec![TranscriptionWord::new("bonjour", -0.1, 0.3)], 15.0);
+        assert_eq!(words[0].start, 15.0);
+    }
+
+    #[test]
+    fn build_segments_splits_on_sentence_punctuation() {
+        let words = [
+            TranscriptionWord::new("Bonjour.", 0.0, 0.4),
+            TranscriptionWord::new("Comment", 0.5, 0.8),
+            TranscriptionWord::new("vas-tu ?", 0.8, 1.2),
+        ];
+        let segments = build_segments(&words);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text, "Bonjour.");
+        assert_eq!(segments[1].id, 1);
+        assert_eq!(segments[1].text, "Comment vas-tu ?");
+        assert_eq!(segments[1].start, 0.5);
+        assert_eq!(segments[1].end, 1.2);
+    }
+
+    #[test]
+    fn build_segments_splits_on_long_pause() {
+        let words = [
+            TranscriptionWord::new("bonjour", 0.0, 0.4),
+            TranscriptionWord::new("monde", 2.0, 2.3),
+        ];
+        let segments = build_segments(&words);
+        assert_eq!(segments.len(), 2);
+    }
+
+    #[test]
+    fn build_segments_of_no_words_is_empty() {
+        assert!(build_segments(&[]).is_empty());
+    }
+}
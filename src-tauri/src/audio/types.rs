@@ -1,16 +1,100 @@
 use crate::audio::chunking::ChunkPipeline;
+use crate::audio::pre_roll::PreRoll;
 use crate::audio::recorder::AudioRecorder;
-use crate::engine::ParakeetEngine;
+use crate::engine::AsrEngine;
 use cpal::Device;
 use parking_lot::Mutex;
 use serde::Serialize;
-use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct MicInfo {
     pub id: String,
     pub label: String,
+    /// Monitor of an output device: records what the machine plays.
+    pub is_monitor: bool,
+}
+
+/// Chunk counters of a file transcription, polled by the HTTP job API.
+/// `total` stays 0 until the whole file has been cut into chunks.
+#[derive(Default)]
+pub struct ChunkProgress {
+    pub total: AtomicU64,
+    pub done: AtomicU64,
+}
+
+impl ChunkProgress {
+    pub fn percent(&self) -> u8 {
+        let total = self.total.load(Ordering::SeqCst);
+        if total == 0 {
+            return 0;
+        }
+        let done = self.done.load(Ordering::SeqCst).min(total);
+        (done * 100 / total) as u8
+    }
+}
+
+/// Post-processing stages to run on text that did not come from the
+/// recorder (`POST /api/process`, `murmure process`). `llm_mode` is the
+/// 1-based mode number, `None` skips the LLM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessOptions {
+    pub fillers: bool,
+    pub dictionary: bool,
+    pub llm_mode: Option<u8>,
+    pub formatting: bool,
+}
+
+impl Default for ProcessOptions {
+    fn default() -> Self {
+        Self {
+            fillers: true,
+            dictionary: true,
+            llm_mode: None,
+            formatting: true,
+        }
+    }
+}
+
+/// Per-request overrides of a file transcription (`/api/transcribe`,
+/// `/api/jobs`). The default matches a plain file transcription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscribeOptions {
+    /// Boosted and corrected like dictionary words, for this file only.
+    pub boost_words: Vec<String>,
+    pub fillers: bool,
+    /// 1-based LLM mode run on the transcript, `None` for no LLM.
+    pub llm_mode: Option<u8>,
+    pub formatting: bool,
+    /// Save the transcript to history and stats, like a dictation.
+    pub history: bool,
+}
+
+impl Default for TranscribeOptions {
+    fn default() -> Self {
+        Self {
+            boost_words: Vec::new(),
+            fillers: true,
+            llm_mode: None,
+            formatting: true,
+            history: false,
+        }
+    }
+}
+
+impl TranscribeOptions {
+    /// Stages left once the chunks are merged. The dictionary is off here
+    /// because each chunk is already corrected as it is transcribed.
+    pub fn post_processing(&self) -> ProcessOptions {
+        ProcessOptions {
+            fillers: self.fillers,
+            dictionary: false,
+            llm_mode: self.llm_mode,
+            formatting: self.formatting,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -18,11 +102,13 @@ pub struct PreviewSnapshot {
     pub queue: Vec<f32>,
     pub generation: u64,
     pub revision: u64,
+    /// Position in `queue` of the last pause the chunker would cut at.
+    pub freeze_point: usize,
 }
 
 pub struct AudioState {
     pub recorder: Mutex<Option<AudioRecorder>>,
-    pub engine: Mutex<Option<ParakeetEngine>>,
+    pub engine: Mutex<Option<AsrEngine>>,
     pub current_file_name: Mutex<Option<String>>,
     recording_mode: AtomicU8,
     recording_trigger: AtomicU8,
@@ -36,7 +122,18 @@ pub struct AudioState {
     pub chunk_inference_active: Arc<AtomicBool>,
     /// The chunking pipeline of the active session
     pub chunk_pipeline: Mutex<Option<ChunkPipeline>>,
+    /// The pipeline of the system audio side, during a dual capture
+    pub remote_pipeline: Mutex<Option<ChunkPipeline>>,
+    /// Microphone stream kept open between recordings, when the pre-roll is on
+    pub pre_roll: Mutex<Option<PreRoll>>,
     session_gen: AtomicU64,
+    /// Beam width of the final decodes, 1 for greedy. Set from the settings
+    /// at startup, or overridden by `murmure transcribe --beam-width`.
+    beam_width: AtomicUsize,
+    /// Last time the engine was needed, for the idle unload
+    engine_last_used: Mutex<Instant>,
+    /// Set while the engine loads, shown as "warming up" by the overlay
+    engine_warming: AtomicBool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -47,6 +144,17 @@ pub enum RecordingMode {
     Command = 2,
 }
 
+impl RecordingMode {
+    /// Name shown to the overlay and to `/api/events` subscribers.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RecordingMode::Standard => "standard",
+            RecordingMode::Llm => "llm",
+            RecordingMode::Command => "command",
+        }
+    }
+}
+
 impl From<u8> for RecordingMode {
     fn from(val: u8) -> Self {
         match val {
@@ -89,7 +197,12 @@ impl AudioState {
             preview_snapshot: Arc::new(Mutex::new(PreviewSnapshot::default())),
             chunk_inference_active: Arc::new(AtomicBool::new(false)),
             chunk_pipeline: Mutex::new(None),
+            remote_pipeline: Mutex::new(None),
+            pre_roll: Mutex::new(None),
             session_gen: AtomicU64::new(0),
+            beam_width: AtomicUsize::new(1),
+            engine_last_used: Mutex::new(Instant::now()),
+            engine_warming: AtomicBool::new(false),
         }
     }
 
@@ -122,6 +235,33 @@ impl AudioState {
         self.recording_trigger.load(Ordering::SeqCst).into()
     }
 
+    /// Clamped here so a hand-edited setting cannot ask for a wider beam
+    /// than the decoder supports.
+    pub fn set_beam_width(&self, width: usize) {
+        let width = width.clamp(1, crate::engine::engine::MAX_BEAM_WIDTH);
+        self.beam_width.store(width, Ordering::SeqCst);
+    }
+
+    pub fn get_beam_width(&self) -> usize {
+        self.beam_width.load(Ordering::SeqCst)
+    }
+
+    pub fn touch_engine(&self) {
+        *self.engine_last_used.lock() = Instant::now();
+    }
+
+    pub fn engine_idle_time(&self) -> Duration {
+        self.engine_last_used.lock().elapsed()
+    }
+
+    pub fn set_engine_warming(&self, warming: bool) {
+        self.engine_warming.store(warming, Ordering::SeqCst);
+    }
+
+    pub fn is_engine_warming(&self) -> bool {
+        self.engine_warming.load(Ordering::SeqCst)
+    }
+
     /// Sets the cached audio device
     pub fn set_cached_device(&self, device: Option<Device>) {
         *self.cached_device.lock() = device;
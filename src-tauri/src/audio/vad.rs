@@ -1,3 +1,8 @@
+use crate::audio::neural_vad::{NeuralVad, NEURAL_VAD_FILENAME};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
 const FLOOR_INIT: f32 = 0.003;
 const FLOOR_ALPHA_DOWN: f32 = 0.2;
 const FLOOR_ALPHA_UP: f32 = 0.005;
@@ -8,6 +13,24 @@ const SPEECH_THRESHOLD_MIN: f32 = 0.004;
 const SPEECH_THRESHOLD_MAX: f32 = 0.08;
 const VAD_EMA_ALPHA: f32 = 0.3;
 
+// Speech probability at which the neural model starts speech, and below
+// which it ends it (the Silero defaults).
+const NEURAL_SPEECH_THRESHOLD: f32 = 0.5;
+const NEURAL_SILENCE_THRESHOLD: f32 = 0.35;
+
+/// How voice activity is detected for chunking, the wake word and the
+/// silence timeout.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VadProvider {
+    /// Loudness against the adaptive noise floor.
+    #[default]
+    Adaptive,
+    /// The Silero VAD model, which tells speech from keyboard or ventilation
+    /// noise. Falls back to `Adaptive` when the model is missing.
+    Neural,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum VoiceActivity {
     NotStarted,
@@ -76,6 +99,159 @@ impl AdaptiveVad {
     }
 }
 
+/// Hysteresis over the neural speech probability, mirroring the states of
+/// `AdaptiveVad`.
+#[derive(Default)]
+struct SpeechHysteresis {
+    speaking: bool,
+    has_speech_started: bool,
+}
+
+impl SpeechHysteresis {
+    /// `None` when no frame was decoded since the last tick: the state holds.
+    fn update(&mut self, probability: Option<f32>) -> VoiceActivity {
+        match probability {
+            Some(p) if p >= NEURAL_SPEECH_THRESHOLD => {
+                self.speaking = true;
+                self.has_speech_started = true;
+            }
+            Some(p) if p < NEURAL_SILENCE_THRESHOLD => self.speaking = false,
+            _ => {}
+        }
+        if !self.has_speech_started {
+            VoiceActivity::NotStarted
+        } else if self.speaking {
+            VoiceActivity::Active
+        } else {
+            VoiceActivity::Silent
+        }
+    }
+}
+
+/// Where a detector gets its speech probability from.
+enum NeuralSource {
+    /// No model: the RMS heuristic decides.
+    None,
+    Owned(NeuralVad),
+    /// The model of another detector on the same audio, read through
+    /// `update_with`, so a recording runs a single session.
+    Shared,
+}
+
+/// Voice activity from the neural model when one is loaded, from the RMS
+/// heuristic otherwise. The heuristic keeps tracking the noise floor either
+/// way.
+pub(crate) struct VoiceDetector {
+    adaptive: AdaptiveVad,
+    neural: NeuralSource,
+    hysteresis: SpeechHysteresis,
+    last_probability: Option<f32>,
+}
+
+impl VoiceDetector {
+    pub(crate) fn adaptive() -> Self {
+        Self {
+            adaptive: AdaptiveVad::new(),
+            neural: NeuralSource::None,
+            hysteresis: SpeechHysteresis::default(),
+            last_probability: None,
+        }
+    }
+
+    /// A neural detector without a model of its own, fed the probability
+    /// taken from `leader` on each tick. Adaptive when `leader` is.
+    pub(crate) fn following(leader: &VoiceDetector) -> Self {
+        let mut detector = Self::adaptive();
+        if leader.is_neural() {
+            detector.neural = NeuralSource::Shared;
+        }
+        detector
+    }
+
+    /// The detector selected in the settings, for audio at `sample_rate`.
+    pub(crate) fn from_settings(app: &AppHandle, sample_rate: u32) -> Self {
+        let mut detector = Self::adaptive();
+        if crate::settings::load_settings(app).vad_provider != VadProvider::Neural {
+            return detector;
+        }
+        let Some(path) = crate::utils::resources::resolve_resource_path(app, NEURAL_VAD_FILENAME)
+        else {
+            warn!(
+                "{} not found, using the adaptive voice detection",
+                NEURAL_VAD_FILENAME
+            );
+            return detector;
+        };
+        match NeuralVad::load(&path, sample_rate) {
+            Ok(neural) => detector.neural = NeuralSource::Owned(neural),
+            Err(e) => warn!(
+                "Failed to load the neural voice detection, using the adaptive one: {}",
+                e
+            ),
+        }
+        detector
+    }
+
+    pub(crate) fn is_neural(&self) -> bool {
+        !matches!(self.neural, NeuralSource::None)
+    }
+
+    /// Feed the audio of the coming tick. Only an owned model needs it.
+    pub(crate) fn push_samples(&mut self, samples: &[f32]) {
+        if let NeuralSource::Owned(neural) = &mut self.neural {
+            neural.push(samples);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn observe(&mut self, rms: f32) {
+        self.adaptive.observe(rms);
+    }
+
+    pub(crate) fn update(&mut self, rms: f32) -> VoiceActivity {
+        self.update_with(rms, None)
+    }
+
+    /// `update` for a detector built by `following`: `shared` is the
+    /// probability taken from the leader on this tick. A detector with its
+    /// own model reads that model instead.
+    pub(crate) fn update_with(&mut self, rms: f32, shared: Option<f32>) -> VoiceActivity {
+        let activity = self.adaptive.update(rms);
+        let probability = match &mut self.neural {
+            NeuralSource::None => return activity,
+            NeuralSource::Owned(neural) => neural.take_probability(),
+            NeuralSource::Shared => shared,
+        };
+        self.last_probability = probability;
+        self.hysteresis.update(probability)
+    }
+
+    /// Probability read by the last `update`, for a detector that follows
+    /// this one. Taken, so a tick without an update passes `None` on.
+    pub(crate) fn take_last_probability(&mut self) -> Option<f32> {
+        self.last_probability.take()
+    }
+
+    /// Whether the last tick, of loudness `rms`, was silence.
+    pub(crate) fn is_silent(&self, rms: f32) -> bool {
+        if self.is_neural() {
+            !self.hysteresis.speaking
+        } else {
+            rms < self.adaptive.silence_threshold()
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn speech_threshold(&self) -> f32 {
+        self.adaptive.speech_threshold()
+    }
+
+    pub(crate) fn reset_speech_state(&mut self) {
+        self.adaptive.reset_speech_state();
+        self.hysteresis = SpeechHysteresis::default();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +409,53 @@ mod tests {
         assert_eq!(activity, VoiceActivity::Active);
     }
 
+    #[test]
+    fn neural_hysteresis_starts_and_ends_speech() {
+        let mut hysteresis = SpeechHysteresis::default();
+        assert_eq!(hysteresis.update(Some(0.4)), VoiceActivity::NotStarted);
+        assert_eq!(hysteresis.update(Some(0.9)), VoiceActivity::Active);
+        // Between the thresholds, and without a new frame, speech goes on.
+        assert_eq!(hysteresis.update(Some(0.4)), VoiceActivity::Active);
+        assert_eq!(hysteresis.update(None), VoiceActivity::Active);
+        assert_eq!(hysteresis.update(Some(0.1)), VoiceActivity::Silent);
+        assert_eq!(hysteresis.update(Some(0.4)), VoiceActivity::Silent);
+    }
+
+    #[test]
+    fn follower_of_adaptive_detector_stays_adaptive() {
+        let leader = VoiceDetector::adaptive();
+        let mut follower = VoiceDetector::following(&leader);
+        assert!(!follower.is_neural());
+        assert_eq!(
+            follower.update_with(0.05, Some(0.9)),
+            VoiceActivity::NotStarted
+        );
+        assert_eq!(follower.take_last_probability(), None);
+    }
+
+    #[test]
+    fn shared_detector_reads_the_leader_probability() {
+        let mut follower = VoiceDetector::adaptive();
+        follower.neural = NeuralSource::Shared;
+        assert_eq!(follower.update_with(0.0, Some(0.9)), VoiceActivity::Active);
+        assert_eq!(follower.take_last_probability(), Some(0.9));
+        assert_eq!(follower.take_last_probability(), None);
+        assert_eq!(follower.update_with(0.0, None), VoiceActivity::Active);
+        assert_eq!(follower.update_with(0.0, Some(0.1)), VoiceActivity::Silent);
+        assert!(follower.is_silent(0.5));
+    }
+
+    #[test]
+    fn detector_without_model_follows_adaptive_vad() {
+        let mut detector = VoiceDetector::adaptive();
+        let mut vad = AdaptiveVad::new();
+        for rms in [0.05, 0.05, 0.001, 0.0, 0.0, 0.0, 0.0, 0.0] {
+            detector.push_samples(&[rms; 16]);
+            assert_eq!(detector.update(rms), vad.update(rms));
+        }
+        assert!(detector.is_silent(0.0));
+    }
+
     #[test]
     fn micro_peak_during_silence_does_not_return_active() {
         let mut vad = AdaptiveVad::new();
@@ -1,16 +1,23 @@
-use super::helpers::{parse_file_arg, parse_llm_mode, parse_strategy};
-use super::types::{CliCommand, ImportStrategy};
+use super::helpers::{
+    parse_beam_width, parse_file_arg, parse_llm_mode, parse_quantization, parse_strategy,
+    parse_thread_count, parse_transcribe_format,
+};
+use super::types::{CliCommand, ImportStrategy, TranscribeFormat};
+use crate::audio::types::ProcessOptions;
+use crate::engine::SessionThreads;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Single-action flags: first match wins. Order is significant and must match
 /// the original sequential checks. `LlmMode` is handled separately because it
 /// carries a value.
-const ACTION_FLAGS: &[(&str, CliCommand)] = &[
+/// Also the action names of the HTTP API `/api/control/{action}` routes.
+pub(crate) const ACTION_FLAGS: &[(&str, CliCommand)] = &[
     ("transcription", CliCommand::Transcription),
     ("transcription-command", CliCommand::TranscriptionCommand),
     ("paste-last", CliCommand::PasteLast),
     ("cancel", CliCommand::Cancel),
+    ("pause", CliCommand::Pause),
     ("voice-mode", CliCommand::VoiceMode),
 ];
 
@@ -43,12 +50,14 @@ USAGE:
     murmure [OPTIONS]
     murmure import <FILE> [IMPORT_OPTIONS]
     murmure transcribe <FILE>
+    murmure process [TEXT]
 
 OPTIONS:
     --transcription              Toggle standard transcription on/off
     --transcription-command      Toggle transcription in command mode
     --paste-last                 Paste the last transcription
     --cancel                     Cancel the current recording
+    --pause                      Pause or resume the current recording
     --voice-mode                 Toggle Voice Mode on/off
     --llm-mode <N>               Toggle transcription with LLM mode N (1-4)
     --llm-transform <N>          Apply LLM mode N prompt to the selected text (1-4)
@@ -75,12 +84,39 @@ TRANSCRIBE:
         murmure transcribe <FILE> [TRANSCRIBE_OPTIONS]
 
     ARGS:
-        <FILE>    Path to the WAV file to transcribe
+        <FILE>    Path to the audio file to transcribe (WAV, FLAC, MP3, M4A, Ogg)
 
     TRANSCRIBE_OPTIONS:
+        --format <FORMAT>        Output format: text (default), json (with word
+                                 and segment timestamps), srt or vtt (subtitles)
         -v, --verbose            Print full logs to stderr (errors only by default)
         --no-dictionary          Disable boosting and fuzzy post-correction (baseline)
-        --dictionary-file <F>    Load the dictionary from a file (one word per line)
+        --dictionary-file <F>    Load the dictionary from a file (one entry per line,
+                                 with an optional `| weight` suffix)
+        --beam-width <N>         Decode with a beam search of N hypotheses (1-8);
+                                 1 is the greedy decoder. Overrides the setting
+        --quantization <Q>       Model precision: int8 (default) or fp32, slower
+                                 but more accurate. Overrides the setting
+        --intra-threads <N>      Threads of each model operator. Overrides the setting
+        --inter-threads <N>      Threads running model operators in parallel.
+                                 Overrides the setting
+
+PROCESS:
+    Run the post-processing stages on text (from TEXT, or stdin when omitted)
+    and print the result to stdout, then exit. Nothing is saved to history.
+
+    USAGE:
+        murmure process [TEXT] [PROCESS_OPTIONS]
+
+    ARGS:
+        <TEXT>    Text to process; read from stdin when omitted
+
+    PROCESS_OPTIONS:
+        --no-fillers             Keep filler words and repeated words
+        --no-dictionary          Skip the dictionary correction
+        --no-formatting          Skip the formatting rules
+        --llm-mode <N>           Also run the prompt of LLM mode N (1-4)
+        -v, --verbose            Print full logs to stderr (errors only by default)
 
 EXAMPLES:
     murmure --transcription
@@ -91,7 +127,13 @@ EXAMPLES:
     murmure import config.murmure --strategy merge
     murmure import config.murmure -s replace
     murmure transcribe recording.wav
-    murmure transcribe recording.wav -v",
+    murmure transcribe recording.wav -v
+    murmure transcribe recording.wav --format json
+    murmure transcribe meeting.wav --format srt > meeting.srt
+    murmure transcribe recording.wav --beam-width 4
+    murmure transcribe recording.wav --quantization fp32 --intra-threads 16
+    murmure process \"euh donc le le rendez-vous est demain\"
+    murmure transcribe recording.wav | murmure process --llm-mode 2",
         VERSION
     );
 }
@@ -103,10 +145,45 @@ EXAMPLES:
 /// - `Err(msg)`: a recognised command with invalid arguments. Cold path callers
 ///   should surface `msg` and exit; hot path callers should log and stay alive.
 pub fn parse_raw_args(args: &[String]) -> Result<Option<CliCommand>, String> {
+    if let Some(index) = args.iter().position(|a| a == "process") {
+        // Same scan as `transcribe`: the text is the first token without a
+        // dash, skipping the value of `--llm-mode`.
+        let rest = &args[index + 1..];
+        let mut text = None;
+        let mut options = ProcessOptions::default();
+        let mut i = 0;
+        while i < rest.len() {
+            match rest[i].as_str() {
+                "--no-fillers" => options.fillers = false,
+                "--no-dictionary" => options.dictionary = false,
+                "--no-formatting" => options.formatting = false,
+                "--llm-mode" => match rest.get(i + 1) {
+                    Some(value) => {
+                        options.llm_mode = Some(parse_llm_mode(value)?);
+                        i += 1;
+                    }
+                    None => return Err("--llm-mode requires a value: 1, 2, 3 or 4".to_string()),
+                },
+                arg if !arg.starts_with('-') && text.is_none() => text = Some(arg.to_string()),
+                _ => {}
+            }
+            i += 1;
+        }
+        return Ok(Some(CliCommand::Process { text, options }));
+    }
+
     if let Some(index) = args.iter().position(|a| a == "transcribe") {
-        // The audio file is the first token without a dash. But `--dictionary-file`
-        // takes a path (also without a dash), so skip the token right after it,
-        // otherwise that path would be mistaken for the audio file.
+        // The audio file is the first token without a dash. But the options below
+        // take a value (also without a dash), so skip the token right after them,
+        // otherwise it would be mistaken for the audio file.
+        const VALUE_OPTIONS: &[&str] = &[
+            "--dictionary-file",
+            "--format",
+            "--beam-width",
+            "--quantization",
+            "--intra-threads",
+            "--inter-threads",
+        ];
         let rest = &args[index + 1..];
         let mut file_path = None;
         let mut skip_next = false;
@@ -115,21 +192,59 @@ pub fn parse_raw_args(args: &[String]) -> Result<Option<CliCommand>, String> {
                 skip_next = false;
                 continue;
             }
-            if arg == "--dictionary-file" {
+            if VALUE_OPTIONS.contains(&arg.as_str()) {
                 skip_next = true;
                 continue;
             }
-            if !arg.starts_with('-') {
+            if !arg.starts_with('-') && file_path.is_none() {
                 file_path = Some(arg);
-                break;
+            }
+        }
+        let format = match rest.iter().position(|a| a == "--format") {
+            Some(i) => match rest.get(i + 1) {
+                Some(value) => parse_transcribe_format(value)?,
+                None => {
+                    return Err("--format requires a value: text, json, srt or vtt".to_string());
+                }
+            },
+            None => TranscribeFormat::Text,
+        };
+        let beam_width = match rest.iter().position(|a| a == "--beam-width") {
+            Some(i) => match rest.get(i + 1) {
+                Some(value) => Some(parse_beam_width(value)?),
+                None => return Err("--beam-width requires a value between 1 and 8".to_string()),
+            },
+            None => None,
+        };
+        let quantization = match rest.iter().position(|a| a == "--quantization") {
+            Some(i) => match rest.get(i + 1) {
+                Some(value) => Some(parse_quantization(value)?),
+                None => return Err("--quantization requires a value: fp32 or int8".to_string()),
+            },
+            None => None,
+        };
+        let mut threads = SessionThreads::default();
+        for (flag, count) in [
+            ("--intra-threads", &mut threads.intra_op),
+            ("--inter-threads", &mut threads.inter_op),
+        ] {
+            if let Some(i) = rest.iter().position(|a| a == flag) {
+                match rest.get(i + 1) {
+                    Some(value) => *count = Some(parse_thread_count(value)?),
+                    None => return Err(format!("{} requires a number of threads", flag)),
+                }
             }
         }
         return match file_path {
             Some(path) => Ok(Some(CliCommand::Transcribe {
                 file_path: path.clone(),
+                format,
+                beam_width,
+                quantization,
+                threads,
             })),
             None => Err(
-                "transcribe requires a <FILE> argument (path to a WAV file). \
+                "transcribe requires a <FILE> argument (path to an audio file). \
                  Usage: murmure transcribe <FILE>"
                     .to_string(),
             ),
@@ -340,8 +455,18 @@ mod tests {
         ];
         let result = parse_raw_args(&args).unwrap();
         match result {
-            Some(CliCommand::Transcribe { file_path }) => {
+            Some(CliCommand::Transcribe {
+                file_path,
+                format,
+                beam_width,
+                quantization,
+                threads,
+            }) => {
                 assert_eq!(file_path, "/tmp/recording.wav");
+                assert_eq!(format, TranscribeFormat::Text);
+                assert_eq!(beam_width, None);
+                assert_eq!(quantization, None);
+                assert_eq!(threads, SessionThreads::default());
             }
             other => panic!("expected Transcribe, got {:?}", other),
         }
@@ -364,7 +489,7 @@ mod tests {
             "1.wav".to_string(),
         ];
         match parse_raw_args(&args).unwrap() {
-            Some(CliCommand::Transcribe { file_path }) => assert_eq!(file_path, "1.wav"),
+            Some(CliCommand::Transcribe { file_path, .. }) => assert_eq!(file_path, "1.wav"),
             other => panic!("expected Transcribe, got {:?}", other),
         }
     }
@@ -378,11 +503,127 @@ mod tests {
             "-v".to_string(),
         ];
         match parse_raw_args(&args).unwrap() {
-            Some(CliCommand::Transcribe { file_path }) => assert_eq!(file_path, "1.wav"),
+            Some(CliCommand::Transcribe { file_path, .. }) => assert_eq!(file_path, "1.wav"),
+            other => panic!("expected Transcribe, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_raw_args_transcribe_format_before_file() {
+        let args = vec![
+            "murmure".to_string(),
+            "transcribe".to_string(),
+            "--format".to_string(),
+            "json".to_string(),
+            "1.wav".to_string(),
+        ];
+        match parse_raw_args(&args).unwrap() {
+            Some(CliCommand::Transcribe {
+                file_path, format, ..
+            }) => {
+                assert_eq!(file_path, "1.wav");
+                assert_eq!(format, TranscribeFormat::Json);
+            }
+            other => panic!("expected Transcribe, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_raw_args_transcribe_invalid_format() {
+        let args = vec![
+            "murmure".to_string(),
+            "transcribe".to_string(),
+            "1.wav".to_string(),
+            "--format".to_string(),
+            "xml".to_string(),
+        ];
+        let err = parse_raw_args(&args).unwrap_err();
+        assert!(
+            err.contains("xml"),
+            "message should mention bad value: {err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_raw_args_transcribe_beam_width_before_file() {
+        let args = vec![
+            "murmure".to_string(),
+            "transcribe".to_string(),
+            "--beam-width".to_string(),
+            "4".to_string(),
+            "1.wav".to_string(),
+        ];
+        match parse_raw_args(&args).unwrap() {
+            Some(CliCommand::Transcribe {
+                file_path,
+                beam_width,
+                ..
+            }) => {
+                assert_eq!(file_path, "1.wav");
+                assert_eq!(beam_width, Some(4));
+            }
+            other => panic!("expected Transcribe, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_raw_args_transcribe_invalid_beam_width() {
+        let args = vec![
+            "murmure".to_string(),
+            "transcribe".to_string(),
+            "1.wav".to_string(),
+            "--beam-width".to_string(),
+            "0".to_string(),
+        ];
+        let err = parse_raw_args(&args).unwrap_err();
+        assert!(err.contains("beam width"), "unexpected message: {err}");
+    }
+
+    #[test]
+    fn test_parse_raw_args_transcribe_session_options_before_file() {
+        let args: Vec<String> = [
+            "murmure",
+            "transcribe",
+            "--quantization",
+            "fp32",
+            "--intra-threads",
+            "16",
+            "--inter-threads",
+            "2",
+            "1.wav",
+        ]
+        .iter()
+        .map(|a| a.to_string())
+        .collect();
+        match parse_raw_args(&args).unwrap() {
+            Some(CliCommand::Transcribe {
+                file_path,
+                quantization,
+                threads,
+                ..
+            }) => {
+                assert_eq!(file_path, "1.wav");
+                assert_eq!(quantization, Some(crate::engine::QuantizationType::FP32));
+                assert_eq!(threads.intra_op, Some(16));
+                assert_eq!(threads.inter_op, Some(2));
+            }
             other => panic!("expected Transcribe, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_parse_raw_args_transcribe_invalid_threads() {
+        let args = vec![
+            "murmure".to_string(),
+            "transcribe".to_string(),
+            "1.wav".to_string(),
+            "--intra-threads".to_string(),
+            "0".to_string(),
+        ];
+        let err = parse_raw_args(&args).unwrap_err();
+        assert!(err.contains("thread count"), "unexpected message: {err}");
+    }
+
     #[test]
     fn test_parse_raw_args_transcribe_file_starts_with_dash() {
         let args = vec![
@@ -395,6 +636,52 @@ mod tests {
         assert!(result.unwrap_err().contains("FILE"));
     }
 
+    #[test]
+    fn test_parse_raw_args_basic_process() {
+        let args = vec![
+            "murmure".to_string(),
+            "process".to_string(),
+            "euh bonjour".to_string(),
+        ];
+        assert_eq!(
+            parse_raw_args(&args).unwrap(),
+            Some(CliCommand::Process {
+                text: Some("euh bonjour".to_string()),
+                options: ProcessOptions::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_raw_args_process_stdin_with_toggles() {
+        let args = vec![
+            "murmure".to_string(),
+            "process".to_string(),
+            "--no-fillers".to_string(),
+            "--llm-mode".to_string(),
+            "2".to_string(),
+            "--no-formatting".to_string(),
+        ];
+        match parse_raw_args(&args).unwrap() {
+            Some(CliCommand::Process { text, options }) => {
+                assert_eq!(text, None);
+                assert!(!options.fillers && !options.formatting);
+                assert!(options.dictionary);
+                assert_eq!(options.llm_mode, Some(2));
+            }
+            other => panic!("expected Process, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_raw_args_process_invalid_llm_mode() {
+        for rest in [vec!["--llm-mode", "9"], vec!["text", "--llm-mode"]] {
+            let mut args = vec!["murmure".to_string(), "process".to_string()];
+            args.extend(rest.iter().map(|a| a.to_string()));
+            assert!(parse_raw_args(&args).is_err(), "args={args:?}");
+        }
+    }
+
     #[test]
     fn test_parse_raw_args_action_flags() {
         let cases: &[(&str, CliCommand)] = &[
@@ -402,6 +689,7 @@ mod tests {
             ("--transcription-command", CliCommand::TranscriptionCommand),
             ("--paste-last", CliCommand::PasteLast),
             ("--cancel", CliCommand::Cancel),
+            ("--pause", CliCommand::Pause),
             ("--voice-mode", CliCommand::VoiceMode),
         ];
 
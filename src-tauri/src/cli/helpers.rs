@@ -1,4 +1,5 @@
-use super::types::ImportStrategy;
+use super::types::{ImportStrategy, TranscribeFormat};
+use crate::engine::QuantizationType;
 
 pub(super) fn parse_strategy(value: &str) -> Result<ImportStrategy, String> {
     match value.to_lowercase().as_str() {
@@ -11,6 +12,19 @@ pub(super) fn parse_strategy(value: &str) -> Result<ImportStrategy, String> {
     }
 }
 
+pub(super) fn parse_transcribe_format(value: &str) -> Result<TranscribeFormat, String> {
+    match value.to_lowercase().as_str() {
+        "text" => Ok(TranscribeFormat::Text),
+        "json" => Ok(TranscribeFormat::Json),
+        "srt" => Ok(TranscribeFormat::Srt),
+        "vtt" => Ok(TranscribeFormat::Vtt),
+        other => Err(format!(
+            "Error: Invalid format '{}'. Use 'text', 'json', 'srt' or 'vtt'.",
+            other
+        )),
+    }
+}
+
 pub(super) fn parse_file_arg(args: &[String], keyword: &str) -> Option<String> {
     let index = args.iter().position(|a| a == keyword)?;
     let file_path = args.get(index + 1)?;
@@ -20,7 +34,7 @@ pub(super) fn parse_file_arg(args: &[String], keyword: &str) -> Option<String> {
     Some(file_path.clone())
 }
 
-pub(super) fn parse_llm_mode(value: &str) -> Result<u8, String> {
+pub(crate) fn parse_llm_mode(value: &str) -> Result<u8, String> {
     match value.parse::<u8>() {
         Ok(n) if (1..=4).contains(&n) => Ok(n),
         _ => Err(format!(
@@ -30,6 +44,39 @@ pub(super) fn parse_llm_mode(value: &str) -> Result<u8, String> {
     }
 }
 
+pub(super) fn parse_beam_width(value: &str) -> Result<usize, String> {
+    let max = crate::engine::engine::MAX_BEAM_WIDTH;
+    match value.parse::<usize>() {
+        Ok(n) if (1..=max).contains(&n) => Ok(n),
+        _ => Err(format!(
+            "Error: Invalid beam width '{}'. Must be between 1 and {}.",
+            value, max
+        )),
+    }
+}
+
+pub(super) fn parse_quantization(value: &str) -> Result<QuantizationType, String> {
+    match value.to_lowercase().as_str() {
+        "fp32" => Ok(QuantizationType::FP32),
+        "int8" => Ok(QuantizationType::Int8),
+        other => Err(format!(
+            "Error: Invalid quantization '{}'. Use 'fp32' or 'int8'.",
+            other
+        )),
+    }
+}
+
+pub(super) fn parse_thread_count(value: &str) -> Result<usize, String> {
+    let max = crate::engine::MAX_SESSION_THREADS;
+    match value.parse::<usize>() {
+        Ok(n) if (1..=max).contains(&n) => Ok(n),
+        _ => Err(format!(
+            "Error: Invalid thread count '{}'. Must be between 1 and {}.",
+            value, max
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,6 +95,29 @@ mod tests {
         assert!(parse_strategy("").is_err());
     }
 
+    #[test]
+    fn test_parse_transcribe_format() {
+        assert_eq!(parse_transcribe_format("text"), Ok(TranscribeFormat::Text));
+        assert_eq!(parse_transcribe_format("JSON"), Ok(TranscribeFormat::Json));
+        assert_eq!(parse_transcribe_format("srt"), Ok(TranscribeFormat::Srt));
+        assert_eq!(parse_transcribe_format("vtt"), Ok(TranscribeFormat::Vtt));
+        assert!(parse_transcribe_format("xml").is_err());
+    }
+
+    #[test]
+    fn test_parse_quantization() {
+        assert_eq!(parse_quantization("fp32"), Ok(QuantizationType::FP32));
+        assert_eq!(parse_quantization("INT8"), Ok(QuantizationType::Int8));
+        assert!(parse_quantization("fp16").is_err());
+    }
+
+    #[test]
+    fn test_parse_thread_count() {
+        assert_eq!(parse_thread_count("4"), Ok(4));
+        assert!(parse_thread_count("0").is_err());
+        assert!(parse_thread_count("many").is_err());
+    }
+
     #[test]
     fn test_parse_llm_mode_valid() {
         assert_eq!(parse_llm_mode("1"), Ok(1));
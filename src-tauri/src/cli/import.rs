@@ -1,12 +1,16 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use log::info;
 use tauri::{AppHandle, Emitter, Manager};
 
-use super::types::{ImportStrategy, MurmureExportData};
+use super::types::{ImportStrategy, MurmureExportData, SystemSettings};
+use crate::audio::neural_vad::NEURAL_VAD_FILENAME;
+use crate::audio::vad::VadProvider;
 use crate::dictionary::Dictionary;
 use crate::formatting_rules::types::FormattingSettings;
 use crate::llm::types::LLMConnectSettings;
+use crate::settings::types::AppSettings;
 
 const MAX_SUPPORTED_VERSION: u32 = 1;
 const MAX_LLM_MODES: usize = 4;
@@ -73,6 +77,7 @@ pub fn execute_import(
             current.overlay_position = s.overlay_position.clone();
             current.api_enabled = s.api_enabled;
             current.api_port = s.api_port;
+            current.api_tls_enabled = s.api_tls_enabled;
             current.copy_to_clipboard = s.copy_to_clipboard;
             current.paste_method = s.paste_method.clone();
             current.persist_history = s.persist_history;
@@ -88,6 +93,10 @@ pub fn execute_import(
             current.streaming_text_width = s.streaming_text_width;
             current.streaming_font_size = s.streaming_font_size;
             current.streaming_max_lines = s.streaming_max_lines;
+            current.decoder_beam_width = s
+                .decoder_beam_width
+                .clamp(1, crate::engine::engine::MAX_BEAM_WIDTH);
+            apply_engine_settings(app, &mut current, s);
             imported_categories.push("settings");
         }
 
@@ -105,6 +114,7 @@ pub fn execute_import(
             current.llm_transform_4_shortcut = sc.llm_transform_4_shortcut.clone();
             current.voice_mode_toggle_shortcut = sc.voice_mode_toggle_shortcut.clone();
             current.cancel_shortcut = sc.cancel_shortcut.clone();
+            current.pause_shortcut = sc.pause_shortcut.clone();
             imported_categories.push("shortcuts");
         }
 
@@ -146,7 +156,12 @@ pub fn execute_import(
     }
 
     if let Some(ref imported) = data.categories.dictionary {
-        apply_dictionary(app, &imported.words(), strategy)?;
+        let weights = data
+            .categories
+            .dictionary_weights
+            .clone()
+            .unwrap_or_default();
+        apply_dictionary(app, &imported.words(), &weights, strategy)?;
         imported_categories.push("dictionary");
     }
 
@@ -195,6 +210,63 @@ pub fn execute_import(
     ))
 }
 
+// Settings added after the export format, applied like their commands do.
+fn apply_engine_settings(app: &AppHandle, current: &mut AppSettings, s: &SystemSettings) {
+    if let Some(language) = &s.whisper_language {
+        current.whisper_language = language
+            .as_deref()
+            .map(|l| l.trim().to_lowercase())
+            .filter(|l| !l.is_empty());
+    }
+    if let Some(quantization) = s.model_quantization {
+        current.model_quantization = quantization;
+    }
+    let threads = |count: Option<usize>| {
+        count.filter(|n| (1..=crate::engine::MAX_SESSION_THREADS).contains(n))
+    };
+    if let Some(intra_op) = s.intra_op_threads {
+        current.intra_op_threads = threads(intra_op);
+    }
+    if let Some(inter_op) = s.inter_op_threads {
+        current.inter_op_threads = threads(inter_op);
+    }
+    if let Some(minutes) = s.model_idle_unload_minutes {
+        current.model_idle_unload_minutes =
+            minutes.min(crate::audio::idle_unload::MAX_IDLE_UNLOAD_MINUTES);
+    }
+    // The neural model may not be installed on this machine.
+    if let Some(provider) = s.vad_provider {
+        if provider != VadProvider::Neural
+            || crate::utils::resources::resolve_resource_path(app, NEURAL_VAD_FILENAME).is_some()
+        {
+            current.vad_provider = provider;
+        }
+    }
+    if let Some(enabled) = s.audio_enhancement {
+        current.audio_enhancement = enabled;
+    }
+    // Needs PulseAudio or PipeWire on this machine.
+    if let Some(enabled) = s.dual_capture {
+        current.dual_capture = enabled && crate::audio::system_audio::check_available().is_ok();
+    }
+    let label = |label: &Option<String>| {
+        label
+            .as_deref()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+    };
+    if let Some(me) = label(&s.speaker_label_me) {
+        current.speaker_label_me = me;
+    }
+    if let Some(remote) = label(&s.speaker_label_remote) {
+        current.speaker_label_remote = remote;
+    }
+    if let Some(ms) = s.pre_roll_ms {
+        current.pre_roll_ms = ms.min(crate::audio::pre_roll::MAX_PRE_ROLL_MS);
+    }
+}
+
 fn apply_formatting_rules(
     app: &AppHandle,
     imported: &FormattingSettings,
@@ -289,11 +361,12 @@ fn apply_llm_connect(
 fn apply_dictionary(
     app: &AppHandle,
     imported: &[String],
+    weights: &HashMap<String, f32>,
     strategy: &ImportStrategy,
 ) -> Result<(), String> {
     match strategy {
         ImportStrategy::Replace => {
-            crate::dictionary::store::save(app, imported)?;
+            crate::dictionary::store::save_with_weights(app, imported, &HashMap::new())?;
         }
         ImportStrategy::Merge => {
             let current = crate::dictionary::store::load(app)?;
@@ -301,6 +374,8 @@ fn apply_dictionary(
             crate::dictionary::store::save(app, &merged)?;
         }
     }
+    // Imported weights override the stored ones of the same words.
+    crate::dictionary::store::save_weights(app, weights)?;
     Ok(())
 }
 
@@ -331,6 +406,20 @@ pub fn apply_hot_reload_side_effects(app: &AppHandle) {
         log::set_max_level(level);
     }
 
+    let model = app.state::<std::sync::Arc<crate::model::Model>>();
+    if model.session_options() != settings.session_options() {
+        model.set_session_options(settings.session_options());
+        crate::audio::reload_engine(app);
+    } else if let Some(engine) = app
+        .state::<crate::audio::types::AudioState>()
+        .engine
+        .lock()
+        .as_mut()
+    {
+        engine.set_language(settings.whisper_language.clone());
+    }
+    crate::audio::pre_roll::apply(app);
+
     let llm_settings = crate::llm::helpers::load_llm_connect_settings(app);
     let _ = app.emit("llm-settings-updated", &llm_settings);
 
@@ -340,6 +429,11 @@ pub fn apply_hot_reload_side_effects(app: &AppHandle) {
         Ok(dict) => {
             let dictionary_state = app.state::<Dictionary>();
             dictionary_state.set(dict);
+            dictionary_state
+                .set_weights(crate::dictionary::store::load_weights(app).unwrap_or_default());
+            if let Ok(suppression) = crate::dictionary::store::load_suppression(app) {
+                dictionary_state.set_suppression(suppression);
+            }
             let _ = app.emit("dictionary:updated", ());
         }
         Err(e) => {
@@ -371,6 +465,17 @@ mod tests {
             .contains("File must have a .murmure extension"));
     }
 
+    #[test]
+    fn test_system_settings_keep_missing_engine_settings() {
+        let s: SystemSettings =
+            serde_json::from_str(r#"{"intra_op_threads": null, "pre_roll_ms": 300}"#).unwrap();
+        assert_eq!(s.intra_op_threads, Some(None));
+        assert_eq!(s.pre_roll_ms, Some(300));
+        assert_eq!(s.inter_op_threads, None);
+        assert_eq!(s.whisper_language, None);
+        assert_eq!(s.vad_provider, None);
+    }
+
     #[test]
     fn test_validate_extension_no_extension() {
         let result = validate_extension("/tmp/config");
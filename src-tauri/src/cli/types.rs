@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
+use crate::audio::types::ProcessOptions;
+use crate::audio::vad::VadProvider;
+use crate::engine::{QuantizationType, SessionThreads};
 use crate::formatting_rules::types::FormattingSettings;
 use crate::llm::types::LLMConnectSettings;
 use crate::settings::types::PasteMethod;
@@ -12,13 +15,25 @@ pub enum CliCommand {
         file_path: String,
         strategy: ImportStrategy,
     },
+    /// `beam_width`, `quantization` and `threads` override the settings for
+    /// this run only.
     Transcribe {
         file_path: String,
+        format: TranscribeFormat,
+        beam_width: Option<usize>,
+        quantization: Option<QuantizationType>,
+        threads: SessionThreads,
+    },
+    /// `text` is `None` when it should be read from stdin.
+    Process {
+        text: Option<String>,
+        options: ProcessOptions,
     },
     Transcription,
     TranscriptionCommand,
     PasteLast,
     Cancel,
+    Pause,
     VoiceMode,
     LlmMode(u8),
     LlmTransform(u8),
@@ -30,6 +45,16 @@ pub enum ImportStrategy {
     Merge,
 }
 
+/// Output of `murmure transcribe`: plain text, JSON with word and segment
+/// timestamps, or subtitles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TranscribeFormat {
+    Text,
+    Json,
+    Srt,
+    Vtt,
+}
+
 #[derive(Deserialize)]
 pub struct MurmureExportData {
     pub version: u32,
@@ -50,6 +75,9 @@ pub struct ExportedCategories {
     pub formatting_rules: Option<FormattingSettings>,
     pub llm_connect: Option<LLMConnectSettings>,
     pub dictionary: Option<DictionaryExport>,
+    /// Weights of the exported words that do not use the default weight.
+    /// A separate key, so older versions still import the word list.
+    pub dictionary_weights: Option<HashMap<String, f32>>,
 }
 
 /// Current backups store the dictionary as a word list; older ones as a
@@ -78,6 +106,7 @@ pub struct SystemSettings {
     pub overlay_position: String,
     pub api_enabled: bool,
     pub api_port: u16,
+    pub api_tls_enabled: bool,
     pub copy_to_clipboard: bool,
     pub paste_method: PasteMethod,
     pub persist_history: bool,
@@ -93,6 +122,32 @@ pub struct SystemSettings {
     pub streaming_text_width: u32,
     pub streaming_font_size: u32,
     pub streaming_max_lines: u32,
+    pub decoder_beam_width: usize,
+    // `None` when the export predates the setting, which then keeps its
+    // current value. The inner `None` of a nullable setting is "automatic".
+    #[serde(deserialize_with = "present")]
+    pub whisper_language: Option<Option<String>>,
+    pub model_quantization: Option<QuantizationType>,
+    #[serde(deserialize_with = "present")]
+    pub intra_op_threads: Option<Option<usize>>,
+    #[serde(deserialize_with = "present")]
+    pub inter_op_threads: Option<Option<usize>>,
+    pub model_idle_unload_minutes: Option<u64>,
+    pub vad_provider: Option<VadProvider>,
+    pub audio_enhancement: Option<bool>,
+    pub dual_capture: Option<bool>,
+    pub speaker_label_me: Option<String>,
+    pub speaker_label_remote: Option<String>,
+    pub pre_roll_ms: Option<u64>,
+}
+
+// Tells a `null` value apart from a missing field.
+fn present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 impl Default for SystemSettings {
@@ -103,6 +158,7 @@ impl Default for SystemSettings {
             overlay_position: "bottom".to_string(),
             api_enabled: false,
             api_port: 4800,
+            api_tls_enabled: false,
             copy_to_clipboard: false,
             paste_method: PasteMethod::default(),
             persist_history: false,
@@ -118,6 +174,18 @@ impl Default for SystemSettings {
             streaming_text_width: 450,
             streaming_font_size: 11,
             streaming_max_lines: 5,
+            decoder_beam_width: 1,
+            whisper_language: None,
+            model_quantization: None,
+            intra_op_threads: None,
+            inter_op_threads: None,
+            model_idle_unload_minutes: None,
+            vad_provider: None,
+            audio_enhancement: None,
+            dual_capture: None,
+            speaker_label_me: None,
+            speaker_label_remote: None,
+            pre_roll_ms: None,
         }
     }
 }
@@ -194,6 +262,7 @@ pub struct ShortcutSettings {
     pub llm_transform_4_shortcut: String,
     pub voice_mode_toggle_shortcut: String,
     pub cancel_shortcut: String,
+    pub pause_shortcut: String,
 }
 
 impl Default for ShortcutSettings {
@@ -212,6 +281,7 @@ impl Default for ShortcutSettings {
             llm_transform_4_shortcut: "ctrl+alt+shift+4".to_string(),
             voice_mode_toggle_shortcut: "ctrl+shift+0".to_string(),
             cancel_shortcut: "ctrl+backspace".to_string(),
+            pause_shortcut: "ctrl+alt+space".to_string(),
         }
     }
 }
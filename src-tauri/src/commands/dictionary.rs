@@ -1,5 +1,6 @@
-use crate::dictionary::{self, Dictionary};
+use crate::dictionary::{self, Dictionary, SuppressionList};
 use crate::settings;
+use std::collections::HashMap;
 use tauri::{command, AppHandle, Emitter, Manager};
 
 #[command]
@@ -18,6 +19,11 @@ pub fn set_dictionary(app: AppHandle, dictionary: Vec<String>) -> Result<(), Str
     }
     dictionary::save(&app, &words)?;
     app.state::<Dictionary>().set(words);
+    app.state::<Dictionary>()
+        .set_weights(dictionary::load_weights(&app)?);
+    // A removed word takes its suppressed terms with it.
+    app.state::<Dictionary>()
+        .set_suppression(dictionary::load_suppression(&app)?);
 
     // Emit event so frontend can react (onboarding, UI refresh)
     let _ = app.emit("dictionary:updated", ());
@@ -30,6 +36,55 @@ pub fn get_dictionary(app: AppHandle) -> Result<Vec<String>, String> {
     dictionary::load(&app)
 }
 
+#[command]
+pub fn get_dictionary_weights(app: AppHandle) -> Result<HashMap<String, f32>, String> {
+    dictionary::load_weights(&app)
+}
+
+/// Set the boost weight of existing words; 1.0 resets a word to the default.
+#[command]
+pub fn set_dictionary_weights(
+    app: AppHandle,
+    weights: HashMap<String, f32>,
+) -> Result<HashMap<String, f32>, String> {
+    let weights = dictionary::save_weights(&app, &weights)?;
+    app.state::<Dictionary>().set_weights(weights.clone());
+
+    let _ = app.emit("dictionary:updated", ());
+    Ok(weights)
+}
+
+#[command]
+pub fn get_suppression_list(app: AppHandle) -> Result<SuppressionList, String> {
+    dictionary::load_suppression(&app)
+}
+
+#[command]
+pub fn set_suppression_list(app: AppHandle, suppression: SuppressionList) -> Result<(), String> {
+    let mut entries = HashMap::new();
+    for (word, terms) in suppression.entries {
+        let mut unique: Vec<String> = Vec::new();
+        for term in terms {
+            if !unique.contains(&term) {
+                unique.push(term);
+            }
+        }
+        if !unique.is_empty() {
+            entries.insert(word, unique);
+        }
+    }
+    let suppression = SuppressionList {
+        entries,
+        fallback: suppression.fallback,
+    };
+    dictionary::save_suppression(&app, &suppression)?;
+    app.state::<Dictionary>()
+        .set_suppression(dictionary::load_suppression(&app)?);
+
+    let _ = app.emit("dictionary:updated", ());
+    Ok(())
+}
+
 #[command]
 pub fn export_dictionary(app: AppHandle, file_path: String) -> Result<(), String> {
     dictionary::export_dictionary(&app, file_path)?;
@@ -41,6 +96,10 @@ pub fn import_dictionary(app: AppHandle, file_path: String) -> Result<(), String
     dictionary::import_dictionary(&app, file_path)?;
     let words = dictionary::load(&app)?;
     app.state::<Dictionary>().set(words);
+    app.state::<Dictionary>()
+        .set_weights(dictionary::load_weights(&app)?);
+    app.state::<Dictionary>()
+        .set_suppression(dictionary::load_suppression(&app)?);
 
     let _ = app.emit("dictionary:updated", ());
     Ok(())
@@ -1,4 +1,8 @@
+use crate::http_api::helpers::{
+    generate_api_token, has_api_token, resolve_bind_address, store_api_token,
+};
 use crate::http_api::{spawn_http_api_thread, HttpApiState};
+use crate::llm::types::SecretString;
 use crate::settings;
 use log::info;
 use tauri::{command, AppHandle, Manager};
@@ -32,6 +36,69 @@ pub fn set_api_port(app: AppHandle, port: u16) -> Result<(), String> {
     settings::save_settings(&app, &s)
 }
 
+#[command]
+pub fn get_api_bind_address(app: AppHandle) -> Result<Option<String>, String> {
+    let s = settings::load_settings(&app);
+    Ok(s.api_bind_address)
+}
+
+#[command]
+pub fn set_api_bind_address(app: AppHandle, address: Option<String>) -> Result<(), String> {
+    let address = address
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty());
+    let ip = resolve_bind_address(address.as_deref())?;
+    if !ip.is_loopback() && !has_api_token() {
+        return Err("Generate an API token before exposing the API on the network".to_string());
+    }
+    let mut s = settings::load_settings(&app);
+    s.api_bind_address = address;
+    settings::save_settings(&app, &s)
+}
+
+#[command]
+pub fn get_api_tls_enabled(app: AppHandle) -> Result<bool, String> {
+    let s = settings::load_settings(&app);
+    Ok(s.api_tls_enabled)
+}
+
+#[command]
+pub fn set_api_tls_enabled(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut s = settings::load_settings(&app);
+    s.api_tls_enabled = enabled;
+    settings::save_settings(&app, &s)
+}
+
+#[command]
+pub fn has_http_api_token() -> Result<bool, String> {
+    Ok(has_api_token())
+}
+
+/// Replace the API token with a new random one. The token is returned once,
+/// for the user to copy; only the keyring keeps it afterwards.
+#[command]
+pub fn generate_http_api_token(app: AppHandle) -> Result<String, String> {
+    let token = generate_api_token();
+    store_api_token(&token)?;
+    app.state::<HttpApiState>()
+        .set_token(Some(SecretString::new(token.clone())));
+    Ok(token)
+}
+
+#[command]
+pub fn revoke_http_api_token(app: AppHandle) -> Result<(), String> {
+    let s = settings::load_settings(&app);
+    let ip = resolve_bind_address(s.api_bind_address.as_deref())?;
+    if !ip.is_loopback() {
+        return Err(
+            "The API token is required while the API is exposed on the network".to_string(),
+        );
+    }
+    store_api_token("")?;
+    app.state::<HttpApiState>().set_token(None);
+    Ok(())
+}
+
 #[command]
 pub fn start_http_api_server(app: AppHandle) -> Result<String, String> {
     let s = settings::load_settings(&app);
@@ -1,6 +1,7 @@
-use crate::model::Model;
+use crate::model::{Model, ModelInfo};
+use std::path::Path;
 use std::sync::Arc;
-use tauri::{command, State};
+use tauri::{command, AppHandle, Manager, State};
 
 #[command]
 pub fn is_model_available(model: State<Arc<Model>>) -> bool {
@@ -13,3 +14,46 @@ pub fn get_model_path(model: State<Arc<Model>>) -> Result<String, String> {
 
     Ok(path.to_string_lossy().to_string())
 }
+
+#[command]
+pub fn list_models(model: State<Arc<Model>>) -> Vec<ModelInfo> {
+    model.list_models()
+}
+
+#[command]
+pub async fn import_model(app: AppHandle, path: String) -> Result<ModelInfo, String> {
+    // Copying and hashing a model takes a while: keep it off the main thread.
+    tauri::async_runtime::spawn_blocking(move || {
+        app.state::<Arc<Model>>()
+            .import_model(Path::new(&path))
+            .map_err(|e| format!("{:#}", e))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Make `id` the active model; the engine is reloaded in the background.
+#[command]
+pub async fn set_active_model(app: AppHandle, id: String) -> Result<(), String> {
+    // Dropping the engine waits for a running transcription to release it.
+    tauri::async_runtime::spawn_blocking(move || {
+        let info = app
+            .state::<Arc<Model>>()
+            .list_models()
+            .into_iter()
+            .find(|m| m.id == id)
+            .ok_or_else(|| format!("Model '{}' is not installed", id))?;
+        if info.active {
+            return Ok(());
+        }
+
+        let mut s = crate::settings::load_settings(&app);
+        s.asr_backend = info.backend;
+        s.active_model = if info.bundled { None } else { Some(info.id) };
+        crate::settings::save_settings(&app, &s)?;
+        crate::audio::reload_engine(&app);
+        Ok(())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
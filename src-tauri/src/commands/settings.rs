@@ -1,5 +1,6 @@
+use crate::audio::system_audio::{DEFAULT_SPEAKER_LABELS, FRENCH_SPEAKER_LABELS};
 use crate::settings::AppSettings;
-use tauri::{command, AppHandle};
+use tauri::{command, AppHandle, Manager};
 
 #[command]
 pub fn get_all_settings(app: AppHandle) -> Result<AppSettings, String> {
@@ -42,6 +43,7 @@ pub fn set_current_mic_id(
     s.mic_label = mic_label;
     crate::settings::save_settings(&app, &s)?;
     crate::audio::microphone::update_mic_cache(&app, mic_id);
+    crate::audio::pre_roll::apply(&app);
     Ok(())
 }
 
@@ -74,6 +76,97 @@ pub fn set_sound_volume(app: AppHandle, percent: u8) -> Result<(), String> {
     crate::settings::save_settings(&app, &s)
 }
 
+#[command]
+pub fn get_decoder_beam_width(app: AppHandle) -> Result<usize, String> {
+    let s = crate::settings::load_settings(&app);
+    Ok(s.decoder_beam_width)
+}
+
+#[command]
+pub fn set_decoder_beam_width(app: AppHandle, width: usize) -> Result<(), String> {
+    let width = width.clamp(1, crate::engine::engine::MAX_BEAM_WIDTH);
+    let mut s = crate::settings::load_settings(&app);
+    s.decoder_beam_width = width;
+    crate::settings::save_settings(&app, &s)?;
+    app.state::<crate::audio::types::AudioState>()
+        .set_beam_width(width);
+    Ok(())
+}
+
+/// Set the language Whisper transcribes; empty detects it.
+#[command]
+pub fn set_whisper_language(app: AppHandle, language: String) -> Result<(), String> {
+    let language = Some(language.trim().to_lowercase()).filter(|l| !l.is_empty());
+    let mut s = crate::settings::load_settings(&app);
+    s.whisper_language = language.clone();
+    crate::settings::save_settings(&app, &s)?;
+    if let Some(engine) = app
+        .state::<crate::audio::types::AudioState>()
+        .engine
+        .lock()
+        .as_mut()
+    {
+        engine.set_language(language);
+    }
+    Ok(())
+}
+
+/// Switch the model precision; the engine is reloaded in the background.
+#[command]
+pub fn set_model_quantization(
+    app: AppHandle,
+    quantization: crate::engine::QuantizationType,
+) -> Result<(), String> {
+    let mut s = crate::settings::load_settings(&app);
+    s.model_quantization = quantization;
+    crate::settings::save_settings(&app, &s)?;
+    apply_session_options(&app, s.session_options());
+    Ok(())
+}
+
+/// Set the ONNX Runtime thread counts, `None` (or 0) lets it decide. The
+/// engine is reloaded in the background.
+#[command]
+pub fn set_inference_threads(
+    app: AppHandle,
+    intra_op: Option<usize>,
+    inter_op: Option<usize>,
+) -> Result<(), String> {
+    let max = crate::engine::MAX_SESSION_THREADS;
+    let check = |count: Option<usize>| match count.filter(|&n| n > 0) {
+        Some(n) if n > max => Err(format!("Thread count must be at most {}", max)),
+        count => Ok(count),
+    };
+    let mut s = crate::settings::load_settings(&app);
+    s.intra_op_threads = check(intra_op)?;
+    s.inter_op_threads = check(inter_op)?;
+    crate::settings::save_settings(&app, &s)?;
+    apply_session_options(&app, s.session_options());
+    Ok(())
+}
+
+/// Unload the model after `minutes` without use, 0 keeps it loaded.
+#[command]
+pub fn set_model_idle_unload(app: AppHandle, minutes: u64) -> Result<(), String> {
+    let mut s = crate::settings::load_settings(&app);
+    s.model_idle_unload_minutes = minutes.min(crate::audio::idle_unload::MAX_IDLE_UNLOAD_MINUTES);
+    crate::settings::save_settings(&app, &s)
+}
+
+#[command]
+pub fn is_model_warming(app: AppHandle) -> bool {
+    app.state::<crate::audio::types::AudioState>()
+        .is_engine_warming()
+}
+
+fn apply_session_options(app: &AppHandle, options: crate::engine::SessionOptions) {
+    let model = app.state::<std::sync::Arc<crate::model::Model>>();
+    if model.session_options() != options {
+        model.set_session_options(options);
+        crate::audio::reload_engine(app);
+    }
+}
+
 #[command]
 pub fn set_lower_output_while_recording(app: AppHandle, enabled: bool) -> Result<(), String> {
     let mut s = crate::settings::load_settings(&app);
@@ -95,6 +188,61 @@ pub fn set_keep_recordings(app: AppHandle, enabled: bool) -> Result<(), String>
     crate::settings::save_settings(&app, &s)
 }
 
+#[command]
+pub fn set_audio_enhancement(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut s = crate::settings::load_settings(&app);
+    s.audio_enhancement = enabled;
+    crate::settings::save_settings(&app, &s)
+}
+
+#[command]
+pub fn set_dual_capture(app: AppHandle, enabled: bool) -> Result<(), String> {
+    if enabled {
+        crate::audio::system_audio::check_available()?;
+    }
+    let mut s = crate::settings::load_settings(&app);
+
+    // On first activation, apply French labels if language is French
+    if enabled
+        && !s.dual_capture
+        && s.language.starts_with("fr")
+        && (s.speaker_label_me.as_str(), s.speaker_label_remote.as_str()) == DEFAULT_SPEAKER_LABELS
+    {
+        s.speaker_label_me = FRENCH_SPEAKER_LABELS.0.to_string();
+        s.speaker_label_remote = FRENCH_SPEAKER_LABELS.1.to_string();
+    }
+
+    s.dual_capture = enabled;
+    crate::settings::save_settings(&app, &s)
+}
+
+/// Rename the two sides of the dual capture transcript.
+#[command]
+pub fn set_speaker_labels(app: AppHandle, me: String, remote: String) -> Result<(), String> {
+    let (me, remote) = (me.trim(), remote.trim());
+    if me.is_empty() || remote.is_empty() {
+        return Err("Speaker labels cannot be empty".to_string());
+    }
+    if me.eq_ignore_ascii_case(remote) {
+        return Err("Speaker labels must differ".to_string());
+    }
+    let mut s = crate::settings::load_settings(&app);
+    s.speaker_label_me = me.to_string();
+    s.speaker_label_remote = remote.to_string();
+    crate::settings::save_settings(&app, &s)
+}
+
+/// Set the pre-roll length, 0 to turn it off. The pre-roll stream opens or
+/// closes right away.
+#[command]
+pub fn set_pre_roll_ms(app: AppHandle, value: u64) -> Result<(), String> {
+    let mut s = crate::settings::load_settings(&app);
+    s.pre_roll_ms = value.min(crate::audio::pre_roll::MAX_PRE_ROLL_MS);
+    crate::settings::save_settings(&app, &s)?;
+    crate::audio::pre_roll::apply(&app);
+    Ok(())
+}
+
 #[command]
 pub fn get_recordings_dir(app: AppHandle) -> Result<String, String> {
     crate::audio::helpers::ensure_recordings_dir(&app)
@@ -100,6 +100,23 @@ pub fn set_cancel_shortcut(app: AppHandle, binding: String) -> Result<String, St
     })
 }
 
+// ============================================================================
+// Pause Recording Shortcut
+// ============================================================================
+
+#[command]
+pub fn get_pause_shortcut(app: AppHandle) -> Result<String, String> {
+    let s = settings::load_settings(&app);
+    Ok(s.pause_shortcut)
+}
+
+#[command]
+pub fn set_pause_shortcut(app: AppHandle, binding: String) -> Result<String, String> {
+    apply_shortcut(&app, &binding, ShortcutAction::PauseRecording, |s| {
+        &mut s.pause_shortcut
+    })
+}
+
 // ============================================================================
 // Cancel Recording (IPC command for overlay button)
 // ============================================================================
@@ -1,3 +1,5 @@
+use crate::audio::neural_vad::NEURAL_VAD_FILENAME;
+use crate::audio::vad::VadProvider;
 use crate::wake_word::wake_word::normalize_text;
 use tauri::{command, AppHandle, Emitter, Manager};
 
@@ -168,6 +170,28 @@ pub fn set_silence_timeout_ms(app: AppHandle, value: u64) -> Result<(), String>
     Ok(())
 }
 
+/// Select the voice detection. It applies to the next recording, and to the
+/// wake word listener right away.
+#[command]
+pub fn set_vad_provider(app: AppHandle, provider: VadProvider) -> Result<(), String> {
+    if provider == VadProvider::Neural
+        && crate::utils::resources::resolve_resource_path(&app, NEURAL_VAD_FILENAME).is_none()
+    {
+        return Err(format!(
+            "The voice detection model {} is not installed",
+            NEURAL_VAD_FILENAME
+        ));
+    }
+    let mut s = crate::settings::load_settings(&app);
+    if s.vad_provider == provider {
+        return Ok(());
+    }
+    s.vad_provider = provider;
+    crate::settings::save_settings(&app, &s)?;
+    restart_listener_if_active(&app, &s);
+    Ok(())
+}
+
 #[command]
 pub fn get_auto_enter_after_wake_word(app: AppHandle) -> Result<bool, String> {
     let s = crate::settings::load_settings(&app);
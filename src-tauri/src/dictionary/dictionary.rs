@@ -1,11 +1,35 @@
+use crate::dictionary::{SuppressionFallback, DEFAULT_WORD_WEIGHT, SUPPRESSION_WEIGHT};
 use crate::engine::helpers::fold_accents;
-use crate::engine::ParakeetEngine;
+use crate::engine::transcription_engine::TranscriptionWord;
+use crate::engine::AsrEngine;
 use std::collections::HashMap;
 
 /// Resync the phrase-boosting words from the user dictionary onto the engine.
 /// Must run before transcription so the boost tree reflects the current vocab.
-pub fn sync_boost_words(engine: &mut ParakeetEngine, dictionary: &[String]) {
-    engine.set_boost_words(dictionary);
+/// Words missing from `weights` are boosted with `DEFAULT_WORD_WEIGHT`;
+/// `suppressed` words go in the same tree with `SUPPRESSION_WEIGHT`, and win
+/// over a dictionary entry with the same spelling.
+pub fn sync_boost_words(
+    engine: &mut AsrEngine,
+    dictionary: &[String],
+    weights: &HashMap<String, f32>,
+    suppressed: &[String],
+) {
+    let is_suppressed = |word: &str| suppressed.iter().any(|s| s.eq_ignore_ascii_case(word));
+    let entries: Vec<(String, f32)> = dictionary
+        .iter()
+        .filter(|word| !is_suppressed(word))
+        .map(|word| {
+            let weight = weights.get(word).copied().unwrap_or(DEFAULT_WORD_WEIGHT);
+            (word.clone(), weight)
+        })
+        .chain(
+            suppressed
+                .iter()
+                .map(|word| (word.clone(), SUPPRESSION_WEIGHT)),
+        )
+        .collect();
+    engine.set_boost_words(&entries);
 }
 
 /// Words shorter than this (normalized, in chars) are never fuzzy-corrected,
@@ -97,18 +121,7 @@ pub fn restore_dictionary_casing_gated(
         .map(|key| (normalize_word(key), key))
         .collect();
 
-    let segments: Vec<(&str, &str)> = text
-        .split_inclusive(|c: char| !c.is_alphanumeric())
-        .map(|segment| {
-            let boundary_len = segment
-                .chars()
-                .rev()
-                .take_while(|c| !c.is_alphanumeric())
-                .map(char::len_utf8)
-                .sum::<usize>();
-            segment.split_at(segment.len() - boundary_len)
-        })
-        .collect();
+    let segments = word_segments(text);
 
     let mut out = String::with_capacity(text.len());
     let mut i = 0;
@@ -135,6 +148,194 @@ pub fn restore_dictionary_casing_gated(
     out
 }
 
+/// Split `text` into (word, trailing separator) pairs; concatenated, the
+/// pairs give back `text`. A run of separators yields pairs with an empty word.
+fn word_segments(text: &str) -> Vec<(&str, &str)> {
+    text.split_inclusive(|c: char| !c.is_alphanumeric())
+        .map(|segment| {
+            let boundary_len = segment
+                .chars()
+                .rev()
+                .take_while(|c| !c.is_alphanumeric())
+                .map(char::len_utf8)
+                .sum::<usize>();
+            segment.split_at(segment.len() - boundary_len)
+        })
+        .collect()
+}
+
+/// Post-correction fallback of the suppression list: suppressed words the
+/// decoder still emitted are masked or removed, per `fallback`. Entries match
+/// whole words, ignoring case and accents; a phrase matches consecutive words.
+pub fn apply_suppression(
+    text: &str,
+    suppressed: &[String],
+    fallback: SuppressionFallback,
+) -> String {
+    if suppressed.is_empty() || fallback == SuppressionFallback::Off {
+        return text.to_string();
+    }
+
+    let entries = suppression_entries(suppressed);
+    let segments = word_segments(text);
+
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < segments.len() {
+        let matched = entries
+            .iter()
+            .filter(|entry| suppressed_match_at(&segments, i, entry))
+            .map(Vec::len)
+            .max();
+        let Some(len) = matched else {
+            out.push_str(segments[i].0);
+            out.push_str(segments[i].1);
+            i += 1;
+            continue;
+        };
+
+        log::info!("Suppressed word found in transcription ({:?})", fallback);
+        let span = &segments[i..i + len];
+        if fallback == SuppressionFallback::Mask {
+            for (word, trailing) in span {
+                out.push_str(&mask_word(word));
+                out.push_str(trailing);
+            }
+        } else {
+            // Keep the punctuation after the removed words, not the spacing.
+            let trailing = span[len - 1].1;
+            if !trailing.trim().is_empty() {
+                while out.ends_with(' ') {
+                    out.pop();
+                }
+                out.push_str(trailing);
+            }
+        }
+        i += len;
+    }
+
+    if fallback == SuppressionFallback::Remove && !text.ends_with(char::is_whitespace) {
+        out.truncate(out.trim_end().len());
+    }
+    out
+}
+
+/// Keep the alternatives of a corrected word in line with its text: they go
+/// through the same dictionary correction, suppressed ones are dropped, and
+/// so are those that now read like the word or like a likelier alternative.
+pub fn correct_alternatives(
+    word: &mut TranscriptionWord,
+    dictionary: &[String],
+    suppressed: &[String],
+) {
+    let mut seen = vec![normalize_word(&word.text)];
+    word.alternatives.retain_mut(|alternative| {
+        let confidence = [(alternative.word.clone(), alternative.probability)];
+        alternative.word = correct_transcription(&alternative.word, dictionary, &confidence);
+        let key = normalize_word(&alternative.word);
+        let is_suppressed =
+            apply_suppression(&alternative.word, suppressed, SuppressionFallback::Remove)
+                != alternative.word;
+        if key.is_empty() || is_suppressed || seen.contains(&key) {
+            return false;
+        }
+        seen.push(key);
+        true
+    });
+}
+
+/// `apply_suppression` on timed words, so subtitles and word timings match
+/// the text. A phrase may span several words. Masked words keep their timing;
+/// removed words are dropped with it.
+pub fn suppress_words(
+    words: Vec<TranscriptionWord>,
+    suppressed: &[String],
+    fallback: SuppressionFallback,
+) -> Vec<TranscriptionWord> {
+    if suppressed.is_empty() || fallback == SuppressionFallback::Off {
+        return words;
+    }
+
+    let entries = suppression_entries(suppressed);
+    // The word segments of every word, with the index of the word they
+    // belong to, so a phrase is matched across words.
+    let (owners, segments): (Vec<usize>, Vec<(&str, &str)>) = words
+        .iter()
+        .enumerate()
+        .flat_map(|(w, word)| {
+            word_segments(&word.text)
+                .into_iter()
+                .map(move |segment| (w, segment))
+        })
+        .unzip();
+
+    let mut texts = vec![String::new(); words.len()];
+    let mut i = 0;
+    while i < segments.len() {
+        let len = entries
+            .iter()
+            .filter(|entry| suppressed_match_at(&segments, i, entry))
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0);
+        if len == 0 {
+            texts[owners[i]].push_str(segments[i].0);
+            texts[owners[i]].push_str(segments[i].1);
+            i += 1;
+            continue;
+        }
+        for k in i..i + len {
+            let (word, trailing) = segments[k];
+            if fallback == SuppressionFallback::Mask {
+                texts[owners[k]].push_str(&mask_word(word));
+            }
+            texts[owners[k]].push_str(trailing);
+        }
+        i += len;
+    }
+
+    words
+        .into_iter()
+        .zip(texts)
+        .filter_map(|(mut word, text)| {
+            // A removed word leaves at most its punctuation.
+            let removed = text != word.text && !text.chars().any(char::is_alphanumeric);
+            word.text = text;
+            (!removed).then_some(word)
+        })
+        .collect()
+}
+
+/// Each suppressed entry as its normalized words.
+fn suppression_entries(suppressed: &[String]) -> Vec<Vec<String>> {
+    suppressed
+        .iter()
+        .map(|entry| {
+            entry
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(normalize_word)
+                .collect::<Vec<String>>()
+        })
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+fn suppressed_match_at(segments: &[(&str, &str)], i: usize, entry: &[String]) -> bool {
+    segments.len() >= i + entry.len()
+        && entry
+            .iter()
+            .zip(&segments[i..])
+            .all(|(word, (segment, _))| normalize_word(segment) == *word)
+}
+
+fn mask_word(word: &str) -> String {
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| if i == 0 { c } else { '*' })
+        .collect()
+}
+
 /// Joined words of this length and above (normalized, in chars) may absorb
 /// 3 edits in the bigram pass; shorter ones keep the unigram thresholds.
 const POSTCORR_BIGRAM_LONG_LEN: usize = 12;
@@ -295,9 +496,11 @@ fn normalize_word(word: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        restore_dictionary_casing, restore_dictionary_casing_gated, POSTCORR_CONF_THRESHOLD,
-        POSTCORR_MAX_DICT_WORDS,
+        apply_suppression, correct_alternatives, restore_dictionary_casing,
+        restore_dictionary_casing_gated, suppress_words, SuppressionFallback,
+        POSTCORR_CONF_THRESHOLD, POSTCORR_MAX_DICT_WORDS,
     };
+    use crate::engine::transcription_engine::{TranscriptionWord, WordAlternative};
     use std::collections::HashMap;
 
     fn dict(words: &[&str]) -> Vec<String> {
@@ -512,4 +715,79 @@ mod tests {
         let out = restore_dictionary_casing("(Sélecoxyb).", &dictionary);
         assert_eq!(out, "(célécoxib).");
     }
+
+    #[test]
+    fn suppression_masks_whole_word_ignoring_case_and_accents() {
+        let suppressed = dict(&["Zoé"]);
+        let out = apply_suppression(
+            "merci zoe, et zoetrope.",
+            &suppressed,
+            SuppressionFallback::Mask,
+        );
+        assert_eq!(out, "merci z**, et zoetrope.");
+    }
+
+    #[test]
+    fn suppression_removes_phrase_and_keeps_punctuation() {
+        let suppressed = dict(&["Acme Cloud"]);
+        let out = apply_suppression(
+            "we moved to acme cloud. Then acme left",
+            &suppressed,
+            SuppressionFallback::Remove,
+        );
+        assert_eq!(out, "we moved to. Then acme left");
+        let out = apply_suppression("try Acme Cloud", &suppressed, SuppressionFallback::Remove);
+        assert_eq!(out, "try");
+    }
+
+    fn texts(words: &[TranscriptionWord]) -> Vec<&str> {
+        words.iter().map(|w| w.text.as_str()).collect()
+    }
+
+    #[test]
+    fn suppression_applies_to_timed_words() {
+        let suppressed = dict(&["Zoé", "Acme Cloud"]);
+        let words = vec![
+            TranscriptionWord::new("merci", 0.0, 0.5),
+            TranscriptionWord::new("zoe,", 1.0, 1.5),
+            TranscriptionWord::new("et", 2.0, 2.5),
+            TranscriptionWord::new("Acme", 3.0, 3.5),
+            TranscriptionWord::new("Cloud.", 4.0, 4.5),
+        ];
+
+        let masked = suppress_words(words.clone(), &suppressed, SuppressionFallback::Mask);
+        assert_eq!(
+            texts(&masked),
+            vec!["merci", "z**,", "et", "A***", "C****."]
+        );
+        assert_eq!(masked[1].start, 1.0);
+
+        let removed = suppress_words(words, &suppressed, SuppressionFallback::Remove);
+        assert_eq!(texts(&removed), vec!["merci", "et"]);
+        assert_eq!(removed[1].start, 2.0);
+    }
+
+    #[test]
+    fn suppression_off_leaves_text_untouched() {
+        let suppressed = dict(&["Acme"]);
+        let out = apply_suppression("acme", &suppressed, SuppressionFallback::Off);
+        assert_eq!(out, "acme");
+    }
+
+    #[test]
+    fn corrected_word_drops_stale_alternatives() {
+        let alternative = |word: &str, probability: f32| WordAlternative {
+            word: word.to_string(),
+            probability,
+        };
+        let mut word = TranscriptionWord::new("Kubernetes", 0.0, 0.5);
+        word.alternatives = vec![
+            alternative("Kubernetis", 0.3),
+            alternative("Bob", 0.2),
+            alternative("cube", 0.1),
+            alternative("Cube", 0.05),
+        ];
+        correct_alternatives(&mut word, &dict(&["Kubernetes"]), &dict(&["Bob"]));
+        assert_eq!(word.alternatives, vec![alternative("cube", 0.1)]);
+    }
 }
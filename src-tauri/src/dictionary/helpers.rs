@@ -1,3 +1,5 @@
+use crate::dictionary::{DictionaryError, DEFAULT_WORD_WEIGHT, MAX_WORD_WEIGHT, MIN_WORD_WEIGHT};
+
 const LEGACY_CSV_HEADERS: [&str; 7] = ["word", "words", "term", "terms", "mot", "mots", "termes"];
 
 fn extract_quoted_field(line: &str) -> Option<String> {
@@ -54,6 +56,46 @@ pub fn normalize_import_content(raw: &str, is_legacy_csv: bool) -> Vec<String> {
     entries
 }
 
+/// Weight stored for an entry: `None` for the default weight, which is not
+/// written anywhere, else the weight clamped to the supported range.
+pub fn normalize_weight(weight: f32) -> Option<f32> {
+    if !weight.is_finite() || (weight - DEFAULT_WORD_WEIGHT).abs() < f32::EPSILON {
+        return None;
+    }
+    Some(weight.clamp(MIN_WORD_WEIGHT, MAX_WORD_WEIGHT))
+}
+
+/// Split the optional `| weight` suffix off an imported entry
+/// ("Lamotrigine | 2"). Entries without a pipe keep the default weight.
+pub fn split_entry_weight(entry: &str) -> Result<(String, Option<f32>), DictionaryError> {
+    let Some((word, weight)) = entry.rsplit_once('|') else {
+        return Ok((entry.to_string(), None));
+    };
+    let weight: f32 = weight
+        .trim()
+        .parse()
+        .map_err(|_| DictionaryError::InvalidWeight(entry.to_string()))?;
+    if !(MIN_WORD_WEIGHT..=MAX_WORD_WEIGHT).contains(&weight) {
+        return Err(DictionaryError::InvalidWeight(entry.to_string()));
+    }
+    Ok((word.trim().to_string(), normalize_weight(weight)))
+}
+
+/// Split the optional `! term, term` suffix off an imported entry
+/// ("Dupont | 2 ! Dupond, Du Pont"): the suppressed terms of the entry.
+pub fn split_entry_suppressed(entry: &str) -> (String, Vec<String>) {
+    let Some((entry, terms)) = entry.split_once('!') else {
+        return (entry.to_string(), Vec::new());
+    };
+    let terms = terms
+        .split(',')
+        .map(str::trim)
+        .filter(|term| !term.is_empty())
+        .map(str::to_string)
+        .collect();
+    (entry.trim().to_string(), terms)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,4 +250,57 @@ mod tests {
     fn test_normalize_only_empty_lines() {
         assert!(normalize_import_content("\n  \n\n", false).is_empty());
     }
+
+    #[test]
+    fn test_split_entry_weight_reads_pipe_suffix() {
+        let (word, weight) = split_entry_weight("Lamotrigine | 2").unwrap();
+        assert_eq!(word, "Lamotrigine");
+        assert_eq!(weight, Some(2.0));
+    }
+
+    #[test]
+    fn test_split_entry_weight_defaults_without_pipe() {
+        let (word, weight) = split_entry_weight("left anterior descending artery").unwrap();
+        assert_eq!(word, "left anterior descending artery");
+        assert_eq!(weight, None);
+    }
+
+    #[test]
+    fn test_split_entry_weight_drops_default_weight() {
+        let (_, weight) = split_entry_weight("Parakeet|1").unwrap();
+        assert_eq!(weight, None);
+    }
+
+    #[test]
+    fn test_split_entry_weight_rejects_invalid_weight() {
+        for entry in ["Dupont | weak", "Dupont | 0.1", "Dupont | 5"] {
+            match split_entry_weight(entry) {
+                Err(DictionaryError::InvalidWeight(e)) => assert_eq!(e, entry),
+                other => panic!("Expected InvalidWeight error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_entry_suppressed_reads_bang_suffix() {
+        assert_eq!(
+            split_entry_suppressed("Dupont | 2 ! Dupond, Du Pont,"),
+            (
+                "Dupont | 2".to_string(),
+                vec!["Dupond".to_string(), "Du Pont".to_string()]
+            )
+        );
+        assert_eq!(
+            split_entry_suppressed("Parakeet"),
+            ("Parakeet".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn test_normalize_weight_clamps_out_of_range() {
+        assert_eq!(normalize_weight(10.0), Some(MAX_WORD_WEIGHT));
+        assert_eq!(normalize_weight(0.0), Some(MIN_WORD_WEIGHT));
+        assert_eq!(normalize_weight(DEFAULT_WORD_WEIGHT), None);
+        assert_eq!(normalize_weight(f32::NAN), None);
+    }
 }
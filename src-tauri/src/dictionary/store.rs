@@ -1,30 +1,173 @@
+use std::collections::HashMap;
 use std::fs;
 use tauri::AppHandle;
 use tauri_plugin_store::StoreExt;
 
-use crate::dictionary::{normalize_import_content, DictionaryError};
+use crate::dictionary::{
+    normalize_import_content, normalize_weight, split_entry_suppressed, split_entry_weight,
+    DictionaryError, SuppressionFallback, SuppressionList, MAX_PHRASE_WORDS,
+};
+
+const SUPPRESSION_STORE_FILE: &str = "suppression.json";
+const SUPPRESSION_FALLBACK_KEY: &str = "fallback";
 
 fn contains_word_case_insensitive(words: &[String], word: &str) -> bool {
     words.iter().any(|w| w.eq_ignore_ascii_case(word))
 }
 
-/// Words are the store keys. The value is a vestigial per-word language list:
-/// ignored on read and written empty, so the on-disk format stays compatible
-/// with older versions in both directions.
+/// Words are the store keys. The value is `{ "weight": w, "suppress": [..] }`
+/// for an entry with its own boost weight or suppressed terms (either field
+/// may be missing), else the vestigial per-word language list, written empty.
+/// Older versions ignore the value on read, so the on-disk format stays
+/// compatible with them in both directions.
 pub fn load(app: &AppHandle) -> Result<Vec<String>, String> {
     let store = app.store("dictionary.json").map_err(|e| e.to_string())?;
     Ok(store.entries().into_iter().map(|(word, _)| word).collect())
 }
 
+/// Weights of the entries that do not use the default weight.
+pub fn load_weights(app: &AppHandle) -> Result<HashMap<String, f32>, String> {
+    let store = app.store("dictionary.json").map_err(|e| e.to_string())?;
+    Ok(store
+        .entries()
+        .into_iter()
+        .filter_map(|(word, value)| weight_from_value(&value).map(|weight| (word, weight)))
+        .collect())
+}
+
+/// Suppressed terms of the entries that have some.
+pub fn load_suppressed(app: &AppHandle) -> Result<HashMap<String, Vec<String>>, String> {
+    let store = app.store("dictionary.json").map_err(|e| e.to_string())?;
+    Ok(store
+        .entries()
+        .into_iter()
+        .map(|(word, value)| (word, suppressed_from_value(&value)))
+        .filter(|(_, suppressed)| !suppressed.is_empty())
+        .collect())
+}
+
+/// Save the word list. Words still present keep their stored weight.
 pub fn save(app: &AppHandle, words: &[String]) -> Result<(), String> {
+    let weights = load_weights(app)?;
+    save_with_weights(app, words, &weights)
+}
+
+/// Merge `updates` into the stored weights of existing words and return the
+/// result. A default weight resets the entry to the plain word.
+pub fn save_weights(
+    app: &AppHandle,
+    updates: &HashMap<String, f32>,
+) -> Result<HashMap<String, f32>, String> {
+    let words = load(app)?;
+    let mut weights = load_weights(app)?;
+    for (word, &weight) in updates {
+        if !words.contains(word) {
+            continue;
+        }
+        match normalize_weight(weight) {
+            Some(weight) => weights.insert(word.clone(), weight),
+            None => weights.remove(word),
+        };
+    }
+    save_with_weights(app, &words, &weights)?;
+    Ok(weights)
+}
+
+/// Save the word list with exactly these weights. Words still present keep
+/// their suppressed terms; removing a word drops them.
+pub fn save_with_weights(
+    app: &AppHandle,
+    words: &[String],
+    weights: &HashMap<String, f32>,
+) -> Result<(), String> {
+    let suppressed = load_suppressed(app)?;
+    save_entries(app, words, weights, &suppressed)
+}
+
+fn save_entries(
+    app: &AppHandle,
+    words: &[String],
+    weights: &HashMap<String, f32>,
+    suppressed: &HashMap<String, Vec<String>>,
+) -> Result<(), String> {
     let store = app.store("dictionary.json").map_err(|e| e.to_string())?;
     store.reset();
     for word in words {
-        store.set(word, serde_json::json!([]));
+        let terms = suppressed.get(word).map(Vec::as_slice).unwrap_or_default();
+        store.set(word, entry_value(weights.get(word).copied(), terms));
     }
     Ok(())
 }
 
+fn entry_value(weight: Option<f32>, suppressed: &[String]) -> serde_json::Value {
+    let mut value = serde_json::Map::new();
+    if let Some(weight) = weight {
+        value.insert("weight".to_string(), serde_json::json!(weight));
+    }
+    if !suppressed.is_empty() {
+        value.insert("suppress".to_string(), serde_json::json!(suppressed));
+    }
+    if value.is_empty() {
+        serde_json::json!([])
+    } else {
+        serde_json::Value::Object(value)
+    }
+}
+
+fn weight_from_value(value: &serde_json::Value) -> Option<f32> {
+    let weight = value.get("weight")?.as_f64()?;
+    normalize_weight(weight as f32)
+}
+
+fn suppressed_from_value(value: &serde_json::Value) -> Vec<String> {
+    value
+        .get("suppress")
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(serde_json::Value::as_str)
+        .map(str::to_string)
+        .collect()
+}
+
+/// The suppressed terms live on the dictionary entries; only the fallback
+/// has its own store.
+pub fn load_suppression(app: &AppHandle) -> Result<SuppressionList, String> {
+    let store = app
+        .store(SUPPRESSION_STORE_FILE)
+        .map_err(|e| e.to_string())?;
+
+    let fallback = match store.get(SUPPRESSION_FALLBACK_KEY) {
+        Some(value) => serde_json::from_value::<SuppressionFallback>(value)
+            .map_err(|e| format!("Failed to parse suppression fallback: {}", e))?,
+        None => SuppressionFallback::default(),
+    };
+    Ok(SuppressionList {
+        entries: load_suppressed(app)?,
+        fallback,
+    })
+}
+
+/// Save the fallback and the suppressed terms of existing entries. Terms
+/// keyed by a word that is not in the dictionary are dropped.
+pub fn save_suppression(app: &AppHandle, suppression: &SuppressionList) -> Result<(), String> {
+    for term in suppression.entries.values().flatten() {
+        validate_entry(term).map_err(|e| e.to_string())?;
+    }
+    let store = app
+        .store(SUPPRESSION_STORE_FILE)
+        .map_err(|e| e.to_string())?;
+
+    let value = serde_json::to_value(suppression.fallback)
+        .map_err(|e| format!("Failed to serialize suppression fallback: {}", e))?;
+
+    store.set(SUPPRESSION_FALLBACK_KEY, value);
+
+    let words = load(app)?;
+    let weights = load_weights(app)?;
+    save_entries(app, &words, &weights, &suppression.entries)
+}
+
 pub fn migrate_and_load(
     app: &AppHandle,
     dictionary_from_settings: Vec<String>,
@@ -44,22 +187,49 @@ pub fn migrate_and_load(
 pub fn export_dictionary(app: &AppHandle, file_path: String) -> Result<(), String> {
     log::debug!("Exporting dictionary to file: {}", file_path);
     let words = load(app)?;
-    let content = match words.is_empty() {
-        true => String::new(),
-        false => format!("{}\n", words.join("\n")),
-    };
+    let weights = load_weights(app)?;
+    let suppressed = load_suppressed(app)?;
+    let content = export_content(&words, &weights, &suppressed);
 
     fs::write(&file_path, content).map_err(|e| e.to_string())?;
     Ok(())
 }
 
+/// One entry per line, with a `| weight` suffix on weighted entries and a
+/// `! term, term` suffix on entries with suppressed terms.
+fn export_content(
+    words: &[String],
+    weights: &HashMap<String, f32>,
+    suppressed: &HashMap<String, Vec<String>>,
+) -> String {
+    words
+        .iter()
+        .map(|word| {
+            let mut line = match weights.get(word) {
+                Some(weight) => format!("{} | {}", word, weight),
+                None => word.clone(),
+            };
+            if let Some(terms) = suppressed.get(word).filter(|terms| !terms.is_empty()) {
+                line.push_str(&format!(" ! {}", terms.join(", ")));
+            }
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+fn validate_entry(word: &str) -> Result<(), DictionaryError> {
+    let has_digit = word.chars().any(|c| c.is_ascii_digit());
+    let space_count = word.chars().filter(|c| *c == ' ').count();
+    if word.trim().is_empty() || has_digit || space_count >= MAX_PHRASE_WORDS {
+        return Err(DictionaryError::InvalidWordFormat(word.to_string()));
+    }
+    Ok(())
+}
+
 fn validate_dictionary_format(words: &[String]) -> Result<(), DictionaryError> {
     for word in words {
-        let has_digit = word.chars().any(|c| c.is_ascii_digit());
-        let space_count = word.chars().filter(|c| *c == ' ').count();
-        if has_digit || space_count > 1 {
-            return Err(DictionaryError::InvalidWordFormat(word.to_string()));
-        }
+        validate_entry(word)?;
     }
     if words.is_empty() {
         return Err(DictionaryError::EmptyDictionary);
@@ -67,6 +237,28 @@ fn validate_dictionary_format(words: &[String]) -> Result<(), DictionaryError> {
     Ok(())
 }
 
+type ImportEntry = (String, Option<f32>, Vec<String>);
+
+fn parse_import_entries(
+    raw: &str,
+    is_legacy_csv: bool,
+) -> Result<Vec<ImportEntry>, DictionaryError> {
+    let entries = normalize_import_content(raw, is_legacy_csv)
+        .into_iter()
+        .map(|entry| {
+            let (entry, suppressed) = split_entry_suppressed(&entry);
+            let (word, weight) = split_entry_weight(&entry)?;
+            Ok((word, weight, suppressed))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let words: Vec<String> = entries.iter().map(|(word, _, _)| word.clone()).collect();
+    validate_dictionary_format(&words)?;
+    for term in entries.iter().flat_map(|(_, _, suppressed)| suppressed) {
+        validate_entry(term)?;
+    }
+    Ok(entries)
+}
+
 pub fn import_dictionary(app: &AppHandle, file_path: String) -> Result<(), String> {
     let raw = fs::read_to_string(&file_path).map_err(|e| e.to_string())?;
     log::debug!("New dictionary: {} from file: {}", raw, &file_path);
@@ -75,15 +267,30 @@ pub fn import_dictionary(app: &AppHandle, file_path: String) -> Result<(), Strin
         .extension()
         .is_some_and(|e| e.eq_ignore_ascii_case("csv"));
 
-    let normalized = normalize_import_content(&raw, is_legacy_csv);
-    validate_dictionary_format(&normalized).map_err(|e| e.to_string())?;
+    let entries = parse_import_entries(&raw, is_legacy_csv).map_err(|e| e.to_string())?;
     let mut words = load(app)?;
-    for word in normalized {
+    let mut weights = load_weights(app)?;
+    let mut suppressed = load_suppressed(app)?;
+    for (word, weight, terms) in entries {
         if !contains_word_case_insensitive(&words, &word) {
-            words.push(word);
+            words.push(word.clone());
+        }
+        let Some(key) = words.iter().find(|w| w.eq_ignore_ascii_case(&word)) else {
+            continue;
+        };
+        // An explicit weight in the file overrides the stored one.
+        if let Some(weight) = weight {
+            weights.insert(key.clone(), weight);
+        }
+        // Suppressed terms add up with the stored ones.
+        let stored = suppressed.entry(key.clone()).or_default();
+        for term in terms {
+            if !contains_word_case_insensitive(stored, &term) {
+                stored.push(term);
+            }
         }
     }
-    save(app, &words)?;
+    save_entries(app, &words, &weights, &suppressed)?;
     Ok(())
 }
 
@@ -144,13 +351,24 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_dictionary_format_invalid_multiple_spaces() {
-        let result =
-            validate_dictionary_format(&normalize_import_content("hello\na b c\ntest", false));
+    fn test_validate_dictionary_format_valid_multi_word_phrase() {
+        let result = validate_dictionary_format(&normalize_import_content(
+            "hello\nleft anterior descending artery\ntest",
+            false,
+        ));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_dictionary_format_invalid_too_many_words() {
+        let result = validate_dictionary_format(&normalize_import_content(
+            "hello\na b c d e f g\ntest",
+            false,
+        ));
         assert!(result.is_err());
         match result.unwrap_err() {
             DictionaryError::InvalidWordFormat(word) => {
-                assert_eq!(word, "a b c");
+                assert_eq!(word, "a b c d e f g");
             }
             _ => panic!("Expected InvalidWordFormat error"),
         }
@@ -181,4 +399,95 @@ mod tests {
             _ => panic!("Expected EmptyDictionary error"),
         }
     }
+
+    #[test]
+    fn test_parse_import_entries_reads_weights() {
+        let entries =
+            parse_import_entries("Lamotrigine | 2\nDupont | 0.5\nParakeet", false).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("Lamotrigine".to_string(), Some(2.0), vec![]),
+                ("Dupont".to_string(), Some(0.5), vec![]),
+                ("Parakeet".to_string(), None, vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_import_entries_checks_word_after_weight() {
+        match parse_import_entries("world123 | 2", false) {
+            Err(DictionaryError::InvalidWordFormat(word)) => assert_eq!(word, "world123"),
+            _ => panic!("Expected InvalidWordFormat error"),
+        }
+    }
+
+    #[test]
+    fn test_export_content_writes_weight_suffix() {
+        let words = vec!["Lamotrigine".to_string(), "Parakeet".to_string()];
+        let weights = HashMap::from([("Lamotrigine".to_string(), 2.0)]);
+        assert_eq!(
+            export_content(&words, &weights, &HashMap::new()),
+            "Lamotrigine | 2\nParakeet\n"
+        );
+        assert_eq!(export_content(&[], &weights, &HashMap::new()), "");
+    }
+
+    #[test]
+    fn test_export_content_round_trips_through_import() {
+        let words = vec![
+            "Dupont".to_string(),
+            "left anterior descending artery".to_string(),
+        ];
+        let weights = HashMap::from([("Dupont".to_string(), 0.5)]);
+        let suppressed = HashMap::from([(
+            "Dupont".to_string(),
+            vec!["Dupond".to_string(), "Du Pont".to_string()],
+        )]);
+        let content = export_content(&words, &weights, &suppressed);
+        assert_eq!(
+            content,
+            "Dupont | 0.5 ! Dupond, Du Pont\nleft anterior descending artery\n"
+        );
+        assert_eq!(
+            parse_import_entries(&content, false).unwrap(),
+            vec![
+                (
+                    "Dupont".to_string(),
+                    Some(0.5),
+                    vec!["Dupond".to_string(), "Du Pont".to_string()]
+                ),
+                ("left anterior descending artery".to_string(), None, vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_value_keeps_legacy_format_for_default_weight() {
+        assert_eq!(entry_value(None, &[]), serde_json::json!([]));
+        assert_eq!(weight_from_value(&entry_value(None, &[])), None);
+        assert_eq!(weight_from_value(&entry_value(Some(2.0), &[])), Some(2.0));
+    }
+
+    #[test]
+    fn test_entry_value_stores_suppressed_terms() {
+        let terms = vec!["Dupond".to_string()];
+        let value = entry_value(None, &terms);
+        assert_eq!(value, serde_json::json!({ "suppress": ["Dupond"] }));
+        assert_eq!(weight_from_value(&value), None);
+        assert_eq!(suppressed_from_value(&value), terms);
+        assert_eq!(
+            suppressed_from_value(&entry_value(Some(2.0), &terms)),
+            terms
+        );
+        assert!(suppressed_from_value(&serde_json::json!([])).is_empty());
+    }
+
+    #[test]
+    fn test_parse_import_entries_checks_suppressed_terms() {
+        match parse_import_entries("Dupont ! Dupond2", false) {
+            Err(DictionaryError::InvalidWordFormat(word)) => assert_eq!(word, "Dupond2"),
+            _ => panic!("Expected InvalidWordFormat error"),
+        }
+    }
 }
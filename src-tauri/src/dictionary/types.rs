@@ -1,25 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
-pub struct Dictionary(pub Arc<Mutex<Vec<String>>>);
+/// Boost strength of an entry without an explicit weight.
+pub const DEFAULT_WORD_WEIGHT: f32 = 1.0;
+/// Bounds of a per-entry weight. The weight multiplies the phrase-boosting
+/// score of the entry, on top of the dictionary-size alpha.
+pub const MIN_WORD_WEIGHT: f32 = 0.25;
+pub const MAX_WORD_WEIGHT: f32 = 3.0;
+/// Longest entry accepted, in words ("left anterior descending artery").
+pub const MAX_PHRASE_WORDS: usize = 6;
+/// Boost tree weight of the suppressed words: a negative weight turns the
+/// phrase boost into a penalty, owed once the decoder closes the word.
+pub const SUPPRESSION_WEIGHT: f32 = -1.0;
+
+/// What the post-correction does with a suppressed word the decoder still
+/// emitted despite the penalty.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionFallback {
+    /// Keep the first letter and replace the others with `*`.
+    #[default]
+    Mask,
+    Remove,
+    /// Decoder penalty only, the text is left as is.
+    Off,
+}
+
+/// Words the decoder must never emit. Each dictionary entry carries its own
+/// suppressed terms, the words the model keeps writing in its place, so they
+/// are stored, exported and removed with the entry.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct SuppressionList {
+    pub entries: HashMap<String, Vec<String>>,
+    pub fallback: SuppressionFallback,
+}
+
+impl SuppressionList {
+    /// Suppressed terms of every entry, without duplicates.
+    pub fn words(&self) -> Vec<String> {
+        let mut words: Vec<String> = Vec::new();
+        for term in self.entries.values().flatten() {
+            if !words.iter().any(|w| w.eq_ignore_ascii_case(term)) {
+                words.push(term.clone());
+            }
+        }
+        words
+    }
+}
+
+/// The dictionary words, the weights of the entries that do not use
+/// `DEFAULT_WORD_WEIGHT`, and the suppression list.
+pub struct Dictionary {
+    words: Arc<Mutex<Vec<String>>>,
+    weights: Arc<Mutex<HashMap<String, f32>>>,
+    suppression: Arc<Mutex<SuppressionList>>,
+}
 
 impl Dictionary {
-    pub fn new(words: Vec<String>) -> Self {
-        Self(Arc::new(Mutex::new(words)))
+    pub fn new(
+        words: Vec<String>,
+        weights: HashMap<String, f32>,
+        suppression: SuppressionList,
+    ) -> Self {
+        Self {
+            words: Arc::new(Mutex::new(words)),
+            weights: Arc::new(Mutex::new(weights)),
+            suppression: Arc::new(Mutex::new(suppression)),
+        }
     }
     pub fn get(&self) -> Vec<String> {
-        self.0.lock().unwrap().clone()
+        self.words.lock().unwrap().clone()
     }
     pub fn set(&self, words: Vec<String>) {
-        *self.0.lock().unwrap() = words;
+        *self.words.lock().unwrap() = words;
+    }
+    pub fn get_weights(&self) -> HashMap<String, f32> {
+        self.weights.lock().unwrap().clone()
+    }
+    pub fn set_weights(&self, weights: HashMap<String, f32>) {
+        *self.weights.lock().unwrap() = weights;
+    }
+    pub fn get_suppression(&self) -> SuppressionList {
+        self.suppression.lock().unwrap().clone()
+    }
+    pub fn set_suppression(&self, suppression: SuppressionList) {
+        *self.suppression.lock().unwrap() = suppression;
     }
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum DictionaryError {
     #[error(
-        "Invalid word format: {0}. Words must not contain digits and may contain at most one space"
+        "Invalid word format: {0}. Words must not contain digits and entries may contain at most 6 words"
     )]
     InvalidWordFormat(String),
+    #[error("Invalid weight: {0}. Weights must be numbers between 0.25 and 3")]
+    InvalidWeight(String),
     #[error("Dictionary import must contain at least one valid word")]
     EmptyDictionary,
 }
@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use super::transcription_engine::{TranscriptionEngine, TranscriptionResult};
+use super::types::{
+    AsrBackend, AsrEngine, AsrInferenceParams, ParakeetEngine, ParakeetInferenceParams,
+    ParakeetModelParams, SessionOptions, WhisperEngine, WhisperInferenceParams, WhisperModelParams,
+};
+
+impl AsrEngine {
+    /// Load the model at `model_path` with the given backend. The tokenizer
+    /// only serves Parakeet phrase boosting; the language only Whisper.
+    pub fn load(
+        backend: AsrBackend,
+        model_path: &Path,
+        options: SessionOptions,
+        tokenizer_path: Option<PathBuf>,
+        language: Option<String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        match backend {
+            AsrBackend::Parakeet => {
+                let mut engine = ParakeetEngine::new();
+                engine.load_model_with_params(
+                    model_path,
+                    ParakeetModelParams {
+                        quantization: options.quantization,
+                        threads: options.threads,
+                        tokenizer_path,
+                    },
+                )?;
+                Ok(Self::Parakeet(engine))
+            }
+            AsrBackend::Whisper => {
+                let mut engine = WhisperEngine::new();
+                engine.load_model_with_params(
+                    model_path,
+                    WhisperModelParams {
+                        quantization: options.quantization,
+                        threads: options.threads,
+                    },
+                )?;
+                Ok(Self::Whisper { engine, language })
+            }
+        }
+    }
+
+    /// Phrase boosting is Parakeet only: Whisper relies on the dictionary
+    /// post-correction.
+    pub fn set_boost_words(&mut self, words: &[(String, f32)]) {
+        if let Self::Parakeet(engine) = self {
+            engine.set_boost_words(words);
+        }
+    }
+
+    pub fn set_language(&mut self, language: Option<String>) {
+        if let Self::Whisper {
+            language: current, ..
+        } = self
+        {
+            *current = language;
+        }
+    }
+
+    pub fn transcribe_samples(
+        &mut self,
+        samples: Vec<f32>,
+        params: Option<AsrInferenceParams>,
+    ) -> Result<TranscriptionResult, Box<dyn std::error::Error>> {
+        match self {
+            Self::Parakeet(engine) => engine.transcribe_samples(
+                samples,
+                params.map(|params| ParakeetInferenceParams {
+                    beam_width: params.beam_width,
+                    ..Default::default()
+                }),
+            ),
+            Self::Whisper { engine, language } => engine.transcribe_samples(
+                samples,
+                Some(WhisperInferenceParams {
+                    language: language.clone(),
+                }),
+            ),
+        }
+    }
+}
@@ -17,6 +17,9 @@ struct Node {
     fail: NodeId,
     backoff_w: f32,
     is_end: bool,
+    // Penalty of a suppressed word ending here, owed only once the word is
+    // closed (see `closing_penalty`). 0 when a boosted phrase ends here too.
+    closing_penalty: f32,
 }
 
 impl Node {
@@ -29,6 +32,7 @@ impl Node {
             fail: ROOT,
             backoff_w: 0.0,
             is_end: false,
+            closing_penalty: 0.0,
         }
     }
 }
@@ -52,54 +56,80 @@ pub struct BoostTree {
 }
 
 impl BoostTree {
+    #[cfg(test)]
     pub fn new(phrases: &[Vec<i32>]) -> Self {
+        let weighted: Vec<(Vec<i32>, f32)> = phrases.iter().map(|p| (p.clone(), 1.0)).collect();
+        Self::with_weights(&weighted)
+    }
+
+    /// Build the automaton from token phrases, each with a weight multiplying
+    /// its token scores (1.0 for a plain dictionary entry, negative for a
+    /// suppressed word). A suppressed word scores 0 on all its tokens: its
+    /// penalty is owed by the token after it, and only if that token starts
+    /// a new word (`closing_penalty`), so longer words sharing its subwords
+    /// decode as usual. A prefix shared by phrases of different weights keeps
+    /// the highest score, so a boosted phrase is never penalised by a
+    /// suppressed one.
+    pub fn with_weights(phrases: &[(Vec<i32>, f32)]) -> Self {
         let mut tree = Self {
             nodes: vec![Node::root()],
         };
-        for phrase in phrases {
-            tree.add_phrase(phrase);
+        for (phrase, weight) in phrases {
+            tree.add_phrase(phrase, *weight);
         }
         tree.build_fail_links();
         tree
     }
 
-    fn add_phrase(&mut self, phrase: &[i32]) {
+    fn add_phrase(&mut self, phrase: &[i32], weight: f32) {
         let last = phrase.len().saturating_sub(1);
         let mut cur = ROOT;
         for (i, &token) in phrase.iter().enumerate() {
-            let depth_token_score = if i == 0 {
-                CONTEXT_SCORE
-            } else {
-                CONTEXT_SCORE * DEPTH_SCALING + (i as f32 + 1.0).ln()
-            };
+            let depth_token_score = weight
+                * if i == 0 {
+                    CONTEXT_SCORE
+                } else {
+                    CONTEXT_SCORE * DEPTH_SCALING + (i as f32 + 1.0).ln()
+                };
+            let token_score = if weight < 0.0 { 0.0 } else { depth_token_score };
 
-            match self.nodes[cur].children.get(&token).copied() {
+            let child = match self.nodes[cur].children.get(&token).copied() {
                 Some(child) => {
-                    let token_score = self.nodes[child].token_score.max(depth_token_score);
+                    let token_score = self.nodes[child].token_score.max(token_score);
                     let parent_node_score = self.nodes[cur].node_score;
                     self.nodes[child].token_score = token_score;
                     self.nodes[child].node_score = parent_node_score + token_score;
-                    if i == last {
-                        self.nodes[child].is_end = true;
-                    }
-                    cur = child;
+                    child
                 }
                 None => {
                     let parent_node_score = self.nodes[cur].node_score;
                     let child = self.nodes.len();
                     self.nodes.push(Node {
-                        token_score: depth_token_score,
-                        node_score: parent_node_score + depth_token_score,
+                        token_score,
+                        node_score: parent_node_score + token_score,
                         depth: i + 1,
                         children: HashMap::new(),
                         fail: ROOT,
                         backoff_w: 0.0,
-                        is_end: i == last,
+                        is_end: false,
+                        closing_penalty: 0.0,
                     });
                     self.nodes[cur].children.insert(token, child);
-                    cur = child;
+                    child
                 }
+            };
+            if i == last {
+                let node = &mut self.nodes[child];
+                // An end without a penalty is a boosted phrase, which wins.
+                let boosted_end = node.is_end && node.closing_penalty == 0.0;
+                node.closing_penalty = if weight < 0.0 && !boosted_end {
+                    node.closing_penalty.min(depth_token_score)
+                } else {
+                    0.0
+                };
+                node.is_end = true;
             }
+            cur = child;
         }
     }
 
@@ -180,6 +210,28 @@ impl BoostTree {
         }
     }
 
+    /// Sum of `backoff_w` along the fail chain from `state` to the root: the
+    /// score returned when a hypothesis leaves `state` with a token that no
+    /// phrase expects. Used by the beam search, where scores accumulate.
+    pub fn backoff(&self, state: NodeId) -> f32 {
+        let mut acc = 0.0;
+        let mut cur = state;
+        while cur != ROOT {
+            acc += self.nodes[cur].backoff_w;
+            cur = self.nodes[cur].fail;
+        }
+        acc
+    }
+
+    /// Penalty owed when the word ending at `state` is closed: by the next
+    /// token if it starts a new word, or at the end of the audio. A token
+    /// continuing the word leaves `state`, so "▁ass" suppressed does not
+    /// penalize "▁ass" + "istant". Negative, or 0 when no suppressed word
+    /// ends at `state`.
+    pub fn closing_penalty(&self, state: NodeId) -> f32 {
+        self.nodes[state].closing_penalty
+    }
+
     pub fn root(&self) -> NodeId {
         ROOT
     }
@@ -233,6 +285,83 @@ mod tests {
         assert!(approx(tree.nodes[second].backoff_w, 0.0));
     }
 
+    #[test]
+    fn backoff_cancels_the_abandoned_partial_match() {
+        let tree = BoostTree::new(&[vec![10, 20, 30]]);
+        let second = tree.advance(tree.advance(ROOT, 10), 20);
+        assert!(approx(tree.backoff(second), -tree.nodes[second].node_score));
+        let end = tree.advance(second, 30);
+        assert!(approx(tree.backoff(end), 0.0));
+        assert!(approx(tree.backoff(ROOT), 0.0));
+    }
+
+    #[test]
+    fn weight_scales_phrase_scores() {
+        let tree = BoostTree::with_weights(&[(vec![10, 20], 2.0), (vec![50], 0.5)]);
+        let first = tree.nodes[ROOT].children[&10];
+        let second = tree.nodes[first].children[&20];
+        let expected = 2.0 * (CONTEXT_SCORE * DEPTH_SCALING + 2.0_f32.ln());
+        assert!(approx(tree.nodes[first].token_score, 2.0 * CONTEXT_SCORE));
+        assert!(approx(tree.nodes[second].token_score, expected));
+        let weak = tree.nodes[ROOT].children[&50];
+        assert!(approx(tree.nodes[weak].token_score, 0.5 * CONTEXT_SCORE));
+    }
+
+    #[test]
+    fn shared_prefix_keeps_strongest_weight() {
+        let tree = BoostTree::with_weights(&[(vec![10, 20], 0.5), (vec![10, 30], 2.0)]);
+        let first = tree.nodes[ROOT].children[&10];
+        assert!(approx(tree.nodes[first].token_score, 2.0 * CONTEXT_SCORE));
+    }
+
+    #[test]
+    fn suppression_penalty_waits_for_the_word_to_close() {
+        let tree = BoostTree::with_weights(&[(vec![10, 20], -1.0)]);
+        let start = tree.bias(ROOT);
+        let first = start.iter().find(|c| c.token == 10).unwrap();
+        assert!(approx(first.score, 0.0));
+
+        // A word sharing the first subword, then diverging, is not penalized.
+        let state = tree.advance(ROOT, 10);
+        assert!(approx(tree.backoff(state), 0.0));
+        assert!(approx(tree.closing_penalty(state), 0.0));
+        assert_eq!(tree.advance(state, 30), ROOT);
+
+        // The completing token scores 0; the penalty is owed once it closes.
+        let end = tree.bias(state);
+        let completing = end.iter().find(|c| c.token == 20).unwrap();
+        assert!(approx(completing.score, 0.0));
+        let closed = tree.advance(state, 20);
+        let expected = -(CONTEXT_SCORE * DEPTH_SCALING + 2.0_f32.ln());
+        assert!(approx(tree.closing_penalty(closed), expected));
+    }
+
+    #[test]
+    fn suppressed_word_does_not_penalize_a_longer_word() {
+        // "▁ass" (10) suppressed, "assistant" decodes as "▁ass" + "istant" (11).
+        let tree = BoostTree::with_weights(&[(vec![10], -1.0)]);
+        let ass = tree.advance(ROOT, 10);
+        assert!(approx(tree.closing_penalty(ass), -CONTEXT_SCORE));
+        assert!(tree.bias(ROOT).iter().all(|c| approx(c.score, 0.0)));
+
+        let assistant = tree.advance(ass, 11);
+        assert_eq!(assistant, ROOT);
+        assert!(approx(tree.closing_penalty(assistant), 0.0));
+    }
+
+    #[test]
+    fn boosted_phrase_ending_on_a_suppressed_word_is_not_penalized() {
+        for phrases in [
+            [(vec![10], 1.0), (vec![10], -1.0)],
+            [(vec![10], -1.0), (vec![10], 1.0)],
+        ] {
+            let tree = BoostTree::with_weights(&phrases);
+            let state = tree.advance(ROOT, 10);
+            assert!(approx(tree.closing_penalty(state), 0.0));
+            assert!(approx(tree.nodes[state].token_score, CONTEXT_SCORE));
+        }
+    }
+
     #[test]
     fn bias_continues_phrase() {
         let tree = BoostTree::new(&[vec![10, 20]]);
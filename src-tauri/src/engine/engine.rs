@@ -7,21 +7,27 @@ use ort::session::Session;
 use ort::value::TensorRef;
 use regex::Regex;
 
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::Path;
 
-use super::boost_tree::{BiasCandidate, BoostTree};
-use super::helpers::{load_tokenizer, tokenize_word_to_ids, word_variants};
-use super::types::{DecoderState, ParakeetError, ParakeetModel, TimestampedResult};
+use super::boost_tree::{BiasCandidate, BoostTree, NodeId};
+use super::helpers::{load_tokenizer, softmax_prob, tokenize_word_to_ids, word_variants};
+use super::types::{DecoderState, ParakeetError, ParakeetModel, SessionThreads, TimestampedResult};
 
-/// Tokens, frame timestamps and raw-logit probabilities of one decoded
-/// sequence, kept index-aligned by the decode loops.
-type DecodedSequence = (Vec<i32>, Vec<usize>, Vec<f32>);
+/// Tokens, frame timestamps, raw-logit probabilities and runner-up tokens of
+/// one decoded sequence, kept index-aligned by the decode loops.
+type DecodedSequence = (Vec<i32>, Vec<usize>, Vec<f32>, Vec<Vec<(i32, f32)>>);
 
 const SUBSAMPLING_FACTOR: usize = 8;
 const WINDOW_SIZE: f32 = 0.01;
 const MAX_TOKENS_PER_STEP: usize = 10;
 
+// Runner-up tokens kept per emitted token for the word alternatives. Below
+// MIN_ALTERNATIVE_PROB a runner-up is noise, not a second guess.
+const TOKEN_ALTERNATIVES: usize = 3;
+const MIN_ALTERNATIVE_PROB: f32 = 0.05;
+
 // Fusion weight bounds applied to boost scores before argmax. Alpha decays
 // with dictionary size: more words means more first-tokens armed at the root,
 // so we lower the volume to keep false positives down on large dictionaries.
@@ -43,6 +49,42 @@ const BOOST_DEEP_DEPTH: usize = 3;
 const GUARD_MIN_TOKENS: usize = 24;
 const GUARD_DIVERGENCE: f32 = 0.35;
 
+/// Largest beam width accepted from the settings and the CLI. Each hypothesis
+/// costs one decoder run per step, so wider beams slow the decode down.
+pub const MAX_BEAM_WIDTH: usize = 8;
+
+// Durations expanded per token in the beam search. The duration head is
+// usually confident, the second best only matters around word boundaries.
+const BEAM_DURATIONS: usize = 2;
+
+/// One partial decode of the beam search. `score` is the sum of the token and
+/// duration log-probabilities plus the boost fusion scores.
+#[derive(Clone)]
+struct BeamHypothesis {
+    tokens: Vec<i32>,
+    timestamps: Vec<usize>,
+    probs: Vec<f32>,
+    alternatives: Vec<Vec<(i32, f32)>>,
+    state: DecoderState,
+    boost_state: NodeId,
+    t: usize,
+    emitted: usize,
+    score: f32,
+}
+
+// Keep the `width` best hypotheses, merging the ones that emitted the same
+// tokens (the best path wins).
+fn prune_beam(beam: &mut Vec<BeamHypothesis>, width: usize) {
+    beam.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut seen = HashSet::new();
+    beam.retain(|h| seen.insert((h.tokens.clone(), h.emitted)));
+    beam.truncate(width);
+}
+
 /// Normalized token-level edit distance between two decoded sequences, in
 /// `[0, 1]`. 0 means identical, 1 means fully different.
 fn token_divergence(a: &[i32], b: &[i32]) -> f32 {
@@ -69,6 +111,24 @@ fn degressive_alpha(word_count: usize) -> f32 {
     (BOOST_ALPHA_MAX - (word_count as f32 / 5.0).log10()).clamp(BOOST_ALPHA_MIN, BOOST_ALPHA_MAX)
 }
 
+// Vocab tokens keep their word-start marker as a leading space.
+fn starts_word(vocab: &[String], token: i32) -> bool {
+    vocab
+        .get(token as usize)
+        .is_some_and(|piece| piece.starts_with(' '))
+}
+
+// A suppressed word is penalized once it is closed: `penalty` (negative)
+// lands on every token starting a new word, never on blank or on a token
+// continuing the word ("▁ass" then "istant" is "assistant").
+fn penalize_word_starts(logits: &mut [f32], vocab: &[String], penalty: f32, blank_idx: i32) {
+    for (idx, logit) in logits.iter_mut().enumerate() {
+        if idx as i32 != blank_idx && starts_word(vocab, idx as i32) {
+            *logit += penalty;
+        }
+    }
+}
+
 fn top_k_for_depth(depth: usize) -> usize {
     if depth >= BOOST_DEEP_DEPTH {
         BOOST_TOP_K_DEEP
@@ -100,18 +160,24 @@ fn top_k_threshold(logits: &[f32], k: usize) -> f32 {
 static DECODE_SPACE_RE: Lazy<Result<Regex, regex::Error>> =
     Lazy::new(|| Regex::new(r"\A\s|\s\B|(\s)\b"));
 
-fn softmax_prob(logits: &[f32], token: usize) -> f32 {
-    let target = match logits.get(token) {
-        Some(&l) => l,
-        None => return 0.0,
-    };
+/// Most probable tokens other than `emitted` and blank, with their softmax
+/// probability over the raw logits, best first.
+fn token_alternatives(logits: &[f32], emitted: i32, blank_idx: i32) -> Vec<(i32, f32)> {
     let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
     let sum: f32 = logits.iter().map(|&l| (l - max).exp()).sum();
-    if sum > 0.0 {
-        ((target - max).exp() / sum).clamp(0.0, 1.0)
-    } else {
-        0.0
+    if sum <= 0.0 {
+        return Vec::new();
     }
+    let mut alternatives: Vec<(i32, f32)> = logits
+        .iter()
+        .enumerate()
+        .filter(|&(idx, _)| idx as i32 != emitted && idx as i32 != blank_idx)
+        .map(|(idx, &l)| (idx as i32, (l - max).exp() / sum))
+        .filter(|&(_, prob)| prob >= MIN_ALTERNATIVE_PROB)
+        .collect();
+    alternatives.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    alternatives.truncate(TOKEN_ALTERNATIVES);
+    alternatives
 }
 
 fn argmax_token(logits: &[f32], blank_idx: i32) -> i32 {
@@ -123,6 +189,24 @@ fn argmax_token(logits: &[f32], blank_idx: i32) -> i32 {
         .unwrap_or(blank_idx)
 }
 
+fn log_softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&l| (l - max).exp()).sum::<f32>().ln() + max;
+    logits.iter().map(|&l| l - log_sum).collect()
+}
+
+/// Indices of the `k` largest values, best first.
+fn top_k_indices(values: &[f32], k: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    indices.sort_by(|&a, &b| {
+        values[b]
+            .partial_cmp(&values[a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    indices.truncate(k);
+    indices
+}
+
 /// Index of the maximum value (first on ties). Returns 0 on an empty slice.
 fn argmax_index(logits: &[f32]) -> usize {
     let mut best = 0;
@@ -149,11 +233,13 @@ impl ParakeetModel {
     pub fn new<P: AsRef<Path>>(
         model_dir: P,
         quantized: bool,
+        threads: SessionThreads,
         tokenizer_path: Option<&Path>,
     ) -> Result<Self, ParakeetError> {
-        let encoder = Self::init_session(&model_dir, "encoder-model", None, quantized)?;
-        let decoder_joint = Self::init_session(&model_dir, "decoder_joint-model", None, quantized)?;
-        let preprocessor = Self::init_session(&model_dir, "nemo128", None, false)?;
+        let encoder = Self::init_session(&model_dir, "encoder-model", threads, quantized)?;
+        let decoder_joint =
+            Self::init_session(&model_dir, "decoder_joint-model", threads, quantized)?;
+        let preprocessor = Self::init_session(&model_dir, "nemo128", threads, false)?;
 
         let (vocab, blank_idx) = Self::load_vocab(&model_dir)?;
         let vocab_size = vocab.len();
@@ -180,15 +266,18 @@ impl ParakeetModel {
         })
     }
 
-    /// Rebuild the phrase-boosting automaton from the user dictionary words.
-    /// Each word is expanded into casing/accent variants (see `word_variants`),
-    /// every tokenizable variant becoming an independent boosted phrase.
+    /// Rebuild the phrase-boosting automaton from the user dictionary entries,
+    /// each a word or multi-word phrase with its weight. Each entry is expanded
+    /// into casing/accent variants (see `word_variants`), every tokenizable
+    /// variant becoming an independent boosted phrase with the entry's weight.
+    /// A negative weight (suppression list) penalises the phrase instead, once
+    /// the decoder closes it (see `BoostTree::closing_penalty`).
     /// Variants that cannot be tokenized are skipped; an empty result clears it.
-    /// No-op when the word set is unchanged (sync runs before every
+    /// No-op when the entries are unchanged (sync runs before every
     /// transcription, including each streaming chunk).
-    pub fn set_boost_words(&mut self, words: &[String]) {
+    pub fn set_boost_words(&mut self, words: &[(String, f32)]) {
         let mut sorted = words.to_vec();
-        sorted.sort();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
         if sorted == self.boost_words {
             return;
         }
@@ -201,8 +290,8 @@ impl ParakeetModel {
                 return;
             }
         };
-        let mut phrases: Vec<Vec<i32>> = Vec::new();
-        for word in words {
+        let mut phrases: Vec<(Vec<i32>, f32)> = Vec::new();
+        for (word, weight) in words {
             let variants = word_variants(word);
             let mut tokenized: Vec<(String, Vec<i32>)> = Vec::new();
             for variant in variants {
@@ -220,24 +309,31 @@ impl ParakeetModel {
                         )
                     })
                     .collect();
-                log::debug!("Boost word {:?} -> variants {:?}", word, detail);
+                log::debug!(
+                    "Boost word {:?} (weight {}) -> variants {:?}",
+                    word,
+                    weight,
+                    detail
+                );
             }
-            phrases.extend(tokenized.into_iter().map(|(_, ids)| ids));
+            phrases.extend(tokenized.into_iter().map(|(_, ids)| (ids, *weight)));
         }
 
         // Alpha is driven by the dictionary size, not the variant count: more
-        // variants per word must not lower the boost volume.
+        // variants per word must not lower the boost volume. Suppressed words
+        // do not count either.
         self.boost_tree = if phrases.is_empty() {
             None
         } else {
-            self.boost_alpha = degressive_alpha(words.len());
+            let boosted_words = words.iter().filter(|(_, weight)| *weight > 0.0).count();
+            self.boost_alpha = degressive_alpha(boosted_words);
             log::debug!(
                 "Phrase boosting active for {} word(s), {} phrase(s), alpha={}",
                 words.len(),
                 phrases.len(),
                 self.boost_alpha
             );
-            Some(BoostTree::new(&phrases))
+            Some(BoostTree::with_weights(&phrases))
         };
     }
 
@@ -286,30 +382,28 @@ impl ParakeetModel {
     fn init_session<P: AsRef<Path>>(
         model_dir: P,
         model_name: &str,
-        intra_threads: Option<usize>,
+        threads: SessionThreads,
         try_quantized: bool,
     ) -> Result<Session, ParakeetError> {
         let providers = vec![CPUExecutionProvider::default().build()];
 
-        // Try quantized version first if requested, fallback to regular version
-        let model_filename = if try_quantized {
-            let quantized_name = format!("{}.int8.onnx", model_name);
-            let quantized_path = model_dir.as_ref().join(&quantized_name);
-            if quantized_path.exists() {
-                log::trace!("Loading quantized model from {}...", quantized_name);
-                quantized_name
-            } else {
-                let regular_name = format!("{}.onnx", model_name);
-                log::trace!(
-                    "Quantized model not found, loading regular model from {}...",
-                    regular_name
-                );
-                regular_name
-            }
+        // Try the requested precision first, fallback to the other one so a
+        // model shipped in a single precision still loads
+        let quantized_name = format!("{}.int8.onnx", model_name);
+        let regular_name = format!("{}.onnx", model_name);
+        let (preferred, other) = if try_quantized {
+            (quantized_name, regular_name)
         } else {
-            let regular_name = format!("{}.onnx", model_name);
-            log::trace!("Loading model from {}...", regular_name);
-            regular_name
+            (regular_name, quantized_name)
+        };
+        let model_filename = if model_dir.as_ref().join(&preferred).exists()
+            || !model_dir.as_ref().join(&other).exists()
+        {
+            log::trace!("Loading model from {}...", preferred);
+            preferred
+        } else {
+            log::info!("{} not found, loading {} instead", preferred, other);
+            other
         };
 
         let mut builder = Session::builder()?
@@ -317,12 +411,13 @@ impl ParakeetModel {
             .with_optimization_level(GraphOptimizationLevel::Level3)?
             .with_execution_providers(providers)?
             .with_memory_pattern(false)?
-            .with_parallel_execution(false)?;
+            .with_parallel_execution(threads.inter_op.is_some_and(|n| n > 1))?;
 
-        if let Some(threads) = intra_threads {
-            builder = builder
-                .with_intra_threads(threads)?
-                .with_inter_threads(threads)?;
+        if let Some(n) = threads.intra_op {
+            builder = builder.with_intra_threads(n)?;
+        }
+        if let Some(n) = threads.inter_op {
+            builder = builder.with_inter_threads(n)?;
         }
 
         let session = builder.commit_from_file(model_dir.as_ref().join(&model_filename))?;
@@ -525,6 +620,7 @@ impl ParakeetModel {
         &mut self,
         waveforms: &ArrayViewD<f32>,
         waveforms_len: &ArrayViewD<i64>,
+        beam_width: usize,
     ) -> Result<Vec<TimestampedResult>, ParakeetError> {
         // Preprocess and encode
         let (features, features_lens) = self.preprocess(waveforms, waveforms_len)?;
@@ -534,9 +630,9 @@ impl ParakeetModel {
         // Decode for each batch item
         let mut results = Vec::new();
         for (encodings, &encodings_len) in encoder_out.outer_iter().zip(encoder_out_lens.iter()) {
-            let (tokens, timestamps, probs) =
-                self.decode_sequence(&encodings.view(), encodings_len as usize)?;
-            let result = self.decode_tokens(tokens, timestamps, probs);
+            let (tokens, timestamps, probs, alternatives) =
+                self.decode_sequence(&encodings.view(), encodings_len as usize, beam_width)?;
+            let result = self.decode_tokens(tokens, timestamps, probs, alternatives);
             results.push(result);
         }
 
@@ -547,7 +643,14 @@ impl ParakeetModel {
         &mut self,
         encodings: &ArrayViewD<f32>, // [time_steps, 1024]
         encodings_len: usize,
+        beam_width: usize,
     ) -> Result<DecodedSequence, ParakeetError> {
+        // The beam search is opt-in and carries the boost state per
+        // hypothesis, so a second-best dictionary path can still win: it
+        // needs no divergence guard.
+        if beam_width > 1 {
+            return self.decode_sequence_beam(encodings, encodings_len, beam_width);
+        }
         // Logit boosting only runs when a boost tree is active. With no boost
         // tree the greedy path must stay bit-exact (default path, streaming),
         // so it lives in its own function with no boost code on it.
@@ -584,6 +687,7 @@ impl ParakeetModel {
         let mut tokens = Vec::new();
         let mut timestamps = Vec::new();
         let mut token_probs = Vec::new();
+        let mut alternatives = Vec::new();
 
         let mut t = 0;
         let mut emitted_tokens = 0;
@@ -599,6 +703,7 @@ impl ParakeetModel {
 
             if token != self.blank_idx {
                 token_probs.push(softmax_prob(vocab_logits, token as usize));
+                alternatives.push(token_alternatives(vocab_logits, token, self.blank_idx));
                 prev_state = new_state;
                 tokens.push(token);
                 timestamps.push(t);
@@ -615,7 +720,7 @@ impl ParakeetModel {
             }
         }
 
-        Ok((tokens, timestamps, token_probs))
+        Ok((tokens, timestamps, token_probs, alternatives))
     }
 
     // Split a (possibly TDT) decoder output into (vocab_logits, duration_logits);
@@ -663,6 +768,7 @@ impl ParakeetModel {
         let mut tokens: Vec<i32> = Vec::new();
         let mut timestamps: Vec<usize> = Vec::new();
         let mut token_probs: Vec<f32> = Vec::new();
+        let mut alternatives: Vec<Vec<(i32, f32)>> = Vec::new();
 
         let mut t = 0;
         let mut emitted_tokens = 0;
@@ -696,6 +802,17 @@ impl ParakeetModel {
                     }
                 }
             }
+            // Greedy decoding cannot take back a suppressed word still open
+            // at the end of the audio: the post-correction fallback covers it.
+            let penalty = tree.closing_penalty(boost_state);
+            if penalty < 0.0 {
+                penalize_word_starts(
+                    &mut boosted,
+                    &self.vocab,
+                    self.boost_alpha * penalty,
+                    self.blank_idx,
+                );
+            }
             let token = argmax_token(&boosted, self.blank_idx);
             if log::log_enabled!(log::Level::Trace)
                 && token != self.blank_idx
@@ -708,6 +825,7 @@ impl ParakeetModel {
                 // Confidence from the raw logits, not the boosted ones, so
                 // boosted tokens stay correctable downstream.
                 token_probs.push(softmax_prob(vocab_logits, token as usize));
+                alternatives.push(token_alternatives(vocab_logits, token, self.blank_idx));
                 prev_state = new_state;
                 tokens.push(token);
                 timestamps.push(t);
@@ -725,7 +843,218 @@ impl ParakeetModel {
             }
         }
 
-        Ok((tokens, timestamps, token_probs))
+        Ok((tokens, timestamps, token_probs, alternatives))
+    }
+
+    // TDT beam search. Hypotheses are expanded frame by frame (lowest frame
+    // first) so the ones compared when pruning have consumed the same audio.
+    // Each expansion runs one decode_step and branches on the best tokens and
+    // durations; the boost tree state travels with each hypothesis.
+    fn decode_sequence_beam(
+        &mut self,
+        encodings: &ArrayViewD<f32>,
+        encodings_len: usize,
+        beam_width: usize,
+    ) -> Result<DecodedSequence, ParakeetError> {
+        let tree = self.boost_tree.take();
+        let result = self.run_beam_decode(encodings, encodings_len, beam_width, tree.as_ref());
+        self.boost_tree = tree;
+        result
+    }
+
+    fn run_beam_decode(
+        &mut self,
+        encodings: &ArrayViewD<f32>,
+        encodings_len: usize,
+        beam_width: usize,
+        tree: Option<&BoostTree>,
+    ) -> Result<DecodedSequence, ParakeetError> {
+        let mut frames: BTreeMap<usize, Vec<BeamHypothesis>> = BTreeMap::new();
+        frames.insert(
+            0,
+            vec![BeamHypothesis {
+                tokens: Vec::new(),
+                timestamps: Vec::new(),
+                probs: Vec::new(),
+                alternatives: Vec::new(),
+                state: self.create_decoder_state()?,
+                boost_state: tree.map_or(0, BoostTree::root),
+                t: 0,
+                emitted: 0,
+                score: 0.0,
+            }],
+        );
+        let mut finished = Vec::new();
+
+        while let Some((t, mut beam)) = frames.pop_first() {
+            if t >= encodings_len {
+                finished.extend(beam);
+                continue;
+            }
+            let encoder_step = encodings.slice(ndarray::s![t, ..]);
+            let encoder_step_dyn = encoder_step.to_owned().into_dyn();
+
+            // Tokens emitted with duration 0 stay on this frame, so keep
+            // expanding until every hypothesis has moved past it.
+            while !beam.is_empty() {
+                prune_beam(&mut beam, beam_width);
+                let mut same_frame = Vec::new();
+                for hyp in &beam {
+                    for child in
+                        self.expand_hypothesis(hyp, &encoder_step_dyn.view(), beam_width, tree)?
+                    {
+                        if child.t == t {
+                            same_frame.push(child);
+                        } else {
+                            frames.entry(child.t).or_default().push(child);
+                        }
+                    }
+                }
+                beam = same_frame;
+            }
+        }
+
+        // A phrase still open at the end of the audio was never completed:
+        // take its partial boost back, as if the next token had left it. A
+        // suppressed word ending the audio is closed, so it pays its penalty.
+        if let Some(tree) = tree {
+            for hyp in &mut finished {
+                hyp.score += self.boost_alpha
+                    * (tree.backoff(hyp.boost_state) + tree.closing_penalty(hyp.boost_state));
+            }
+        }
+        Ok(finished
+            .into_iter()
+            .max_by(|a, b| {
+                a.score
+                    .partial_cmp(&b.score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|best| (best.tokens, best.timestamps, best.probs, best.alternatives))
+            .unwrap_or_default())
+    }
+
+    fn expand_hypothesis(
+        &mut self,
+        hyp: &BeamHypothesis,
+        encoder_step: &ArrayViewD<f32>,
+        beam_width: usize,
+        tree: Option<&BoostTree>,
+    ) -> Result<Vec<BeamHypothesis>, ParakeetError> {
+        let (probs, new_state) = self.decode_step(&hyp.tokens, &hyp.state, encoder_step)?;
+        let (vocab_logits, dur_logits) = Self::tdt_logits(&probs, self.vocab_size)?;
+        let token_lp = log_softmax(vocab_logits);
+        let dur_lp = log_softmax(dur_logits);
+        // A plain RNN-T joint has no duration head: blank moves one frame,
+        // tokens stay on the frame.
+        let durations: Vec<(usize, f32)> = if dur_lp.is_empty() {
+            vec![(0, 0.0)]
+        } else {
+            top_k_indices(&dur_lp, BEAM_DURATIONS)
+                .into_iter()
+                .map(|d| (d, dur_lp[d]))
+                .collect()
+        };
+
+        let mut children = Vec::new();
+        for (token, token_score) in
+            self.beam_token_scores(&token_lp, vocab_logits, hyp.boost_state, beam_width, tree)
+        {
+            for &(duration, duration_lp) in &durations {
+                let mut child = hyp.clone();
+                child.score += token_score + duration_lp;
+                if token == self.blank_idx {
+                    child.t += duration.max(1);
+                    child.emitted = 0;
+                } else {
+                    // Confidence from the raw logits, as in the greedy paths.
+                    child.probs.push(token_lp[token as usize].exp());
+                    child.alternatives.push(token_alternatives(
+                        vocab_logits,
+                        token,
+                        self.blank_idx,
+                    ));
+                    child.tokens.push(token);
+                    child.timestamps.push(hyp.t);
+                    child.state = new_state.clone();
+                    child.boost_state =
+                        tree.map_or(hyp.boost_state, |tree| tree.advance(hyp.boost_state, token));
+                    child.emitted += 1;
+                    if duration > 0 {
+                        child.t += duration;
+                        child.emitted = 0;
+                    } else if child.emitted == MAX_TOKENS_PER_STEP {
+                        child.t += 1;
+                        child.emitted = 0;
+                    }
+                }
+                children.push(child);
+            }
+        }
+        Ok(children)
+    }
+
+    // Best `beam_width` tokens after fusion. Boosted tokens get the same
+    // top-K gate as the greedy path. In the beam, scores add up over the
+    // whole hypothesis, so a token no phrase expects pays back the partial
+    // boost (`BoostTree::backoff`), otherwise abandoned prefixes would win.
+    fn beam_token_scores(
+        &self,
+        token_lp: &[f32],
+        vocab_logits: &[f32],
+        boost_state: NodeId,
+        beam_width: usize,
+        tree: Option<&BoostTree>,
+    ) -> Vec<(i32, f32)> {
+        let mut scored: Vec<(i32, f32)> = top_k_indices(token_lp, beam_width)
+            .into_iter()
+            .map(|idx| (idx as i32, token_lp[idx]))
+            .collect();
+        let Some(tree) = tree else {
+            return scored;
+        };
+
+        let candidates = tree.bias(boost_state);
+        let reimburse = self.boost_alpha * tree.backoff(boost_state);
+        let tight = top_k_threshold(vocab_logits, BOOST_TOP_K);
+        let deep = top_k_threshold(vocab_logits, BOOST_TOP_K_DEEP);
+        for (token, score) in scored.iter_mut() {
+            if *token != self.blank_idx && !candidates.iter().any(|c| c.token == *token) {
+                *score += reimburse;
+            }
+        }
+        for cand in &candidates {
+            let idx = cand.token as usize;
+            if cand.token == self.blank_idx || idx >= token_lp.len() {
+                continue;
+            }
+            let threshold = if top_k_for_depth(cand.depth) == BOOST_TOP_K_DEEP {
+                deep
+            } else {
+                tight
+            };
+            let bonus = if vocab_logits[idx] >= threshold {
+                self.boost_alpha * cand.score
+            } else {
+                0.0
+            };
+            match scored.iter_mut().find(|(token, _)| *token == cand.token) {
+                Some((_, score)) => *score += bonus,
+                None if bonus > 0.0 => scored.push((cand.token, token_lp[idx] + bonus)),
+                None => {}
+            }
+        }
+        let penalty = self.boost_alpha * tree.closing_penalty(boost_state);
+        if penalty < 0.0 {
+            for (token, score) in scored.iter_mut() {
+                if *token != self.blank_idx && starts_word(&self.vocab, *token) {
+                    *score += penalty;
+                }
+            }
+        }
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(beam_width);
+        scored
     }
 
     fn decode_tokens(
@@ -733,15 +1062,30 @@ impl ParakeetModel {
         ids: Vec<i32>,
         timestamps: Vec<usize>,
         probs: Vec<f32>,
+        alternatives: Vec<Vec<(i32, f32)>>,
     ) -> TimestampedResult {
-        // tokens and kept_probs go through the same filter to stay aligned.
+        // tokens, kept_probs and kept_alternatives go through the same filter
+        // to stay aligned.
         let mut tokens: Vec<String> = Vec::with_capacity(ids.len());
         let mut kept_probs: Vec<f32> = Vec::with_capacity(ids.len());
+        let mut kept_alternatives: Vec<Vec<(String, f32)>> = Vec::with_capacity(ids.len());
         for (i, &id) in ids.iter().enumerate() {
             let idx = id as usize;
             if idx < self.vocab.len() {
                 tokens.push(self.vocab[idx].clone());
                 kept_probs.push(probs.get(i).copied().unwrap_or(1.0));
+                kept_alternatives.push(
+                    alternatives
+                        .get(i)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|&(alt, prob)| {
+                            self.vocab
+                                .get(alt as usize)
+                                .map(|text| (text.clone(), prob))
+                        })
+                        .collect(),
+                );
             }
         }
 
@@ -768,12 +1112,14 @@ impl ParakeetModel {
             timestamps: float_timestamps,
             tokens,
             probs: kept_probs,
+            alternatives: kept_alternatives,
         }
     }
 
     pub fn transcribe_samples(
         &mut self,
         samples: Vec<f32>,
+        beam_width: usize,
     ) -> Result<TimestampedResult, ParakeetError> {
         let batch_size = 1;
         let samples_len = samples.len();
@@ -785,7 +1131,8 @@ impl ParakeetModel {
         let waveforms_lens = Array1::from_vec(vec![samples_len as i64]).into_dyn();
 
         // Run recognition to get detailed results
-        let results = self.recognize_batch(&waveforms.view(), &waveforms_lens.view())?;
+        let results =
+            self.recognize_batch(&waveforms.view(), &waveforms_lens.view(), beam_width)?;
 
         // Extract the first (and only) result
         let timestamped_result = results.into_iter().next().ok_or_else(|| {
@@ -808,23 +1155,11 @@ use super::types::{
 use std::path::Path as StdPath;
 
 impl ParakeetEngine {
-    pub fn set_boost_words(&mut self, words: &[String]) {
+    pub fn set_boost_words(&mut self, words: &[(String, f32)]) {
         if let Some(model) = self.model.as_mut() {
             model.set_boost_words(words);
         }
     }
-
-    /// Load an int8 engine with the given bundled tokenizer in one call.
-    pub fn load_int8(
-        model_path: &StdPath,
-        tokenizer_path: Option<std::path::PathBuf>,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
-        let mut params = ParakeetModelParams::int8();
-        params.tokenizer_path = tokenizer_path;
-        let mut engine = ParakeetEngine::new();
-        engine.load_model_with_params(model_path, params)?;
-        Ok(engine)
-    }
 }
 
 impl TranscriptionEngine for ParakeetEngine {
@@ -840,7 +1175,12 @@ impl TranscriptionEngine for ParakeetEngine {
             QuantizationType::FP32 => false,
             QuantizationType::Int8 => true,
         };
-        let model = ParakeetModel::new(model_path, quantized, params.tokenizer_path.as_deref())?;
+        let model = ParakeetModel::new(
+            model_path,
+            quantized,
+            params.threads,
+            params.tokenizer_path.as_deref(),
+        )?;
 
         self.model = Some(model);
         self.loaded_model_path = Some(model_path.to_path_buf());
@@ -860,7 +1200,7 @@ impl TranscriptionEngine for ParakeetEngine {
         let parakeet_params = params.unwrap_or_default();
 
         // Get the timestamped result from the model
-        let timestamped_result = model.transcribe_samples(samples)?;
+        let timestamped_result = model.transcribe_samples(samples, parakeet_params.beam_width)?;
 
         // Convert timestamps based on requested granularity
         let segments =
@@ -868,6 +1208,7 @@ impl TranscriptionEngine for ParakeetEngine {
 
         Ok(TranscriptionResult {
             word_confidences: super::helpers::word_confidences(&timestamped_result),
+            words: super::helpers::timed_words(&timestamped_result),
             text: timestamped_result.text,
             segments,
         })
@@ -900,6 +1241,36 @@ mod tests {
         assert!(!in_top_k(&logits, 0, 3));
     }
 
+    #[test]
+    fn log_softmax_sums_to_one_and_keeps_order() {
+        let lp = log_softmax(&[1.0, 3.0, 2.0]);
+        let total: f32 = lp.iter().map(|l| l.exp()).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        assert_eq!(top_k_indices(&lp, 2), vec![1, 2]);
+    }
+
+    #[test]
+    fn token_alternatives_skip_emitted_blank_and_noise() {
+        // Token 3 is blank; token 0 is far below MIN_ALTERNATIVE_PROB.
+        let logits = [-5.0, 3.0, 2.5, 4.0, 2.0];
+        let alternatives = token_alternatives(&logits, 1, 3);
+        let ids: Vec<i32> = alternatives.iter().map(|&(id, _)| id).collect();
+        assert_eq!(ids, vec![2, 4]);
+        assert!((alternatives[0].1 - softmax_prob(&logits, 2)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn closing_penalty_spares_word_continuations() {
+        // "▁ass" suppressed: "istant" continues it into "assistant".
+        let vocab: Vec<String> = [" ass", "istant", " is", "<blk>"]
+            .iter()
+            .map(|piece| piece.to_string())
+            .collect();
+        let mut logits = [1.0, 1.0, 1.0, 1.0];
+        penalize_word_starts(&mut logits, &vocab, -2.0, 3);
+        assert_eq!(logits, [-1.0, 1.0, -1.0, 1.0]);
+    }
+
     #[test]
     fn top_k_relaxes_once_match_is_engaged() {
         assert_eq!(top_k_for_depth(1), BOOST_TOP_K);
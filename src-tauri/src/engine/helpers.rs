@@ -1,6 +1,6 @@
 use tokenizers::Tokenizer;
 
-use super::transcription_engine::TranscriptionSegment;
+use super::transcription_engine::{TranscriptionSegment, TranscriptionWord, WordAlternative};
 use super::types::{Segment, TimestampGranularity, TimestampedResult, Token, Utterance, Word};
 
 /// Replace accented Latin letters with their ASCII base. Coverage is
@@ -95,28 +95,129 @@ pub fn load_tokenizer(tokenizer_path: Option<&std::path::Path>) -> Option<Tokeni
     }
 }
 
+/// Softmax probability of `token` over the raw `logits`, 0 when the token is
+/// out of range.
+pub fn softmax_prob(logits: &[f32], token: usize) -> f32 {
+    let Some(&target) = logits.get(token) else {
+        return 0.0;
+    };
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let sum: f32 = logits.iter().map(|&l| (l - max).exp()).sum();
+    if sum > 0.0 {
+        ((target - max).exp() / sum).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
 /// Per-word confidence: the minimum token probability (weakest link), with
 /// punctuation-only tokens excluded from the minimum.
 pub fn word_confidences(result: &TimestampedResult) -> Vec<(String, f32)> {
-    let mut words: Vec<(String, f32)> = Vec::new();
-    for (token, &prob) in result.tokens.iter().zip(result.probs.iter()) {
-        let has_content = token.chars().any(|c| c.is_alphanumeric());
-        if token.starts_with(' ') || words.is_empty() {
-            words.push((
-                token.trim_start().to_string(),
-                if has_content { prob } else { 1.0 },
-            ));
-        } else if let Some((text, conf)) = words.last_mut() {
-            text.push_str(token);
+    timed_words(result)
+        .into_iter()
+        .map(|word| (word.text, word.confidence))
+        .collect()
+}
+
+// A TDT step advances at most 4 encoder frames (80 ms each). Past that, the
+// gap before the next token is silence, not the end of the current word.
+const MAX_TOKEN_SPAN_SECS: f32 = 0.32;
+
+// Alternatives kept per word, best first.
+const WORD_ALTERNATIVES: usize = 3;
+
+/// Group tokens into words with their time span and confidence. A word ends
+/// where the next token starts, capped at `MAX_TOKEN_SPAN_SECS` after its last
+/// token so pauses are not absorbed into the preceding word.
+pub fn timed_words(result: &TimestampedResult) -> Vec<TranscriptionWord> {
+    let tokens = create_tokens_from_timestamped_result(result);
+    let mut words: Vec<TranscriptionWord> = Vec::new();
+    // Token indices of each word, to build its alternatives.
+    let mut spans: Vec<Vec<usize>> = Vec::new();
+    for (i, (token, &prob)) in tokens.iter().zip(result.probs.iter()).enumerate() {
+        let has_content = token.text.chars().any(|c| c.is_alphanumeric());
+        let end = token.t_end.min(token.t_start + MAX_TOKEN_SPAN_SECS);
+        if token.text.starts_with(' ') || words.is_empty() {
+            words.push(TranscriptionWord {
+                text: token.text.trim_start().to_string(),
+                start: token.t_start,
+                end,
+                confidence: if has_content { prob } else { 1.0 },
+                alternatives: Vec::new(),
+            });
+            spans.push(vec![i]);
+        } else if let (Some(word), Some(span)) = (words.last_mut(), spans.last_mut()) {
+            word.text.push_str(&token.text);
+            word.end = end;
             if has_content {
-                *conf = conf.min(prob);
+                word.confidence = word.confidence.min(prob);
             }
+            span.push(i);
         }
     }
-    words.retain(|(text, _)| text.chars().any(|c| c.is_alphanumeric()));
+    for (word, span) in words.iter_mut().zip(&spans) {
+        word.alternatives = word_alternatives(result, span, &word.text);
+    }
+    words.retain(|word| word.text.chars().any(|c| c.is_alphanumeric()));
     words
 }
 
+/// Spellings of a word with one token swapped for a runner-up, scored with the
+/// same weakest-link rule as its confidence. Only single swaps are built: the
+/// tokens after a swap were decoded from the emitted one, so chaining runner-ups
+/// would be a guess.
+fn word_alternatives(
+    result: &TimestampedResult,
+    span: &[usize],
+    text: &str,
+) -> Vec<WordAlternative> {
+    let has_content = |token: &str| token.chars().any(|c| c.is_alphanumeric());
+    let mut alternatives: Vec<WordAlternative> = Vec::new();
+    for &swapped in span {
+        let Some(runner_ups) = result.alternatives.get(swapped) else {
+            continue;
+        };
+        for (runner_up, runner_up_prob) in runner_ups {
+            // A leading space moves the word boundary: that is another split,
+            // not another spelling of this word.
+            if runner_up.starts_with(' ') != result.tokens[swapped].starts_with(' ') {
+                continue;
+            }
+            let mut word = String::new();
+            let mut probability = 1.0_f32;
+            for &i in span {
+                let (token, prob) = if i == swapped {
+                    (runner_up.as_str(), *runner_up_prob)
+                } else {
+                    (
+                        result.tokens[i].as_str(),
+                        result.probs.get(i).copied().unwrap_or(1.0),
+                    )
+                };
+                word.push_str(token);
+                if has_content(token) {
+                    probability = probability.min(prob);
+                }
+            }
+            let word = word.trim_start().to_string();
+            if word == text || !has_content(&word) {
+                continue;
+            }
+            match alternatives.iter_mut().find(|a| a.word == word) {
+                Some(existing) => existing.probability = existing.probability.max(probability),
+                None => alternatives.push(WordAlternative { word, probability }),
+            }
+        }
+    }
+    alternatives.sort_by(|a, b| {
+        b.probability
+            .partial_cmp(&a.probability)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    alternatives.truncate(WORD_ALTERNATIVES);
+    alternatives
+}
+
 pub fn convert_timestamps(
     timestamped_result: &TimestampedResult,
     granularity: TimestampGranularity,
@@ -527,6 +628,7 @@ mod tests {
             timestamps: vec![0.0; tokens.len()],
             tokens: tokens.iter().map(|t| t.to_string()).collect(),
             probs: probs.to_vec(),
+            alternatives: Vec::new(),
         }
     }
 
@@ -550,6 +652,60 @@ mod tests {
         assert!((words[0].1 - 0.9).abs() < 1e-6);
     }
 
+    #[test]
+    fn timed_words_spans_first_to_last_token() {
+        let result = TimestampedResult {
+            text: String::new(),
+            timestamps: vec![0.0, 0.08, 0.4],
+            tokens: vec![" ma".to_string(), "çon".to_string(), " mur".to_string()],
+            probs: vec![0.9, 0.4, 0.8],
+            alternatives: Vec::new(),
+        };
+        let words = timed_words(&result);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "maçon");
+        assert_eq!(words[0].start, 0.0);
+        assert!((words[0].end - 0.4).abs() < 1e-6);
+        assert!((words[0].confidence - 0.4).abs() < 1e-6);
+        assert_eq!(words[1].text, "mur");
+        assert!((words[1].start - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn timed_words_does_not_stretch_a_word_over_a_pause() {
+        let result = TimestampedResult {
+            text: String::new(),
+            timestamps: vec![0.0, 3.0],
+            tokens: vec![" bonjour".to_string(), " monde".to_string()],
+            probs: vec![0.9, 0.9],
+            alternatives: Vec::new(),
+        };
+        let words = timed_words(&result);
+        assert!((words[0].end - MAX_TOKEN_SPAN_SECS).abs() < 1e-6);
+        assert!((words[1].start - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn timed_words_swap_one_token_for_alternatives() {
+        let mut result = timestamped(&[" ma", "çon", " mur"], &[0.9, 0.4, 0.8]);
+        result.alternatives = vec![
+            vec![(" mai".to_string(), 0.05)],
+            vec![("son".to_string(), 0.35), (" son".to_string(), 0.2)],
+            Vec::new(),
+        ];
+        let words = timed_words(&result);
+        let texts: Vec<&str> = words[0]
+            .alternatives
+            .iter()
+            .map(|a| a.word.as_str())
+            .collect();
+        // " son" would split the word in two, so it is not an alternative.
+        assert_eq!(texts, vec!["mason", "maiçon"]);
+        assert!((words[0].alternatives[0].probability - 0.35).abs() < 1e-6);
+        assert!((words[0].alternatives[1].probability - 0.05).abs() < 1e-6);
+        assert!(words[1].alternatives.is_empty());
+    }
+
     #[test]
     fn word_variants_dedups_plain_lowercase_word() {
         let variants = word_variants("celecoxib");
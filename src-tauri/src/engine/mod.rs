@@ -1,7 +1,9 @@
+pub mod asr_engine;
 pub mod boost_tree;
 pub mod engine;
 pub mod helpers;
 pub mod transcription_engine;
 pub mod types;
+pub mod whisper;
 
 pub use types::*;
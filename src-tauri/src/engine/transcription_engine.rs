@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::path::Path;
 
 #[derive(Debug)]
@@ -8,6 +9,46 @@ pub struct TranscriptionResult {
     /// Per-word model confidence (min softmax prob over the word's tokens),
     /// in text order. Used to gate the dictionary fuzzy post-correction.
     pub word_confidences: Vec<(String, f32)>,
+    /// Words with their time span, in seconds from the start of the samples.
+    pub words: Vec<TranscriptionWord>,
+}
+
+/// A decoded word with its time span and its confidence (same grouping and
+/// weakest-link rule as `word_confidences`). Serialized with the OpenAI
+/// `verbose_json` field names.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TranscriptionWord {
+    #[serde(rename = "word")]
+    pub text: String,
+    pub start: f32,
+    pub end: f32,
+    #[serde(rename = "probability")]
+    pub confidence: f32,
+    /// Other spellings the model considered for this word, most probable
+    /// first. Empty when it had no serious second guess.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<WordAlternative>,
+}
+
+#[cfg(test)]
+impl TranscriptionWord {
+    /// A word decoded with full confidence and no alternatives.
+    pub fn new(text: &str, start: f32, end: f32) -> Self {
+        Self {
+            text: text.to_string(),
+            start,
+            end,
+            confidence: 1.0,
+            alternatives: Vec::new(),
+        }
+    }
+}
+
+/// A runner-up for a decoded word, scored like `TranscriptionWord::confidence`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WordAlternative {
+    pub word: String,
+    pub probability: f32,
 }
 
 #[derive(Debug)]
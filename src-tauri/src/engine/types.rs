@@ -1,4 +1,4 @@
-use ndarray::Array3;
+use ndarray::{Array2, Array3};
 use ort::session::Session;
 use serde::{Deserialize, Serialize};
 use tokenizers::Tokenizer;
@@ -15,6 +15,9 @@ pub struct TimestampedResult {
     /// Softmax probability of each emitted token over the raw (unboosted)
     /// vocab logits, aligned with `tokens`.
     pub probs: Vec<f32>,
+    /// Runner-up tokens of each emitted token with their probability, best
+    /// first, aligned with `tokens`.
+    pub alternatives: Vec<Vec<(String, f32)>>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -43,9 +46,9 @@ pub struct ParakeetModel {
     pub tokenizer: Option<Tokenizer>,
     pub boost_tree: Option<BoostTree>,
     pub boost_alpha: f32,
-    /// Sorted word list the current boost tree was built from, to skip
+    /// Sorted weighted entries the current boost tree was built from, to skip
     /// rebuilding when the dictionary has not changed between transcriptions.
-    pub boost_words: Vec<String>,
+    pub boost_words: Vec<(String, f32)>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -86,15 +89,78 @@ pub enum TimestampGranularity {
     Segment,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(thiserror::Error, Debug)]
+pub enum WhisperError {
+    #[error("ORT error")]
+    Ort(#[from] ort::Error),
+    #[error("I/O error")]
+    Io(#[from] std::io::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Model file not found: {0}")]
+    ModelFileNotFound(String),
+    #[error("Model output not found: {0}")]
+    OutputNotFound(String),
+    #[error("Invalid model metadata: {0}")]
+    Metadata(String),
+    #[error("Language not supported by this model: {0}")]
+    UnsupportedLanguage(String),
+}
+
+/// A Whisper-family encoder/decoder exported to ONNX in the sherpa-onnx
+/// layout (`*-encoder.onnx`, `*-decoder.onnx`, `*-tokens.txt`). The model
+/// dimensions and special tokens come from the encoder metadata.
+pub struct WhisperModel {
+    pub encoder: Session,
+    pub decoder: Session,
+    /// Raw bytes of each token, indexed by id. Byte-level BPE tokens may hold
+    /// part of a UTF-8 character.
+    pub tokens: Vec<Vec<u8>>,
+    /// Mel filterbank, `[n_mels, N_FFT / 2 + 1]`.
+    pub mel_filters: Array2<f32>,
+    pub n_text_layer: usize,
+    pub n_text_ctx: usize,
+    pub n_text_state: usize,
+    pub sot: i64,
+    pub eot: i64,
+    pub transcribe: i64,
+    pub no_timestamps: i64,
+    pub is_multilingual: bool,
+    /// (ISO code, token id) of every language the model can transcribe.
+    pub languages: Vec<(String, i64)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum QuantizationType {
     FP32,
+    #[default]
     Int8,
 }
+
+/// Upper bound of the thread counts accepted from the settings and the CLI.
+pub const MAX_SESSION_THREADS: usize = 128;
+
+/// ONNX Runtime thread pools of each session; `None` lets ORT decide.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionThreads {
+    pub intra_op: Option<usize>,
+    /// Only used above 1, which runs independent graph nodes in parallel.
+    pub inter_op: Option<usize>,
+}
+
+/// How the backends build their ONNX Runtime sessions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionOptions {
+    pub quantization: QuantizationType,
+    pub threads: SessionThreads,
+}
+
 /// Parameters for configuring Parakeet model loading.
 #[derive(Debug, Clone)]
 pub struct ParakeetModelParams {
     pub quantization: QuantizationType,
+    pub threads: SessionThreads,
     pub tokenizer_path: Option<std::path::PathBuf>,
 }
 
@@ -102,15 +168,7 @@ impl Default for ParakeetModelParams {
     fn default() -> Self {
         Self {
             quantization: QuantizationType::FP32,
-            tokenizer_path: None,
-        }
-    }
-}
-
-impl ParakeetModelParams {
-    pub fn int8() -> Self {
-        Self {
-            quantization: QuantizationType::Int8,
+            threads: SessionThreads::default(),
             tokenizer_path: None,
         }
     }
@@ -120,12 +178,53 @@ impl ParakeetModelParams {
 #[derive(Debug, Clone)]
 pub struct ParakeetInferenceParams {
     pub timestamp_granularity: TimestampGranularity,
+    /// Hypotheses kept by the TDT beam search. 1 runs the greedy decode.
+    pub beam_width: usize,
 }
 
 impl Default for ParakeetInferenceParams {
     fn default() -> Self {
         Self {
             timestamp_granularity: TimestampGranularity::Token,
+            beam_width: 1,
+        }
+    }
+}
+
+/// Parameters for configuring Whisper model loading.
+#[derive(Debug, Clone)]
+pub struct WhisperModelParams {
+    pub quantization: QuantizationType,
+    pub threads: SessionThreads,
+}
+
+impl Default for WhisperModelParams {
+    fn default() -> Self {
+        Self {
+            quantization: QuantizationType::FP32,
+            threads: SessionThreads::default(),
+        }
+    }
+}
+
+/// Parameters for configuring Whisper inference behavior.
+#[derive(Debug, Clone, Default)]
+pub struct WhisperInferenceParams {
+    /// ISO code of the spoken language; `None` detects it on each window.
+    pub language: Option<String>,
+}
+
+/// Whisper speech recognition engine wrapper.
+pub struct WhisperEngine {
+    pub model: Option<WhisperModel>,
+    pub loaded_model_path: Option<std::path::PathBuf>,
+}
+
+impl WhisperEngine {
+    pub fn new() -> Self {
+        Self {
+            model: None,
+            loaded_model_path: None,
         }
     }
 }
@@ -144,3 +243,36 @@ impl ParakeetEngine {
         }
     }
 }
+
+/// Speech recognition backends the app can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsrBackend {
+    #[default]
+    Parakeet,
+    Whisper,
+}
+
+/// Backend-agnostic inference parameters, mapped onto each backend's own.
+#[derive(Debug, Clone)]
+pub struct AsrInferenceParams {
+    /// Beam width of the Parakeet decoder; Whisper always decodes greedily.
+    pub beam_width: usize,
+}
+
+impl Default for AsrInferenceParams {
+    fn default() -> Self {
+        Self { beam_width: 1 }
+    }
+}
+
+/// The loaded transcription engine, whichever the backend. The pipeline, the
+/// streaming preview and the wake word only go through this handle.
+pub enum AsrEngine {
+    Parakeet(ParakeetEngine),
+    Whisper {
+        engine: WhisperEngine,
+        /// ISO code of the spoken language, `None` to detect it.
+        language: Option<String>,
+    },
+}
@@ -0,0 +1,580 @@
+use base64::Engine as _;
+use ndarray::{Array2, Array3, Array4, ArrayD};
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use realfft::RealFftPlanner;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::helpers::{convert_timestamps, softmax_prob, timed_words, word_confidences};
+use super::transcription_engine::{TranscriptionEngine, TranscriptionResult};
+use super::types::{
+    QuantizationType, SessionThreads, TimestampGranularity, TimestampedResult, WhisperEngine,
+    WhisperError, WhisperInferenceParams, WhisperModel, WhisperModelParams,
+};
+
+const SAMPLE_RATE: usize = 16000;
+const N_FFT: usize = 400;
+const HOP_LENGTH: usize = 160;
+/// Whisper always encodes 30 s of audio; longer input is cut into windows
+/// decoded one after the other, shorter input is zero-padded.
+pub const WINDOW_SECS: u32 = 30;
+const WINDOW_SAMPLES: usize = SAMPLE_RATE * WINDOW_SECS as usize;
+const N_FRAMES: usize = WINDOW_SAMPLES / HOP_LENGTH;
+// Windows shorter than this are dropped: Whisper tends to hallucinate a
+// sentence on a near-empty window.
+const MIN_WINDOW_SAMPLES: usize = SAMPLE_RATE / 10;
+
+/// Per-layer attention keys and values, `[n_text_layer, 1, ctx, n_text_state]`.
+type KvCache = (ArrayD<f32>, ArrayD<f32>);
+
+/// Whether `dir` holds a Whisper model in the sherpa-onnx layout.
+pub fn is_whisper_model_dir(dir: &Path) -> bool {
+    find_model_file(dir, "-tokens.txt").is_some()
+        && (find_model_file(dir, "-encoder.onnx").is_some()
+            || find_model_file(dir, "-encoder.int8.onnx").is_some())
+}
+
+// First file of `dir` whose name ends with `suffix` (e.g. `tiny-encoder.onnx`).
+pub(crate) fn find_model_file(dir: &Path, suffix: &str) -> Option<PathBuf> {
+    let mut matches: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(suffix))
+        })
+        .collect();
+    matches.sort();
+    matches.into_iter().next()
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    // Slaney scale (librosa default, used by Whisper): linear below 1 kHz,
+    // logarithmic above.
+    let min_log_mel = 15.0;
+    if hz >= 1000.0 {
+        min_log_mel + (hz / 1000.0).ln() / (6.4f32.ln() / 27.0)
+    } else {
+        hz * 3.0 / 200.0
+    }
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    let min_log_mel = 15.0;
+    if mel >= min_log_mel {
+        1000.0 * ((6.4f32.ln() / 27.0) * (mel - min_log_mel)).exp()
+    } else {
+        mel * 200.0 / 3.0
+    }
+}
+
+/// Slaney-normalized triangular mel filterbank, `[n_mels, N_FFT / 2 + 1]`.
+fn mel_filterbank(n_mels: usize) -> Array2<f32> {
+    let n_freqs = N_FFT / 2 + 1;
+    let max_mel = hz_to_mel(SAMPLE_RATE as f32 / 2.0);
+    let hz_points: Vec<f32> = (0..n_mels + 2)
+        .map(|i| mel_to_hz(max_mel * i as f32 / (n_mels + 1) as f32))
+        .collect();
+
+    let mut filters = Array2::zeros((n_mels, n_freqs));
+    for m in 0..n_mels {
+        let (lower, center, upper) = (hz_points[m], hz_points[m + 1], hz_points[m + 2]);
+        let norm = 2.0 / (upper - lower);
+        for k in 0..n_freqs {
+            let hz = (k * SAMPLE_RATE) as f32 / N_FFT as f32;
+            let rising = (hz - lower) / (center - lower);
+            let falling = (upper - hz) / (upper - center);
+            filters[[m, k]] = rising.min(falling).max(0.0) * norm;
+        }
+    }
+    filters
+}
+
+/// Whisper log-mel spectrogram of one window, `[1, n_mels, N_FRAMES]`. The
+/// samples are zero-padded to 30 s and framed with a centered STFT.
+fn log_mel_spectrogram(samples: &[f32], filters: &Array2<f32>) -> Array3<f32> {
+    let mut audio = samples[..samples.len().min(WINDOW_SAMPLES)].to_vec();
+    audio.resize(WINDOW_SAMPLES, 0.0);
+
+    // Reflect padding, as torch.stft(center=True).
+    let pad = N_FFT / 2;
+    let mut padded = Vec::with_capacity(WINDOW_SAMPLES + 2 * pad);
+    padded.extend((1..=pad).rev().map(|i| audio[i]));
+    padded.extend_from_slice(&audio);
+    padded.extend((1..=pad).map(|i| audio[WINDOW_SAMPLES - 1 - i]));
+
+    let window: Vec<f32> = (0..N_FFT)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / N_FFT as f32).cos())
+        .collect();
+    let fft = RealFftPlanner::<f32>::new().plan_fft_forward(N_FFT);
+    let mut frame = fft.make_input_vec();
+    let mut spectrum = fft.make_output_vec();
+
+    let n_mels = filters.nrows();
+    let mut mel = Array3::zeros((1, n_mels, N_FRAMES));
+    for t in 0..N_FRAMES {
+        let start = t * HOP_LENGTH;
+        for (i, value) in frame.iter_mut().enumerate() {
+            *value = padded[start + i] * window[i];
+        }
+        if fft.process(&mut frame, &mut spectrum).is_err() {
+            continue;
+        }
+        let power: Vec<f32> = spectrum.iter().map(|c| c.norm_sqr()).collect();
+        for m in 0..n_mels {
+            let energy: f32 = filters.row(m).iter().zip(&power).map(|(w, p)| w * p).sum();
+            mel[[0, m, t]] = energy.max(1e-10).log10();
+        }
+    }
+
+    let max = mel.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    mel.mapv_inplace(|v| (v.max(max - 8.0) + 4.0) / 4.0);
+    mel
+}
+
+/// Turn byte-level tokens into text pieces. A token ending inside a UTF-8
+/// character is merged into the next one, keeping the lowest probability.
+fn merge_byte_tokens(tokens: &[(Vec<u8>, f32)]) -> Vec<(String, f32)> {
+    let mut pieces = Vec::new();
+    let mut pending: Vec<u8> = Vec::new();
+    let mut pending_prob = 1.0f32;
+    for (bytes, prob) in tokens {
+        pending.extend_from_slice(bytes);
+        pending_prob = pending_prob.min(*prob);
+        if let Ok(text) = std::str::from_utf8(&pending) {
+            pieces.push((text.to_string(), pending_prob));
+            pending.clear();
+            pending_prob = 1.0;
+        }
+    }
+    if !pending.is_empty() {
+        pieces.push((String::from_utf8_lossy(&pending).into_owned(), pending_prob));
+    }
+    pieces
+}
+
+fn metadata_value(session: &Session, key: &str) -> Result<String, WhisperError> {
+    session
+        .metadata()?
+        .custom(key)?
+        .ok_or_else(|| WhisperError::Metadata(key.to_string()))
+}
+
+fn metadata_number<T: std::str::FromStr>(session: &Session, key: &str) -> Result<T, WhisperError> {
+    metadata_value(session, key)?
+        .trim()
+        .parse()
+        .map_err(|_| WhisperError::Metadata(key.to_string()))
+}
+
+impl WhisperModel {
+    pub fn new<P: AsRef<Path>>(
+        model_dir: P,
+        quantized: bool,
+        threads: SessionThreads,
+    ) -> Result<Self, WhisperError> {
+        let model_dir = model_dir.as_ref();
+        let encoder = Self::init_session(model_dir, "encoder", threads, quantized)?;
+        let decoder = Self::init_session(model_dir, "decoder", threads, quantized)?;
+        let tokens = Self::load_tokens(model_dir)?;
+
+        let n_mels: usize = metadata_number(&encoder, "n_mels")?;
+        let is_multilingual = metadata_number::<i32>(&encoder, "is_multilingual")? != 0;
+        let languages = if is_multilingual {
+            let codes = metadata_value(&encoder, "all_language_codes")?;
+            let ids = metadata_value(&encoder, "all_language_tokens")?;
+            codes
+                .split(',')
+                .zip(ids.split(','))
+                .map(|(code, id)| {
+                    id.trim()
+                        .parse()
+                        .map(|id| (code.trim().to_string(), id))
+                        .map_err(|_| WhisperError::Metadata("all_language_tokens".to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+
+        let model = Self {
+            mel_filters: mel_filterbank(n_mels),
+            n_text_layer: metadata_number(&encoder, "n_text_layer")?,
+            n_text_ctx: metadata_number(&encoder, "n_text_ctx")?,
+            n_text_state: metadata_number(&encoder, "n_text_state")?,
+            sot: metadata_number(&encoder, "sot")?,
+            eot: metadata_number(&encoder, "eot")?,
+            transcribe: metadata_number(&encoder, "transcribe")?,
+            no_timestamps: metadata_number(&encoder, "no_timestamps")?,
+            is_multilingual,
+            languages,
+            encoder,
+            decoder,
+            tokens,
+        };
+        log::trace!(
+            "Loaded Whisper model: {} mels, {} decoder layers, {} languages",
+            n_mels,
+            model.n_text_layer,
+            model.languages.len()
+        );
+        Ok(model)
+    }
+
+    fn init_session(
+        model_dir: &Path,
+        part: &str,
+        threads: SessionThreads,
+        try_quantized: bool,
+    ) -> Result<Session, WhisperError> {
+        // The requested precision first, the other one if the export has
+        // only that one.
+        let quantized = format!("-{}.int8.onnx", part);
+        let regular = format!("-{}.onnx", part);
+        let (preferred, other) = if try_quantized {
+            (&quantized, &regular)
+        } else {
+            (&regular, &quantized)
+        };
+        let path = find_model_file(model_dir, preferred)
+            .or_else(|| find_model_file(model_dir, other))
+            .ok_or_else(|| WhisperError::ModelFileNotFound(format!("*{}", regular)))?;
+        log::trace!("Loading Whisper {} from {}...", part, path.display());
+
+        let mut builder = Session::builder()?
+            .with_config_entry("session.log_severity_level", "3")?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .with_memory_pattern(false)?
+            .with_parallel_execution(threads.inter_op.is_some_and(|n| n > 1))?;
+        if let Some(n) = threads.intra_op {
+            builder = builder.with_intra_threads(n)?;
+        }
+        if let Some(n) = threads.inter_op {
+            builder = builder.with_inter_threads(n)?;
+        }
+        Ok(builder.commit_from_file(path)?)
+    }
+
+    // `*-tokens.txt`: one `<base64 bytes> <id>` pair per line.
+    fn load_tokens(model_dir: &Path) -> Result<Vec<Vec<u8>>, WhisperError> {
+        let path = find_model_file(model_dir, "-tokens.txt")
+            .ok_or_else(|| WhisperError::ModelFileNotFound("*-tokens.txt".to_string()))?;
+        let content = fs::read_to_string(path)?;
+
+        let mut tokens: Vec<Vec<u8>> = Vec::new();
+        for line in content.lines() {
+            let mut parts = line.split_whitespace();
+            let (Some(encoded), Some(id)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let (Ok(bytes), Ok(id)) = (
+                base64::engine::general_purpose::STANDARD.decode(encoded),
+                id.parse::<usize>(),
+            ) else {
+                continue;
+            };
+            if tokens.len() <= id {
+                tokens.resize(id + 1, Vec::new());
+            }
+            tokens[id] = bytes;
+        }
+        Ok(tokens)
+    }
+
+    /// Cross-attention keys and values of one window, fed to every decoder step.
+    fn encode(&mut self, mel: &Array3<f32>) -> Result<KvCache, WhisperError> {
+        log::trace!("Running Whisper encoder inference...");
+        let outputs = self
+            .encoder
+            .run(inputs!["mel" => TensorRef::from_array_view(mel.view())?])?;
+        let cross_k = outputs
+            .get("n_layer_cross_k")
+            .ok_or_else(|| WhisperError::OutputNotFound("n_layer_cross_k".to_string()))?
+            .try_extract_array::<f32>()?
+            .to_owned();
+        let cross_v = outputs
+            .get("n_layer_cross_v")
+            .ok_or_else(|| WhisperError::OutputNotFound("n_layer_cross_v".to_string()))?
+            .try_extract_array::<f32>()?
+            .to_owned();
+        Ok((cross_k, cross_v))
+    }
+
+    fn empty_self_cache(&self) -> Array4<f32> {
+        Array4::zeros((self.n_text_layer, 1, self.n_text_ctx, self.n_text_state))
+    }
+
+    /// One decoder run over `tokens`, starting at position `offset`. Returns
+    /// the logits of the last token and the updated self-attention caches.
+    fn decode_step(
+        &mut self,
+        tokens: &[i64],
+        self_cache: KvCache,
+        cross: &KvCache,
+        offset: usize,
+    ) -> Result<(Vec<f32>, KvCache), WhisperError> {
+        let tokens = Array2::from_shape_vec((1, tokens.len()), tokens.to_vec())?;
+        let offset = ndarray::Array1::from_vec(vec![offset as i64]);
+        let outputs = self.decoder.run(inputs![
+            "tokens" => TensorRef::from_array_view(tokens.view())?,
+            "in_n_layer_self_k_cache" => TensorRef::from_array_view(self_cache.0.view())?,
+            "in_n_layer_self_v_cache" => TensorRef::from_array_view(self_cache.1.view())?,
+            "n_layer_cross_k" => TensorRef::from_array_view(cross.0.view())?,
+            "n_layer_cross_v" => TensorRef::from_array_view(cross.1.view())?,
+            "offset" => TensorRef::from_array_view(offset.view())?,
+        ])?;
+
+        let logits = outputs
+            .get("logits")
+            .ok_or_else(|| WhisperError::OutputNotFound("logits".to_string()))?
+            .try_extract_array::<f32>()?;
+        // [1, n_tokens, n_vocab]: only the last position predicts the next token.
+        let last = logits.shape()[1].saturating_sub(1);
+        let logits = logits
+            .index_axis(ndarray::Axis(0), 0)
+            .index_axis(ndarray::Axis(0), last)
+            .iter()
+            .copied()
+            .collect();
+        let self_k = outputs
+            .get("out_n_layer_self_k_cache")
+            .ok_or_else(|| WhisperError::OutputNotFound("out_n_layer_self_k_cache".to_string()))?
+            .try_extract_array::<f32>()?
+            .to_owned();
+        let self_v = outputs
+            .get("out_n_layer_self_v_cache")
+            .ok_or_else(|| WhisperError::OutputNotFound("out_n_layer_self_v_cache".to_string()))?
+            .try_extract_array::<f32>()?
+            .to_owned();
+        Ok((logits, (self_k, self_v)))
+    }
+
+    /// Language token of the spoken language: the most probable language
+    /// token right after start-of-transcript.
+    fn detect_language(&mut self, cross: &KvCache) -> Result<i64, WhisperError> {
+        let cache = (
+            self.empty_self_cache().into_dyn(),
+            self.empty_self_cache().into_dyn(),
+        );
+        let (logits, _) = self.decode_step(&[self.sot], cache, cross, 0)?;
+        let best = self
+            .languages
+            .iter()
+            .max_by(|a, b| {
+                let score = |id: i64| logits.get(id as usize).copied().unwrap_or(f32::MIN);
+                score(a.1)
+                    .partial_cmp(&score(b.1))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .ok_or_else(|| WhisperError::Metadata("all_language_tokens".to_string()))?;
+        log::debug!("Whisper detected language: {}", best.0);
+        Ok(best.1)
+    }
+
+    fn language_token(&self, language: &str) -> Result<i64, WhisperError> {
+        self.languages
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(language))
+            .map(|&(_, id)| id)
+            .ok_or_else(|| WhisperError::UnsupportedLanguage(language.to_string()))
+    }
+
+    /// Greedy decode of one 30 s window: text tokens with their probability.
+    fn decode_window(
+        &mut self,
+        samples: &[f32],
+        language: Option<&str>,
+    ) -> Result<Vec<(i64, f32)>, WhisperError> {
+        let mel = log_mel_spectrogram(samples, &self.mel_filters);
+        let cross = self.encode(&mel)?;
+
+        let mut prompt = vec![self.sot];
+        if self.is_multilingual {
+            let language = match language {
+                Some(language) => self.language_token(language)?,
+                None => self.detect_language(&cross)?,
+            };
+            prompt.extend([language, self.transcribe]);
+        }
+        prompt.push(self.no_timestamps);
+
+        let mut cache = (
+            self.empty_self_cache().into_dyn(),
+            self.empty_self_cache().into_dyn(),
+        );
+        let mut offset = 0;
+        let mut input = prompt;
+        let mut emitted: Vec<(i64, f32)> = Vec::new();
+        let max_tokens = self.n_text_ctx / 2;
+        while emitted.len() < max_tokens {
+            let (logits, next_cache) = self.decode_step(&input, cache, &cross, offset)?;
+            cache = next_cache;
+            offset += input.len();
+
+            // Text tokens are below eot; timestamps and control tokens above
+            // it never belong in the transcript.
+            let text_logits = &logits[..logits.len().min(self.eot as usize + 1)];
+            let token = text_logits
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(idx, _)| idx as i64)
+                .unwrap_or(self.eot);
+            if token == self.eot {
+                break;
+            }
+            emitted.push((token, softmax_prob(&logits, token as usize)));
+            input = vec![token];
+        }
+        Ok(emitted)
+    }
+
+    /// Transcribe the samples window by window. Whisper is decoded without
+    /// timestamp tokens, so token times are spread evenly over each window's
+    /// audio: word timings are approximate.
+    pub fn transcribe_samples(
+        &mut self,
+        samples: &[f32],
+        language: Option<&str>,
+    ) -> Result<TimestampedResult, WhisperError> {
+        let mut pieces: Vec<(String, f32)> = Vec::new();
+        let mut timestamps: Vec<f32> = Vec::new();
+        for (index, window) in samples.chunks(WINDOW_SAMPLES).enumerate() {
+            if window.len() < MIN_WINDOW_SAMPLES {
+                continue;
+            }
+            let decoded = self.decode_window(window, language)?;
+            let bytes: Vec<(Vec<u8>, f32)> = decoded
+                .iter()
+                .map(|&(id, prob)| {
+                    let bytes = self.tokens.get(id as usize).cloned().unwrap_or_default();
+                    (bytes, prob)
+                })
+                .collect();
+            let mut window_pieces = merge_byte_tokens(&bytes);
+            if let Some(first) = window_pieces.first_mut() {
+                // Separate consecutive windows by a word boundary.
+                if !pieces.is_empty() && !first.0.starts_with(' ') {
+                    first.0.insert(0, ' ');
+                }
+            }
+
+            let start = (index * WINDOW_SAMPLES) as f32 / SAMPLE_RATE as f32;
+            let duration = window.len() as f32 / SAMPLE_RATE as f32;
+            let step = duration / window_pieces.len().max(1) as f32;
+            timestamps.extend((0..window_pieces.len()).map(|i| start + i as f32 * step));
+            pieces.append(&mut window_pieces);
+        }
+
+        let text: String = pieces.iter().map(|(piece, _)| piece.as_str()).collect();
+        Ok(TimestampedResult {
+            text: text.trim().to_string(),
+            timestamps,
+            alternatives: vec![Vec::new(); pieces.len()],
+            probs: pieces.iter().map(|&(_, prob)| prob).collect(),
+            tokens: pieces.into_iter().map(|(piece, _)| piece).collect(),
+        })
+    }
+}
+
+impl TranscriptionEngine for WhisperEngine {
+    type InferenceParams = WhisperInferenceParams;
+    type ModelParams = WhisperModelParams;
+
+    fn load_model_with_params(
+        &mut self,
+        model_path: &Path,
+        params: Self::ModelParams,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let quantized = params.quantization == QuantizationType::Int8;
+        self.model = Some(WhisperModel::new(model_path, quantized, params.threads)?);
+        self.loaded_model_path = Some(model_path.to_path_buf());
+        Ok(())
+    }
+
+    fn transcribe_samples(
+        &mut self,
+        samples: Vec<f32>,
+        params: Option<Self::InferenceParams>,
+    ) -> Result<TranscriptionResult, Box<dyn std::error::Error>> {
+        let model = self
+            .model
+            .as_mut()
+            .ok_or("Model not loaded. Call load_model_with_params() first.")?;
+        let params = params.unwrap_or_default();
+
+        let timestamped_result = model.transcribe_samples(&samples, params.language.as_deref())?;
+        let segments = convert_timestamps(&timestamped_result, TimestampGranularity::Token);
+
+        Ok(TranscriptionResult {
+            word_confidences: word_confidences(&timestamped_result),
+            words: timed_words(&timestamped_result),
+            text: timestamped_result.text,
+            segments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slaney_mel_scale_round_trips() {
+        for hz in [0.0, 440.0, 1000.0, 4000.0, 8000.0] {
+            assert!((mel_to_hz(hz_to_mel(hz)) - hz).abs() < 0.01);
+        }
+        assert_eq!(hz_to_mel(1000.0), 15.0);
+    }
+
+    #[test]
+    fn mel_filterbank_has_one_triangle_per_band() {
+        let filters = mel_filterbank(80);
+        assert_eq!(filters.dim(), (80, N_FFT / 2 + 1));
+        for row in filters.rows() {
+            assert!(row.iter().any(|&w| w > 0.0));
+            assert!(row.iter().all(|&w| w >= 0.0));
+        }
+    }
+
+    #[test]
+    fn log_mel_pads_to_a_full_window() {
+        let filters = mel_filterbank(80);
+        let tone: Vec<f32> = (0..SAMPLE_RATE)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / SAMPLE_RATE as f32).sin())
+            .collect();
+        let mel = log_mel_spectrogram(&tone, &filters);
+        assert_eq!(mel.dim(), (1, 80, N_FRAMES));
+        let max = mel.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let min = mel.iter().copied().fold(f32::INFINITY, f32::min);
+        // Dynamic range is clamped to 8 (log10) before scaling by 1/4.
+        assert!(max - min <= 2.0 + 1e-5);
+    }
+
+    #[test]
+    fn split_utf8_characters_are_merged_across_tokens() {
+        let e_acute = "é".as_bytes();
+        let tokens = vec![
+            (b" caf".to_vec(), 0.9),
+            (vec![e_acute[0]], 0.8),
+            (vec![e_acute[1]], 0.6),
+            (b" noir".to_vec(), 0.95),
+        ];
+        let pieces = merge_byte_tokens(&tokens);
+        assert_eq!(
+            pieces,
+            vec![
+                (" caf".to_string(), 0.9),
+                ("é".to_string(), 0.6),
+                (" noir".to_string(), 0.95),
+            ]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use super::helpers::{parse_control_action, parse_llm_control_action};
+use super::server::error_response;
+use super::types::{AppStateView, ControlResponse, LlmModeView, MicrophoneView, TranscribeState};
+use crate::audio::types::AudioState;
+use crate::cli::types::CliCommand;
+use crate::shortcuts::types::{recording_state, RecordingSource};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::info;
+use tauri::{AppHandle, Manager};
+
+/// `GET /api/state`: recording status, active LLM mode, microphone and last
+/// transcript.
+pub async fn state_handler(State(state): State<TranscribeState>) -> Response {
+    let app = state.app.clone();
+    match tokio::task::spawn_blocking(move || app_state_view(&app)).await {
+        Ok(view) => (StatusCode::OK, Json(view)).into_response(),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("State task failed: {}", e),
+        ),
+    }
+}
+
+/// `POST /api/control/{action}`: the CLI action flags (`transcription`,
+/// `transcription-command`, `paste-last`, `cancel`, `pause`, `voice-mode`).
+pub async fn control_handler(
+    State(state): State<TranscribeState>,
+    Path(action): Path<String>,
+) -> Response {
+    match parse_control_action(&action) {
+        Some(command) => run_control(state, action, command).await,
+        None => error_response(
+            StatusCode::NOT_FOUND,
+            format!(
+                "Unknown action '{}'. Use transcription, transcription-command, paste-last, cancel, pause or voice-mode.",
+                action
+            ),
+        ),
+    }
+}
+
+/// `POST /api/control/llm-mode/{n}` and `/api/control/llm-transform/{n}`,
+/// with the same 1-based mode number as the CLI.
+pub async fn llm_control_handler(
+    State(state): State<TranscribeState>,
+    Path((action, n)): Path<(String, String)>,
+) -> Response {
+    match parse_llm_control_action(&action, &n) {
+        Ok(Some(command)) => run_control(state, format!("{}/{}", action, n), command).await,
+        Ok(None) => error_response(
+            StatusCode::NOT_FOUND,
+            format!(
+                "Unknown action '{}'. Use llm-mode or llm-transform.",
+                action
+            ),
+        ),
+        Err(e) => error_response(StatusCode::BAD_REQUEST, e),
+    }
+}
+
+/// Run `command` through `cli_dispatch`, like a hot CLI call, and answer with
+/// the state right after. Unlike the CLI, an unusable LLM mode is reported.
+async fn run_control(state: TranscribeState, action: String, command: CliCommand) -> Response {
+    info!("HTTP API control: {}", action);
+    let app = state.app.clone();
+    let joined = tokio::task::spawn_blocking(move || {
+        if let CliCommand::LlmMode(n) | CliCommand::LlmTransform(n) = command {
+            let index = (n as usize).saturating_sub(1);
+            if crate::shortcuts::shortcuts::ensure_llm_mode_ready(&app, index, true).is_err() {
+                return Err(format!(
+                    "LLM mode {} is not configured, or LLM Connect is disabled",
+                    n
+                ));
+            }
+        }
+        crate::shortcuts::cli_dispatch::dispatch(&app, &command);
+        Ok(app_state_view(&app))
+    })
+    .await;
+
+    match joined {
+        Ok(Ok(view)) => (
+            StatusCode::OK,
+            Json(ControlResponse {
+                action,
+                state: view,
+            }),
+        )
+            .into_response(),
+        Ok(Err(e)) => error_response(StatusCode::CONFLICT, e),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Control task failed: {}", e),
+        ),
+    }
+}
+
+fn app_state_view(app: &AppHandle) -> AppStateView {
+    let source = *recording_state().source.lock();
+    let settings = crate::settings::load_settings(app);
+    let llm_settings = crate::llm::helpers::load_llm_connect_settings(app);
+    let llm_mode = if crate::llm::helpers::is_llm_connect_enabled(app) {
+        llm_settings
+            .modes
+            .get(llm_settings.active_mode_index)
+            .map(|mode| LlmModeView {
+                index: llm_settings.active_mode_index + 1,
+                name: mode.name.clone(),
+            })
+    } else {
+        None
+    };
+
+    let paused = app
+        .state::<AudioState>()
+        .recorder
+        .lock()
+        .as_ref()
+        .is_some_and(|recorder| recorder.is_paused());
+
+    AppStateView {
+        recording: source != RecordingSource::None,
+        paused,
+        recording_source: recording_source_name(source),
+        llm_mode,
+        microphone: MicrophoneView {
+            id: settings.mic_id,
+            label: settings.mic_label,
+        },
+        last_transcript: crate::history::get_last_transcription(app).ok(),
+    }
+}
+
+fn recording_source_name(source: RecordingSource) -> &'static str {
+    match source {
+        RecordingSource::None => "none",
+        RecordingSource::Standard => "standard",
+        RecordingSource::Llm => "llm",
+        RecordingSource::Command => "command",
+    }
+}
@@ -0,0 +1,37 @@
+use super::types::{HttpApiState, TranscribeState};
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures_util::Stream;
+use serde::Serialize;
+use std::convert::Infallible;
+use tauri::{AppHandle, Manager};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Mirror a lifecycle event to `/api/events` subscribers. Called next to the
+/// matching Tauri emit, but independent of the overlay window, so the stream
+/// also works with the overlay hidden.
+pub fn publish_event<T: Serialize>(app: &AppHandle, name: &'static str, payload: &T) {
+    if let Some(state) = app.try_state::<HttpApiState>() {
+        state.publish_event(name, payload);
+    }
+}
+
+/// `GET /api/events`: Server-Sent Events stream of recording and
+/// transcription events, one JSON object per event.
+pub async fn events_handler(
+    State(state): State<TranscribeState>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let rx = state.app.state::<HttpApiState>().subscribe_events();
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        let event = match rx.recv().await {
+            Ok(event) => Event::default().event(event.name).data(event.data),
+            // The client fell behind the buffer: say how much it missed.
+            Err(RecvError::Lagged(skipped)) => Event::default()
+                .event("lagged")
+                .data(format!("{{\"skipped\":{}}}", skipped)),
+            Err(RecvError::Closed) => return None,
+        };
+        Some((Ok(event), rx))
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
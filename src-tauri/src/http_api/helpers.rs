@@ -0,0 +1,322 @@
+use super::types::ResponseFormat;
+use crate::audio::types::TranscribeOptions;
+use crate::cli::helpers::parse_llm_mode;
+use crate::cli::types::CliCommand;
+use crate::cli::ACTION_FLAGS;
+use crate::dictionary::MAX_PHRASE_WORDS;
+use crate::llm::types::SecretString;
+use std::net::{IpAddr, Ipv4Addr};
+
+const KEYRING_SERVICE: &str = "murmure";
+const KEYRING_API_TOKEN: &str = "http_api_token";
+
+/// Parse an OpenAI `response_format` value. Matching is exact, as in the
+/// OpenAI API, so a typo is reported instead of silently falling back to JSON.
+pub fn parse_response_format(value: &str) -> Option<ResponseFormat> {
+    match value {
+        "json" => Some(ResponseFormat::Json),
+        "text" => Some(ResponseFormat::Text),
+        "verbose_json" => Some(ResponseFormat::VerboseJson),
+        "srt" => Some(ResponseFormat::Srt),
+        "vtt" => Some(ResponseFormat::Vtt),
+        _ => None,
+    }
+}
+
+pub fn store_api_token(token: &str) -> Result<(), String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_API_TOKEN)
+        .map_err(|e| format!("Failed to access keyring: {}", e))?;
+    if token.is_empty() {
+        let _ = entry.delete_credential();
+        Ok(())
+    } else {
+        entry
+            .set_password(token)
+            .map_err(|e| format!("Failed to store API token: {}", e))
+    }
+}
+
+pub fn load_api_token() -> Option<SecretString> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_API_TOKEN).ok()?;
+    entry
+        .get_password()
+        .ok()
+        .filter(|t| !t.is_empty())
+        .map(SecretString::new)
+}
+
+pub fn has_api_token() -> bool {
+    load_api_token().is_some()
+}
+
+/// A new random token (two v4 UUIDs, 244 random bits).
+pub fn generate_api_token() -> String {
+    format!(
+        "mrm_{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// Address the HTTP API listens on: `127.0.0.1` unless another one is set.
+pub fn resolve_bind_address(configured: Option<&str>) -> Result<IpAddr, String> {
+    match configured.map(str::trim) {
+        Some(addr) if !addr.is_empty() => addr
+            .parse()
+            .map_err(|e| format!("Invalid bind address '{}': {}", addr, e)),
+        _ => Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+    }
+}
+
+/// True when the `Authorization` header carries `Bearer <token>`.
+pub fn bearer_token_matches(authorization: Option<&str>, token: &str) -> bool {
+    let Some((scheme, credentials)) = authorization.and_then(|h| h.trim().split_once(' ')) else {
+        return false;
+    };
+    scheme.eq_ignore_ascii_case("bearer")
+        && constant_time_eq(credentials.trim().as_bytes(), token.as_bytes())
+}
+
+/// True when the query string carries `token=<token>`. Only `/api/events`
+/// accepts it, because a browser `EventSource` cannot set headers. Tokens are
+/// URL-safe, so the value is compared as is.
+pub fn query_token_matches(query: Option<&str>, token: &str) -> bool {
+    query
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .filter_map(|pair| pair.strip_prefix("token="))
+        .any(|value| constant_time_eq(value.as_bytes(), token.as_bytes()))
+}
+
+/// Compare without returning early, so response time does not leak how many
+/// leading bytes of a guessed token are right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Map a `/api/control/{action}` route to the CLI command it mirrors. Action
+/// names are the CLI flags without their leading `--`.
+pub fn parse_control_action(action: &str) -> Option<CliCommand> {
+    ACTION_FLAGS
+        .iter()
+        .find(|(flag, _)| *flag == action)
+        .map(|(_, command)| command.clone())
+}
+
+/// Map `/api/control/{action}/{n}` to `--llm-mode n` or `--llm-transform n`.
+/// `Ok(None)` means the action is unknown.
+pub fn parse_llm_control_action(action: &str, n: &str) -> Result<Option<CliCommand>, String> {
+    let command: fn(u8) -> CliCommand = match action {
+        "llm-mode" => CliCommand::LlmMode,
+        "llm-transform" => CliCommand::LlmTransform,
+        _ => return Ok(None),
+    };
+    parse_llm_mode(n)
+        .map(|n| Some(command(n)))
+        .map_err(|_| format!("Invalid LLM mode '{}'. Must be 1, 2, 3, or 4.", n))
+}
+
+/// Apply one multipart field of `/api/transcribe` or `/api/jobs` to
+/// `options`. `Ok(false)` means the field is not a pipeline option.
+/// `boost_words` may be repeated, each value a comma-separated list.
+pub fn apply_transcribe_option(
+    options: &mut TranscribeOptions,
+    name: &str,
+    value: &str,
+) -> Result<bool, String> {
+    let value = value.trim();
+    match name {
+        "boost_words" => options.boost_words.extend(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|word| !word.is_empty())
+                .map(str::to_string),
+        ),
+        "llm_mode" => {
+            options.llm_mode = Some(
+                parse_llm_mode(value)
+                    .map_err(|_| format!("Invalid llm_mode '{}'. Must be 1, 2, 3, or 4.", value))?,
+            )
+        }
+        "fillers" => options.fillers = parse_bool_field(name, value)?,
+        "formatting" => options.formatting = parse_bool_field(name, value)?,
+        "history" => options.history = parse_bool_field(name, value)?,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Boost words taken from an OpenAI `prompt`. Whisper reads the prompt as
+/// preceding text, which mostly serves to spell names and jargon, so the
+/// comma, semicolon, period or line separated terms that could be dictionary
+/// entries become boost words. Prose longer than an entry is dropped.
+pub fn prompt_boost_words(prompt: &str) -> Vec<String> {
+    prompt
+        .split([',', ';', '.', '\n'])
+        .map(str::trim)
+        .filter(|term| {
+            !term.is_empty()
+                && !term.chars().any(|c| c.is_ascii_digit())
+                && term.split_whitespace().count() <= MAX_PHRASE_WORDS
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_bool_field(name: &str, value: &str) -> Result<bool, String> {
+    match value {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        _ => Err(format!("Invalid {} '{}'. Use true or false.", name, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_response_format_accepts_openai_values() {
+        assert_eq!(parse_response_format("json"), Some(ResponseFormat::Json));
+        assert_eq!(parse_response_format("text"), Some(ResponseFormat::Text));
+        assert_eq!(
+            parse_response_format("verbose_json"),
+            Some(ResponseFormat::VerboseJson)
+        );
+        assert_eq!(parse_response_format("srt"), Some(ResponseFormat::Srt));
+        assert_eq!(parse_response_format("vtt"), Some(ResponseFormat::Vtt));
+    }
+
+    #[test]
+    fn parse_response_format_rejects_unknown_values() {
+        assert_eq!(parse_response_format("JSON"), None);
+        assert_eq!(parse_response_format("xml"), None);
+        assert_eq!(parse_response_format(""), None);
+    }
+
+    #[test]
+    fn resolve_bind_address_defaults_to_loopback() {
+        let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        assert_eq!(resolve_bind_address(None), Ok(localhost));
+        assert_eq!(resolve_bind_address(Some("  ")), Ok(localhost));
+        assert_eq!(
+            resolve_bind_address(Some("0.0.0.0")),
+            Ok(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+        );
+        assert!(resolve_bind_address(Some("::1")).unwrap().is_loopback());
+        assert!(resolve_bind_address(Some("my-host")).is_err());
+    }
+
+    #[test]
+    fn bearer_token_matches_only_the_exact_token() {
+        assert!(bearer_token_matches(Some("Bearer secret"), "secret"));
+        assert!(bearer_token_matches(Some("bearer  secret "), "secret"));
+        assert!(!bearer_token_matches(Some("Bearer secre"), "secret"));
+        assert!(!bearer_token_matches(Some("Bearer secrets"), "secret"));
+        assert!(!bearer_token_matches(Some("Basic secret"), "secret"));
+        assert!(!bearer_token_matches(Some("secret"), "secret"));
+        assert!(!bearer_token_matches(None, "secret"));
+    }
+
+    #[test]
+    fn query_token_matches_only_the_token_parameter() {
+        assert!(query_token_matches(Some("token=secret"), "secret"));
+        assert!(query_token_matches(Some("lang=fr&token=secret"), "secret"));
+        assert!(!query_token_matches(Some("token=secre"), "secret"));
+        assert!(!query_token_matches(Some("api_token=secret"), "secret"));
+        assert!(!query_token_matches(Some("secret"), "secret"));
+        assert!(!query_token_matches(None, "secret"));
+    }
+
+    #[test]
+    fn generate_api_token_is_unique() {
+        let token = generate_api_token();
+        assert_eq!(token.len(), 68);
+        assert_ne!(token, generate_api_token());
+    }
+
+    #[test]
+    fn parse_control_action_mirrors_cli_flags() {
+        assert_eq!(
+            parse_control_action("transcription"),
+            Some(CliCommand::Transcription)
+        );
+        assert_eq!(
+            parse_control_action("transcription-command"),
+            Some(CliCommand::TranscriptionCommand)
+        );
+        assert_eq!(
+            parse_control_action("paste-last"),
+            Some(CliCommand::PasteLast)
+        );
+        assert_eq!(parse_control_action("cancel"), Some(CliCommand::Cancel));
+        assert_eq!(parse_control_action("pause"), Some(CliCommand::Pause));
+        assert_eq!(
+            parse_control_action("voice-mode"),
+            Some(CliCommand::VoiceMode)
+        );
+        assert_eq!(parse_control_action("--cancel"), None);
+        assert_eq!(parse_control_action("import"), None);
+    }
+
+    #[test]
+    fn parse_llm_control_action_validates_the_mode() {
+        assert_eq!(
+            parse_llm_control_action("llm-mode", "2"),
+            Ok(Some(CliCommand::LlmMode(2)))
+        );
+        assert_eq!(
+            parse_llm_control_action("llm-transform", "4"),
+            Ok(Some(CliCommand::LlmTransform(4)))
+        );
+        assert!(parse_llm_control_action("llm-mode", "5").is_err());
+        assert!(parse_llm_control_action("llm-mode", "0").is_err());
+        assert_eq!(parse_llm_control_action("cancel", "1"), Ok(None));
+    }
+
+    #[test]
+    fn apply_transcribe_option_parses_pipeline_fields() {
+        let mut options = TranscribeOptions::default();
+        for (name, value) in [
+            ("boost_words", "Kubernetes, Murmure"),
+            ("boost_words", "Parakeet,"),
+            ("llm_mode", "3"),
+            ("fillers", "false"),
+            ("history", "1"),
+        ] {
+            assert_eq!(apply_transcribe_option(&mut options, name, value), Ok(true));
+        }
+        assert_eq!(options.boost_words, ["Kubernetes", "Murmure", "Parakeet"]);
+        assert_eq!(options.llm_mode, Some(3));
+        assert!(!options.fillers && options.formatting && options.history);
+
+        assert_eq!(
+            apply_transcribe_option(&mut options, "audio", "x"),
+            Ok(false)
+        );
+        assert!(apply_transcribe_option(&mut options, "llm_mode", "5").is_err());
+        assert!(apply_transcribe_option(&mut options, "formatting", "no").is_err());
+    }
+
+    #[test]
+    fn prompt_boost_words_keeps_glossary_terms() {
+        assert_eq!(
+            prompt_boost_words("Kubernetes, Murmure; left anterior descending artery.\nNestJS"),
+            [
+                "Kubernetes",
+                "Murmure",
+                "left anterior descending artery",
+                "NestJS"
+            ]
+        );
+        assert_eq!(
+            prompt_boost_words("This is a meeting about the new release of Murmure, v2"),
+            Vec::<String>::new()
+        );
+        assert!(prompt_boost_words("  ").is_empty());
+    }
+}
@@ -0,0 +1,197 @@
+use super::helpers::apply_transcribe_option;
+use super::server::{
+    check_llm_mode, error_response, run_transcription, write_temp_audio, ErrorResponse,
+};
+use super::types::{Job, JobSlot, TempAudio, TranscribeState};
+use crate::audio::types::{ChunkProgress, TranscribeOptions};
+use axum::{
+    extract::{Multipart, Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::info;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// Suggested wait before resubmitting when the job queue is full.
+const QUEUE_FULL_RETRY_AFTER_SECS: u64 = 30;
+
+/// `POST /api/jobs`: queues the uploaded file and returns at once with the
+/// job id. Takes the same pipeline options as `/api/transcribe`. Jobs share
+/// `transcribe_lock` with the synchronous routes, so they run one at a time.
+pub async fn create_job_handler(
+    State(state): State<TranscribeState>,
+    mut multipart: Multipart,
+) -> Response {
+    let mut audio_bytes = None;
+    let mut options = TranscribeOptions::default();
+
+    loop {
+        match multipart.next_field().await {
+            Ok(Some(field)) if field.name() == Some("audio") => match field.bytes().await {
+                Ok(b) => audio_bytes = Some(b),
+                Err(e) => {
+                    return error_response(
+                        StatusCode::BAD_REQUEST,
+                        format!("Failed to read audio file: {}", e),
+                    )
+                }
+            },
+            Ok(Some(field)) => {
+                let name = field.name().unwrap_or_default().to_string();
+                let value = field.text().await.unwrap_or_default();
+                if let Err(e) = apply_transcribe_option(&mut options, &name, &value) {
+                    return error_response(StatusCode::BAD_REQUEST, e);
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                return error_response(
+                    StatusCode::BAD_REQUEST,
+                    format!("Failed to parse multipart: {}", e),
+                )
+            }
+        }
+    }
+
+    let Some(bytes) = audio_bytes else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "No 'audio' field in multipart request".to_string(),
+        );
+    };
+
+    if let Err((status, error)) = check_llm_mode(&state.app, &options) {
+        return error_response(status, error);
+    }
+
+    let id = uuid::Uuid::new_v4();
+    let temp = match write_temp_audio(&id, bytes) {
+        Ok(temp) => temp,
+        Err((status, error)) => return error_response(status, error),
+    };
+
+    let job = Job::queued();
+    let cancelled = job.cancelled.clone();
+    let cancel_notify = job.cancel_notify.clone();
+    let progress = job.progress.clone();
+    let id = id.to_string();
+    let Some(slot) = state.jobs.try_insert(id.clone(), job) else {
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, QUEUE_FULL_RETRY_AFTER_SECS.to_string())],
+            Json(ErrorResponse {
+                error: "Job queue is full, retry later".to_string(),
+            }),
+        )
+            .into_response();
+    };
+
+    info!("HTTP API job {}: queued", &id[..8]);
+    let view = state.jobs.view(&id);
+    let job = JobTask {
+        id,
+        slot,
+        temp,
+        cancelled,
+        cancel_notify,
+        progress,
+        options,
+    };
+    tokio::spawn(run_job(state, job));
+
+    match view {
+        Some(view) => (StatusCode::ACCEPTED, Json(view)).into_response(),
+        None => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Job disappeared after creation".to_string(),
+        ),
+    }
+}
+
+/// `GET /api/jobs/{id}`: status, percent progress and, once completed, the
+/// timestamped transcript.
+pub async fn get_job_handler(
+    State(state): State<TranscribeState>,
+    Path(id): Path<String>,
+) -> Response {
+    match state.jobs.view(&id) {
+        Some(view) => (StatusCode::OK, Json(view)).into_response(),
+        None => job_not_found(),
+    }
+}
+
+/// `DELETE /api/jobs/{id}`: cancels a queued or running job, or forgets a
+/// finished one.
+pub async fn delete_job_handler(
+    State(state): State<TranscribeState>,
+    Path(id): Path<String>,
+) -> Response {
+    match state.jobs.cancel_or_remove(&id) {
+        Some(view) => (StatusCode::OK, Json(view)).into_response(),
+        None => job_not_found(),
+    }
+}
+
+fn job_not_found() -> Response {
+    error_response(StatusCode::NOT_FOUND, "Job not found".to_string())
+}
+
+/// What the task of a job owns until it exits.
+struct JobTask {
+    id: String,
+    slot: JobSlot,
+    temp: TempAudio,
+    cancelled: Arc<AtomicBool>,
+    cancel_notify: Arc<Notify>,
+    progress: Arc<ChunkProgress>,
+    options: TranscribeOptions,
+}
+
+async fn run_job(state: TranscribeState, job: JobTask) {
+    let JobTask {
+        id,
+        slot,
+        temp,
+        cancelled,
+        cancel_notify,
+        progress,
+        options,
+    } = job;
+    // A job cancelled while queued exits at once, deleting its upload and
+    // freeing its slot, instead of waiting for its turn.
+    let transcribe_guard = tokio::select! {
+        guard = state.transcribe_lock.clone().lock_owned() => guard,
+        _ = cancel_notify.notified() => {
+            info!("HTTP API job {}: cancelled before start", &id[..8]);
+            return;
+        }
+    };
+    if cancelled.load(Ordering::SeqCst) {
+        info!("HTTP API job {}: cancelled before start", &id[..8]);
+        return;
+    }
+
+    state.jobs.start(&id);
+    info!("HTTP API job {}: starting", &id[..8]);
+    let started = std::time::Instant::now();
+
+    let app = state.app.clone();
+    let joined = tokio::task::spawn_blocking(move || {
+        let _guard = transcribe_guard;
+        let _slot = slot;
+        let temp = temp;
+        run_transcription(&app, &temp.0, &cancelled, &progress, &options)
+    })
+    .await;
+
+    let outcome = joined.unwrap_or_else(|e| Err(format!("Transcription task failed: {}", e)));
+    info!(
+        "HTTP API job {}: finished in {} ms",
+        &id[..8],
+        started.elapsed().as_millis()
+    );
+    state.jobs.complete(&id, outcome);
+}
@@ -1,4 +1,10 @@
+pub mod control;
+pub mod events;
+pub mod helpers;
 pub mod http_api;
+pub mod jobs;
+pub mod openai;
+pub mod process;
 pub mod server;
 pub mod types;
 
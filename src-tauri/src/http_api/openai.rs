@@ -0,0 +1,140 @@
+use super::helpers::{parse_response_format, prompt_boost_words};
+use super::server::{subtitle_response, transcribe_bytes, TranscriptionResponse};
+use super::types::{
+    OpenAiError, OpenAiErrorResponse, OpenAiVerboseTranscription, ResponseFormat, TranscribeState,
+};
+use crate::audio::subtitles::SubtitleFormat;
+use crate::audio::types::TranscribeOptions;
+use crate::engine::types::AsrBackend;
+use crate::model::Model;
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+fn openai_error(status: StatusCode, message: String, param: Option<&'static str>) -> Response {
+    let kind = if status.is_server_error() {
+        "server_error"
+    } else {
+        "invalid_request_error"
+    };
+    let body = OpenAiErrorResponse {
+        error: OpenAiError {
+            message,
+            kind,
+            param,
+            code: None,
+        },
+    };
+    (status, Json(body)).into_response()
+}
+
+/// Language for `verbose_json`: the one set for a Whisper model. Parakeet
+/// and Whisper's own detection do not report the language they heard.
+fn configured_language(app: &AppHandle) -> Option<String> {
+    let model = app.state::<Arc<Model>>().active_model().ok()?;
+    if model.backend != AsrBackend::Whisper {
+        return None;
+    }
+    crate::settings::load_settings(app).whisper_language
+}
+
+/// `POST /v1/audio/transcriptions`, the OpenAI audio API shape, so any
+/// OpenAI-style client can target Murmure by changing its base URL.
+///
+/// The terms of `prompt` are boosted like `boost_words` (see
+/// `prompt_boost_words`). `model`, `language` and `temperature` are accepted
+/// and ignored: Murmure runs its active local model with its own settings.
+pub async fn transcriptions_handler(
+    State(state): State<TranscribeState>,
+    mut multipart: Multipart,
+) -> Response {
+    let mut file = None;
+    let mut response_format = ResponseFormat::Json;
+    let mut options = TranscribeOptions::default();
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => {
+                return openai_error(
+                    StatusCode::BAD_REQUEST,
+                    format!("Failed to parse multipart: {}", e),
+                    None,
+                )
+            }
+        };
+        let name = field.name().unwrap_or_default().to_string();
+        match name.as_str() {
+            "file" => match field.bytes().await {
+                Ok(bytes) => file = Some(bytes),
+                Err(e) => {
+                    return openai_error(
+                        StatusCode::BAD_REQUEST,
+                        format!("Failed to read audio file: {}", e),
+                        Some("file"),
+                    )
+                }
+            },
+            "response_format" => {
+                let value = field.text().await.unwrap_or_default();
+                match parse_response_format(value.trim()) {
+                    Some(format) => response_format = format,
+                    None => {
+                        return openai_error(
+                            StatusCode::BAD_REQUEST,
+                            format!(
+                                "Invalid response_format '{}'. Use json, text, verbose_json, srt or vtt.",
+                                value
+                            ),
+                            Some("response_format"),
+                        )
+                    }
+                }
+            }
+            "prompt" => {
+                let value = field.text().await.unwrap_or_default();
+                options.boost_words.extend(prompt_boost_words(&value));
+            }
+            _ => {}
+        }
+    }
+
+    let Some(bytes) = file else {
+        return openai_error(
+            StatusCode::BAD_REQUEST,
+            "No 'file' field in multipart request".to_string(),
+            Some("file"),
+        );
+    };
+
+    match transcribe_bytes(&state, bytes, options).await {
+        Ok(transcript) => match response_format {
+            ResponseFormat::Text => (StatusCode::OK, transcript.text).into_response(),
+            ResponseFormat::VerboseJson => {
+                let body = OpenAiVerboseTranscription {
+                    task: "transcribe",
+                    language: configured_language(&state.app),
+                    transcript,
+                };
+                (StatusCode::OK, Json(body)).into_response()
+            }
+            ResponseFormat::Srt => subtitle_response(&transcript, SubtitleFormat::Srt),
+            ResponseFormat::Vtt => subtitle_response(&transcript, SubtitleFormat::Vtt),
+            ResponseFormat::Json => (
+                StatusCode::OK,
+                Json(TranscriptionResponse {
+                    text: transcript.text,
+                    llm_error: None,
+                }),
+            )
+                .into_response(),
+        },
+        Err((status, message)) => openai_error(status, message, None),
+    }
+}
@@ -0,0 +1,58 @@
+use super::server::error_response;
+use super::types::{ProcessRequest, ProcessResponse, TranscribeState};
+use axum::{
+    extract::{rejection::JsonRejection, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use log::info;
+
+/// `POST /api/process`: runs the dictation post-processing stages (fillers,
+/// dictionary, LLM mode, formatting rules) on the JSON `text`, without audio.
+pub async fn process_handler(
+    State(state): State<TranscribeState>,
+    body: Result<Json<ProcessRequest>, JsonRejection>,
+) -> Response {
+    let request = match body {
+        Ok(Json(request)) => request,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, e.body_text()),
+    };
+    if request.text.trim().is_empty() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "No 'text' field in request".to_string(),
+        );
+    }
+    if let Some(n) = request.llm_mode {
+        if !(1..=4).contains(&n) {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid LLM mode '{}'. Must be 1, 2, 3, or 4.", n),
+            );
+        }
+    }
+
+    info!("HTTP API: processing {} chars of text", request.text.len());
+    let app = state.app.clone();
+    let joined = tokio::task::spawn_blocking(move || {
+        crate::audio::pipeline::process_text(&app, &request.text, &request.options())
+    })
+    .await;
+
+    match joined {
+        Ok(Ok(result)) => (
+            StatusCode::OK,
+            Json(ProcessResponse {
+                text: result.text,
+                llm_error: result.llm_error,
+            }),
+        )
+            .into_response(),
+        Ok(Err(e)) => error_response(StatusCode::CONFLICT, e),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Processing task failed: {}", e),
+        ),
+    }
+}
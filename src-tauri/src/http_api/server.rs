@@ -1,22 +1,37 @@
-use super::types::{CancelOnDrop, HttpApiState, TempWav, TranscribeState};
+use super::helpers::{
+    apply_transcribe_option, bearer_token_matches, load_api_token, parse_response_format,
+    query_token_matches, resolve_bind_address,
+};
+use super::types::{
+    CancelOnDrop, HttpApiState, JobStore, ResponseFormat, TempAudio, TranscribeState,
+};
 use crate::audio;
+use crate::audio::subtitles::{render_subtitles, SubtitleFormat};
+use crate::audio::timestamps::TimedTranscript;
+use crate::audio::types::{ChunkProgress, TranscribeOptions};
 use anyhow::Result;
 use axum::{
-    extract::{DefaultBodyLimit, Multipart},
-    http::StatusCode,
+    extract::{DefaultBodyLimit, Multipart, Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
     response::IntoResponse,
-    routing::post,
+    routing::{get, post},
     Json, Router,
 };
+use axum_server::tls_rustls::RustlsConfig;
 use log::info;
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+const EVENTS_PATH: &str = "/api/events";
+
 #[derive(Serialize, Deserialize)]
 pub struct TranscriptionResponse {
     pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm_error: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -24,52 +39,214 @@ pub struct ErrorResponse {
     pub error: String,
 }
 
-fn error_response(status: StatusCode, error: String) -> axum::response::Response {
+pub(super) fn error_response(status: StatusCode, error: String) -> axum::response::Response {
     (status, Json(ErrorResponse { error })).into_response()
 }
 
+pub(super) fn subtitle_response(
+    transcript: &TimedTranscript,
+    format: SubtitleFormat,
+) -> axum::response::Response {
+    let content_type = match format {
+        SubtitleFormat::Srt => "application/x-subrip; charset=utf-8",
+        SubtitleFormat::Vtt => "text/vtt; charset=utf-8",
+    };
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, content_type)],
+        render_subtitles(&transcript.words, format),
+    )
+        .into_response()
+}
+
 pub async fn start_http_api(
     app: tauri::AppHandle,
     port: u16,
     api_state: HttpApiState,
 ) -> Result<()> {
+    let settings = crate::settings::load_settings(&app);
+    let ip =
+        resolve_bind_address(settings.api_bind_address.as_deref()).map_err(anyhow::Error::msg)?;
+    api_state.set_token(load_api_token());
+    if !ip.is_loopback() && api_state.token().is_none() {
+        return Err(anyhow::anyhow!(
+            "An API token is required to listen on {}. Generate one in Settings → System → API token.",
+            ip
+        ));
+    }
+
+    let rustls_config = if settings.api_tls_enabled {
+        let (cert_path, key_path) = crate::smartmic::cert::ensure_cert(&app)?;
+        Some(RustlsConfig::from_pem_file(&cert_path, &key_path).await?)
+    } else {
+        None
+    };
+
     let state = TranscribeState {
         app: Arc::new(app),
         transcribe_lock: Arc::new(tokio::sync::Mutex::new(())),
+        jobs: Arc::new(JobStore::default()),
     };
 
+    let control_routes = Router::new()
+        .route(
+            "/api/control/{action}",
+            post(super::control::control_handler),
+        )
+        .route(
+            "/api/control/{action}/{n}",
+            post(super::control::llm_control_handler),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            api_state.clone(),
+            reject_browser_control,
+        ));
+
+    let event_routes = Router::new()
+        .route(EVENTS_PATH, get(super::events::events_handler))
+        .route_layer(middleware::from_fn_with_state(
+            api_state.clone(),
+            allow_event_stream_origin,
+        ));
+
     let router = Router::new()
         .route("/api/transcribe", post(transcribe_handler))
+        .route(
+            "/v1/audio/transcriptions",
+            post(super::openai::transcriptions_handler),
+        )
+        .route("/api/jobs", post(super::jobs::create_job_handler))
+        .route(
+            "/api/jobs/{id}",
+            get(super::jobs::get_job_handler).delete(super::jobs::delete_job_handler),
+        )
+        .route("/api/process", post(super::process::process_handler))
+        .route("/api/state", get(super::control::state_handler))
+        .merge(event_routes)
+        .merge(control_routes)
         .with_state(state)
-        .layer(DefaultBodyLimit::max(100_000_000));
+        .layer(DefaultBodyLimit::max(100_000_000))
+        .layer(middleware::from_fn_with_state(
+            api_state.clone(),
+            require_bearer_token,
+        ));
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let addr = SocketAddr::from((ip, port));
     let listener = tokio::net::TcpListener::bind(&addr).await?;
 
-    info!("HTTP API listening on http://{}", addr);
+    let scheme = if rustls_config.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+    info!("HTTP API listening on {}://{}", scheme, addr);
 
     let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
     api_state.set_shutdown_sender(shutdown_tx);
 
-    let server = axum::serve(listener, router);
+    match rustls_config {
+        Some(rustls_config) => {
+            let server = axum_server::from_tcp_rustls(listener.into_std()?, rustls_config)
+                .serve(router.into_make_service());
 
-    tokio::select! {
-        _ = server => {
-            info!("HTTP API server ended normally");
+            tokio::select! {
+                result = server => {
+                    result?;
+                    info!("HTTP API server ended normally");
+                }
+                _ = shutdown_rx => {
+                    info!("HTTP API server shutdown signal received");
+                }
+            }
         }
-        _ = shutdown_rx => {
-            info!("HTTP API server shutdown signal received");
+        None => {
+            let server = axum::serve(listener, router);
+
+            tokio::select! {
+                _ = server => {
+                    info!("HTTP API server ended normally");
+                }
+                _ = shutdown_rx => {
+                    info!("HTTP API server shutdown signal received");
+                }
+            }
         }
     }
 
     Ok(())
 }
 
+/// Reject requests without the configured bearer token. The token is read
+/// from `HttpApiState` on each request, so generating or revoking one takes
+/// effect at once. `/api/events` also takes it as `?token=`, for `EventSource`.
+async fn require_bearer_token(
+    State(api_state): State<HttpApiState>,
+    request: Request,
+    next: Next,
+) -> axum::response::Response {
+    if let Some(token) = api_state.token() {
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+        let query_token = request.uri().path() == EVENTS_PATH
+            && query_token_matches(request.uri().query(), token.expose());
+        if !query_token && !bearer_token_matches(authorization, token.expose()) {
+            return (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, "Bearer")],
+                Json(ErrorResponse {
+                    error: "Missing or invalid API token".to_string(),
+                }),
+            )
+                .into_response();
+        }
+    }
+    next.run(request).await
+}
+
+/// Without a token, any web page can make the browser send a simple POST to
+/// the loopback API. Browsers always add `Origin` to those cross-origin
+/// requests, so control actions refuse it unless a token was checked.
+async fn reject_browser_control(
+    State(api_state): State<HttpApiState>,
+    request: Request,
+    next: Next,
+) -> axum::response::Response {
+    if api_state.token().is_none() && request.headers().contains_key(header::ORIGIN) {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            "Control actions from a web page require an API token".to_string(),
+        );
+    }
+    next.run(request).await
+}
+
+/// Let web pages read the event stream, so a browser overlay can show live
+/// captions. Only with a token: without one, any page visited could read what
+/// is dictated.
+async fn allow_event_stream_origin(
+    State(api_state): State<HttpApiState>,
+    request: Request,
+    next: Next,
+) -> axum::response::Response {
+    let mut response = next.run(request).await;
+    if api_state.token().is_some() {
+        response.headers_mut().insert(
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            header::HeaderValue::from_static("*"),
+        );
+    }
+    response
+}
+
 async fn transcribe_handler(
     axum::extract::State(state): axum::extract::State<TranscribeState>,
     mut multipart: Multipart,
 ) -> impl IntoResponse {
     let mut audio_bytes = None;
+    let mut response_format = ResponseFormat::Json;
+    let mut options = TranscribeOptions::default();
 
     loop {
         match multipart.next_field().await {
@@ -82,7 +259,28 @@ async fn transcribe_handler(
                     )
                 }
             },
-            Ok(Some(_)) => {}
+            Ok(Some(field)) if field.name() == Some("response_format") => {
+                let value = field.text().await.unwrap_or_default();
+                match parse_response_format(value.trim()) {
+                    Some(format) => response_format = format,
+                    None => {
+                        return error_response(
+                            StatusCode::BAD_REQUEST,
+                            format!(
+                                "Invalid response_format '{}'. Use json, text, verbose_json, srt or vtt.",
+                                value
+                            ),
+                        )
+                    }
+                }
+            }
+            Ok(Some(field)) => {
+                let name = field.name().unwrap_or_default().to_string();
+                let value = field.text().await.unwrap_or_default();
+                if let Err(e) = apply_transcribe_option(&mut options, &name, &value) {
+                    return error_response(StatusCode::BAD_REQUEST, e);
+                }
+            }
             Ok(None) => break,
             Err(e) => {
                 return error_response(
@@ -94,7 +292,23 @@ async fn transcribe_handler(
     }
 
     match audio_bytes {
-        Some(bytes) => transcribe_bytes(&state, bytes).await,
+        Some(bytes) => match transcribe_bytes(&state, bytes, options).await {
+            Ok(transcript) => match response_format {
+                ResponseFormat::Text => (StatusCode::OK, transcript.text).into_response(),
+                ResponseFormat::VerboseJson => (StatusCode::OK, Json(transcript)).into_response(),
+                ResponseFormat::Srt => subtitle_response(&transcript, SubtitleFormat::Srt),
+                ResponseFormat::Vtt => subtitle_response(&transcript, SubtitleFormat::Vtt),
+                ResponseFormat::Json => (
+                    StatusCode::OK,
+                    Json(TranscriptionResponse {
+                        text: transcript.text,
+                        llm_error: transcript.llm_error,
+                    }),
+                )
+                    .into_response(),
+            },
+            Err((status, error)) => error_response(status, error),
+        },
         None => error_response(
             StatusCode::BAD_REQUEST,
             "No 'audio' field in multipart request".to_string(),
@@ -102,22 +316,70 @@ async fn transcribe_handler(
     }
 }
 
-async fn transcribe_bytes(
+/// Writes an uploaded body to a temp file, removed when the guard drops.
+pub(super) fn write_temp_audio(
+    id: &uuid::Uuid,
+    bytes: axum::body::Bytes,
+) -> Result<TempAudio, (StatusCode, String)> {
+    // No extension: the decoder sniffs the container from the content.
+    let temp = TempAudio(std::env::temp_dir().join(format!("murmure-{}", id)));
+    std::fs::write(&temp.0, bytes).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to write audio file: {}", e),
+        )
+    })?;
+    Ok(temp)
+}
+
+/// Blocking part of a transcription, shared by the synchronous routes and the
+/// jobs. Must run under `transcribe_lock`.
+pub(super) fn run_transcription(
+    app: &tauri::AppHandle,
+    path: &std::path::Path,
+    cancelled: &Arc<AtomicBool>,
+    progress: &Arc<ChunkProgress>,
+    options: &TranscribeOptions,
+) -> Result<Option<TimedTranscript>, String> {
+    audio::preload_engine(app).map_err(|e| format!("Model not available: {}", e))?;
+    audio::transcribe_file_with_progress(app, path, cancelled, progress, options)
+        .map_err(|e| format!("Transcription failed: {}", e))
+}
+
+/// Reject a requested LLM mode that cannot run before any audio is
+/// transcribed, rather than failing once the work is done.
+pub(super) fn check_llm_mode(
+    app: &tauri::AppHandle,
+    options: &TranscribeOptions,
+) -> Result<(), (StatusCode, String)> {
+    let Some(n) = options.llm_mode else {
+        return Ok(());
+    };
+    let index = (n as usize).saturating_sub(1);
+    crate::shortcuts::shortcuts::ensure_llm_mode_ready(app, index, false).map_err(|_| {
+        (
+            StatusCode::CONFLICT,
+            format!(
+                "LLM mode {} is not configured, or LLM Connect is disabled",
+                n
+            ),
+        )
+    })
+}
+
+/// Runs the chunked file pipeline on an uploaded audio body. Shared by every
+/// transcription route; each one only differs in how it shapes the response.
+pub(super) async fn transcribe_bytes(
     state: &TranscribeState,
     bytes: axum::body::Bytes,
-) -> axum::response::Response {
+    options: TranscribeOptions,
+) -> Result<TimedTranscript, (StatusCode, String)> {
+    check_llm_mode(&state.app, &options)?;
     let id = uuid::Uuid::new_v4();
-    let temp = TempWav(std::env::temp_dir().join(format!("murmure-{}.wav", id)));
+    let temp = write_temp_audio(&id, bytes)?;
     let mut short_id = id.to_string();
     short_id.truncate(8);
 
-    if let Err(e) = std::fs::write(&temp.0, bytes) {
-        return error_response(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Failed to write audio file: {}", e),
-        );
-    }
-
     let transcribe_guard = state.transcribe_lock.clone().lock_owned().await;
 
     let cancelled = Arc::new(AtomicBool::new(false));
@@ -140,9 +402,7 @@ async fn transcribe_bytes(
             );
             return Ok(None);
         }
-        audio::preload_engine(&app).map_err(|e| format!("Model not available: {}", e))?;
-        let result = audio::transcribe_file_chunked_cancellable(&app, &temp.0, &cancelled)
-            .map_err(|e| format!("Transcription failed: {}", e))?;
+        let result = run_transcription(&app, &temp.0, &cancelled, &Arc::default(), &options)?;
         if result.is_none() {
             info!(
                 "HTTP API transcription {}: cancelled by client disconnect",
@@ -154,22 +414,22 @@ async fn transcribe_bytes(
     .await;
 
     match joined {
-        Ok(Ok(Some(text))) => {
+        Ok(Ok(Some(transcript))) => {
             info!(
                 "HTTP API transcription {}: done in {} ms",
                 short_id,
                 started.elapsed().as_millis()
             );
-            (StatusCode::OK, Json(TranscriptionResponse { text })).into_response()
+            Ok(transcript)
         }
-        Ok(Ok(None)) => error_response(
+        Ok(Ok(None)) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             "Transcription cancelled".to_string(),
-        ),
-        Ok(Err(e)) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e),
-        Err(e) => error_response(
+        )),
+        Ok(Err(e)) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+        Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             format!("Transcription task failed: {}", e),
-        ),
+        )),
     }
 }
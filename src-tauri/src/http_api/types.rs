@@ -1,13 +1,28 @@
+use crate::audio::timestamps::TimedTranscript;
+use crate::audio::types::{ChunkProgress, ProcessOptions};
+use crate::llm::types::SecretString;
 use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use tokio::sync::oneshot;
+use std::time::Instant;
+use tokio::sync::{broadcast, oneshot, Notify};
+
+/// Job tasks alive at once, cancelled ones included until they exit;
+/// further submissions get a 429.
+const MAX_ACTIVE_JOBS: usize = 8;
+/// Finished jobs kept for polling; the oldest are dropped past this.
+const MAX_FINISHED_JOBS: usize = 32;
+/// Events buffered per `/api/events` subscriber; a slower one skips ahead.
+const EVENT_BUFFER: usize = 256;
 
 #[derive(Clone)]
 pub struct TranscribeState {
     pub app: Arc<tauri::AppHandle>,
     pub transcribe_lock: Arc<tokio::sync::Mutex<()>>,
+    pub jobs: Arc<JobStore>,
 }
 
 pub(super) struct CancelOnDrop(pub(super) Arc<AtomicBool>);
@@ -18,9 +33,9 @@ impl Drop for CancelOnDrop {
     }
 }
 
-pub(super) struct TempWav(pub(super) PathBuf);
+pub(super) struct TempAudio(pub(super) PathBuf);
 
-impl Drop for TempWav {
+impl Drop for TempAudio {
     fn drop(&mut self) {
         if let Err(e) = std::fs::remove_file(&self.0) {
             warn!("HTTP API: failed to remove temp audio file: {}", e);
@@ -28,23 +43,63 @@ impl Drop for TempWav {
     }
 }
 
+/// A lifecycle event for `/api/events`: SSE event name and JSON data.
+#[derive(Debug, Clone)]
+pub struct ApiEvent {
+    pub name: &'static str,
+    pub data: String,
+}
+
 #[derive(Clone)]
 pub struct HttpApiState {
     shutdown_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    /// Bearer token required on every request; `None` leaves the API open.
+    /// Kept here so a new or revoked token applies without a restart.
+    token: Arc<Mutex<Option<SecretString>>>,
+    events: broadcast::Sender<ApiEvent>,
 }
 
 impl HttpApiState {
     pub fn new() -> Self {
         Self {
             shutdown_tx: Arc::new(Mutex::new(None)),
+            token: Arc::new(Mutex::new(None)),
+            events: broadcast::channel(EVENT_BUFFER).0,
         }
     }
 
+    pub fn set_token(&self, token: Option<SecretString>) {
+        let mut guard = self.token.lock().unwrap();
+        *guard = token;
+    }
+
+    pub fn token(&self) -> Option<SecretString> {
+        self.token.lock().unwrap().clone()
+    }
+
     pub fn set_shutdown_sender(&self, tx: oneshot::Sender<()>) {
         let mut guard = self.shutdown_tx.lock().unwrap();
         *guard = Some(tx);
     }
 
+    /// Send an event to the `/api/events` subscribers. Nothing is serialized
+    /// while nobody listens.
+    pub fn publish_event<T: Serialize>(&self, name: &'static str, payload: &T) {
+        if self.events.receiver_count() == 0 {
+            return;
+        }
+        match serde_json::to_string(payload) {
+            Ok(data) => {
+                let _ = self.events.send(ApiEvent { name, data });
+            }
+            Err(e) => warn!("HTTP API: failed to serialize {} event: {}", name, e),
+        }
+    }
+
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ApiEvent> {
+        self.events.subscribe()
+    }
+
     pub fn stop(&self) {
         let mut guard = self.shutdown_tx.lock().unwrap();
         if let Some(tx) = guard.take() {
@@ -58,3 +113,426 @@ impl Default for HttpApiState {
         Self::new()
     }
 }
+
+/// `response_format` of the transcription routes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    Json,
+    Text,
+    VerboseJson,
+    Srt,
+    Vtt,
+}
+
+/// Error body in the shape the OpenAI SDKs parse (`error.message`).
+#[derive(Serialize)]
+pub struct OpenAiErrorResponse {
+    pub error: OpenAiError,
+}
+
+#[derive(Serialize)]
+pub struct OpenAiError {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub param: Option<&'static str>,
+    pub code: Option<&'static str>,
+}
+
+/// OpenAI `verbose_json` body. `language` is left out when it is not known.
+#[derive(Serialize)]
+pub struct OpenAiVerboseTranscription {
+    pub task: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(flatten)]
+    pub transcript: TimedTranscript,
+}
+
+/// Read-only snapshot of the running app, for `GET /api/state` and the
+/// control routes.
+#[derive(Serialize)]
+pub struct AppStateView {
+    pub recording: bool,
+    /// True while a recording is paused; `recording` stays true.
+    pub paused: bool,
+    /// `none`, `standard`, `llm` or `command`.
+    pub recording_source: &'static str,
+    /// `None` while LLM Connect is disabled.
+    pub llm_mode: Option<LlmModeView>,
+    pub microphone: MicrophoneView,
+    pub last_transcript: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct LlmModeView {
+    /// 1-based, as in `--llm-mode`.
+    pub index: usize,
+    pub name: String,
+}
+
+/// `id` is `None` when the system default input is used.
+#[derive(Serialize)]
+pub struct MicrophoneView {
+    pub id: Option<String>,
+    pub label: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ControlResponse {
+    pub action: String,
+    pub state: AppStateView,
+}
+
+/// Body of `POST /api/process`. Every stage runs unless turned off, except
+/// the LLM, which only runs when `llm_mode` (1-based) is given.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ProcessRequest {
+    pub text: String,
+    pub fillers: bool,
+    pub dictionary: bool,
+    pub llm_mode: Option<u8>,
+    pub formatting: bool,
+}
+
+impl Default for ProcessRequest {
+    fn default() -> Self {
+        let options = ProcessOptions::default();
+        Self {
+            text: String::new(),
+            fillers: options.fillers,
+            dictionary: options.dictionary,
+            llm_mode: options.llm_mode,
+            formatting: options.formatting,
+        }
+    }
+}
+
+impl ProcessRequest {
+    pub fn options(&self) -> ProcessOptions {
+        ProcessOptions {
+            fillers: self.fillers,
+            dictionary: self.dictionary,
+            llm_mode: self.llm_mode,
+            formatting: self.formatting,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ProcessResponse {
+    pub text: String,
+    /// Set when the LLM request failed; `text` then skips the LLM stage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub llm_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+}
+
+pub(super) struct Job {
+    pub(super) status: JobStatus,
+    pub(super) progress: Arc<ChunkProgress>,
+    pub(super) cancelled: Arc<AtomicBool>,
+    /// Wakes a queued task waiting for its turn when the job is cancelled.
+    pub(super) cancel_notify: Arc<Notify>,
+    pub(super) result: Option<TimedTranscript>,
+    pub(super) error: Option<String>,
+    pub(super) finished_at: Option<Instant>,
+}
+
+impl Job {
+    pub(super) fn queued() -> Self {
+        Self {
+            status: JobStatus::Queued,
+            progress: Arc::new(ChunkProgress::default()),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            cancel_notify: Arc::new(Notify::new()),
+            result: None,
+            error: None,
+            finished_at: None,
+        }
+    }
+
+    fn finish(&mut self, status: JobStatus) {
+        self.status = status;
+        self.finished_at = Some(Instant::now());
+    }
+}
+
+/// Body of every `/api/jobs` response.
+#[derive(Serialize)]
+pub struct JobResponse {
+    pub id: String,
+    pub status: JobStatus,
+    /// Percent of the file's chunks transcribed, 100 once completed.
+    pub progress: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<TimedTranscript>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Default)]
+pub struct JobStore {
+    jobs: Mutex<HashMap<String, Job>>,
+    /// Job tasks not exited yet, see `JobSlot`.
+    tasks: Arc<AtomicUsize>,
+}
+
+/// Held by the task of a job, which counts against `MAX_ACTIVE_JOBS` until
+/// the slot is dropped: a cancelled job frees its slot only once its task
+/// has exited and removed its upload.
+pub(super) struct JobSlot(Arc<AtomicUsize>);
+
+impl Drop for JobSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl JobStore {
+    /// Registers a job and returns the slot of its task, or `None` when
+    /// `MAX_ACTIVE_JOBS` tasks are already alive.
+    pub(super) fn try_insert(&self, id: String, job: Job) -> Option<JobSlot> {
+        let mut jobs = self.jobs.lock().unwrap();
+        if self.tasks.load(Ordering::SeqCst) >= MAX_ACTIVE_JOBS {
+            return None;
+        }
+        self.tasks.fetch_add(1, Ordering::SeqCst);
+        evict_finished(&mut jobs);
+        jobs.insert(id, job);
+        Some(JobSlot(self.tasks.clone()))
+    }
+
+    pub(super) fn view(&self, id: &str) -> Option<JobResponse> {
+        let jobs = self.jobs.lock().unwrap();
+        jobs.get(id).map(|job| job_response(id, job))
+    }
+
+    pub(super) fn start(&self, id: &str) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(id) {
+            if job.status == JobStatus::Queued {
+                job.status = JobStatus::Running;
+            }
+        }
+    }
+
+    /// Records the outcome of a run. A job cancelled while queued keeps its
+    /// `Cancelled` status.
+    pub(super) fn complete(&self, id: &str, outcome: Result<Option<TimedTranscript>, String>) {
+        let mut jobs = self.jobs.lock().unwrap();
+        let Some(job) = jobs.get_mut(id) else {
+            return;
+        };
+        if job.status.is_finished() {
+            return;
+        }
+        match outcome {
+            Ok(Some(transcript)) => {
+                job.result = Some(transcript);
+                job.finish(JobStatus::Completed);
+            }
+            Ok(None) => job.finish(JobStatus::Cancelled),
+            Err(e) => {
+                job.error = Some(e);
+                job.finish(JobStatus::Failed);
+            }
+        }
+    }
+
+    /// Cancels a queued or running job, or forgets a finished one. Returns
+    /// the job as it was left, or `None` for an unknown id.
+    pub(super) fn cancel_or_remove(&self, id: &str) -> Option<JobResponse> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let job = jobs.get_mut(id)?;
+        if job.status.is_finished() {
+            return jobs.remove(id).map(|job| job_response(id, &job));
+        }
+        job.cancelled.store(true, Ordering::SeqCst);
+        job.cancel_notify.notify_one();
+        // A running job turns `Cancelled` once its current chunk is done.
+        if job.status == JobStatus::Queued {
+            job.finish(JobStatus::Cancelled);
+        }
+        Some(job_response(id, job))
+    }
+}
+
+fn job_response(id: &str, job: &Job) -> JobResponse {
+    let progress = if job.status == JobStatus::Completed {
+        100
+    } else {
+        job.progress.percent()
+    };
+    JobResponse {
+        id: id.to_string(),
+        status: job.status,
+        progress,
+        result: job.result.clone(),
+        error: job.error.clone(),
+    }
+}
+
+fn evict_finished(jobs: &mut HashMap<String, Job>) {
+    let mut finished: Vec<(Instant, String)> = jobs
+        .iter()
+        .filter_map(|(id, job)| job.finished_at.map(|at| (at, id.clone())))
+        .collect();
+    if finished.len() < MAX_FINISHED_JOBS {
+        return;
+    }
+    finished.sort();
+    let excess = finished.len() + 1 - MAX_FINISHED_JOBS;
+    for (_, id) in finished.into_iter().take(excess) {
+        jobs.remove(&id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store_with(count: usize) -> (JobStore, Vec<JobSlot>) {
+        let store = JobStore::default();
+        let slots = (0..count)
+            .map(|i| store.try_insert(i.to_string(), Job::queued()).unwrap())
+            .collect();
+        (store, slots)
+    }
+
+    #[test]
+    fn try_insert_rejects_past_active_limit() {
+        let (store, mut slots) = store_with(MAX_ACTIVE_JOBS);
+        assert!(store
+            .try_insert("extra".to_string(), Job::queued())
+            .is_none());
+
+        store.complete("0", Err("boom".to_string()));
+        drop(slots.remove(0));
+        assert!(store
+            .try_insert("extra".to_string(), Job::queued())
+            .is_some());
+    }
+
+    #[test]
+    fn cancel_queued_job_frees_its_slot_once_its_task_exits() {
+        let (store, mut slots) = store_with(MAX_ACTIVE_JOBS);
+        let view = store.cancel_or_remove("3").unwrap();
+        assert_eq!(view.status, JobStatus::Cancelled);
+        assert!(store
+            .try_insert("extra".to_string(), Job::queued())
+            .is_none());
+
+        drop(slots.remove(3));
+        assert!(store
+            .try_insert("extra".to_string(), Job::queued())
+            .is_some());
+    }
+
+    #[test]
+    fn submit_and_cancel_cannot_exceed_active_limit() {
+        let store = JobStore::default();
+        let mut slots = Vec::new();
+        for i in 0..MAX_ACTIVE_JOBS * 3 {
+            let id = i.to_string();
+            let Some(slot) = store.try_insert(id.clone(), Job::queued()) else {
+                continue;
+            };
+            slots.push(slot);
+            assert!(store.cancel_or_remove(&id).is_some());
+            // Forgetting the cancelled job does not free the slot either.
+            assert!(store.cancel_or_remove(&id).is_some());
+        }
+        assert_eq!(slots.len(), MAX_ACTIVE_JOBS);
+        assert!(store
+            .try_insert("extra".to_string(), Job::queued())
+            .is_none());
+    }
+
+    #[test]
+    fn cancel_running_job_waits_for_pipeline() {
+        let (store, _slots) = store_with(1);
+        store.start("0");
+        let view = store.cancel_or_remove("0").unwrap();
+        assert_eq!(view.status, JobStatus::Running);
+
+        store.complete("0", Ok(None));
+        assert_eq!(store.view("0").unwrap().status, JobStatus::Cancelled);
+    }
+
+    #[test]
+    fn cancelled_job_ignores_late_result() {
+        let (store, _slots) = store_with(1);
+        store.cancel_or_remove("0");
+        store.complete("0", Ok(Some(TimedTranscript::default())));
+        let view = store.view("0").unwrap();
+        assert_eq!(view.status, JobStatus::Cancelled);
+        assert!(view.result.is_none());
+    }
+
+    #[test]
+    fn delete_finished_job_forgets_it() {
+        let (store, _slots) = store_with(1);
+        store.complete("0", Ok(Some(TimedTranscript::default())));
+        assert_eq!(store.view("0").unwrap().progress, 100);
+        assert!(store.cancel_or_remove("0").is_some());
+        assert!(store.view("0").is_none());
+    }
+
+    #[test]
+    fn finished_jobs_are_evicted_oldest_first() {
+        let store = JobStore::default();
+        for i in 0..MAX_FINISHED_JOBS + 5 {
+            let id = i.to_string();
+            drop(store.try_insert(id.clone(), Job::queued()).unwrap());
+            store.complete(&id, Err("boom".to_string()));
+        }
+        assert!(store.view("0").is_none());
+        assert!(store.view(&(MAX_FINISHED_JOBS + 4).to_string()).is_some());
+        assert!(store.jobs.lock().unwrap().len() <= MAX_FINISHED_JOBS);
+    }
+
+    #[test]
+    fn publish_event_reaches_subscribers_as_json() {
+        let state = HttpApiState::new();
+        state.publish_event("recording-stopped", &serde_json::json!({}));
+        let mut rx = state.subscribe_events();
+        state.publish_event("transcription", &serde_json::json!({ "text": "Bonjour" }));
+        let event = rx.try_recv().unwrap();
+        assert_eq!(event.name, "transcription");
+        assert_eq!(event.data, r#"{"text":"Bonjour"}"#);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn process_request_runs_every_stage_but_llm_by_default() {
+        let request: ProcessRequest = serde_json::from_str(r#"{"text":"euh bonjour"}"#).unwrap();
+        assert_eq!(request.text, "euh bonjour");
+        assert_eq!(request.options(), ProcessOptions::default());
+
+        let request: ProcessRequest =
+            serde_json::from_str(r#"{"text":"x","fillers":false,"llm_mode":2}"#).unwrap();
+        let options = request.options();
+        assert!(!options.fillers);
+        assert!(options.dictionary && options.formatting);
+        assert_eq!(options.llm_mode, Some(2));
+    }
+}
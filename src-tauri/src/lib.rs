@@ -34,6 +34,7 @@ use log::{error, info, warn};
 use model::Model;
 use overlay::tray::setup_tray;
 use smartmic::SmartMicState;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use tauri::{DeviceEventFilter, Manager};
@@ -71,13 +72,15 @@ pub fn run() {
         log::warn!("Rustls crypto provider was already installed");
     }
 
-    let is_transcribe = matches!(
+    let is_one_shot = matches!(
         cli::parse_raw_args(&std::env::args().collect::<Vec<_>>()),
-        Ok(Some(cli::CliCommand::Transcribe { .. }))
+        Ok(Some(
+            cli::CliCommand::Transcribe { .. } | cli::CliCommand::Process { .. }
+        ))
     );
 
-    // transcribe keeps stdout for the transcription only; logs go to stderr.
-    let log_targets = if is_transcribe {
+    // transcribe and process keep stdout for their result only; logs go to stderr.
+    let log_targets = if is_one_shot {
         vec![Target::new(TargetKind::Stderr)]
     } else {
         vec![
@@ -111,7 +114,7 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_updater::Builder::new().build());
 
-    if !is_transcribe {
+    if !is_one_shot {
         builder = builder.plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
             match cli::parse_raw_args(&args) {
                 Ok(Some(cli::CliCommand::Import {
@@ -213,6 +216,45 @@ pub fn run() {
 
             let mut s = settings::load_settings(app.handle());
 
+            // `transcribe --beam-width` wins over the setting, without saving it.
+            let beam_width = match &pending_cli_action {
+                Some(cli::CliCommand::Transcribe {
+                    beam_width: Some(width),
+                    ..
+                }) => *width,
+                _ => s.decoder_beam_width,
+            };
+            app.state::<AudioState>().set_beam_width(beam_width);
+
+            // Same for `--quantization`, `--intra-threads` and `--inter-threads`.
+            let mut session_options = s.session_options();
+            if let Some(cli::CliCommand::Transcribe {
+                quantization,
+                threads,
+                ..
+            }) = &pending_cli_action
+            {
+                session_options.quantization = quantization.unwrap_or(session_options.quantization);
+                session_options.threads.intra_op =
+                    threads.intra_op.or(session_options.threads.intra_op);
+                session_options.threads.inter_op =
+                    threads.inter_op.or(session_options.threads.inter_op);
+            }
+            app.state::<Arc<Model>>()
+                .set_session_options(session_options);
+            if let Some(cli::CliCommand::Transcribe { .. }) = &pending_cli_action {
+                if let Ok(model) = app.state::<Arc<Model>>().active_model() {
+                    let requested = session_options.quantization;
+                    let loaded = model::registry::loaded_precision(requested, &model.precisions);
+                    if loaded != requested {
+                        eprintln!(
+                            "Warning: model '{}' has no {:?} files, {:?} is used instead",
+                            model.name, requested, loaded
+                        );
+                    }
+                }
+            }
+
             #[cfg(target_os = "macos")]
             {
                 use tauri::ActivationPolicy;
@@ -224,7 +266,10 @@ pub fn run() {
                 app.set_activation_policy(policy);
             }
 
-            if matches!(pending_cli_action, Some(cli::CliCommand::Transcribe { .. })) {
+            if matches!(
+                pending_cli_action,
+                Some(cli::CliCommand::Transcribe { .. } | cli::CliCommand::Process { .. })
+            ) {
                 let verbose = std::env::args().any(|a| a == "-v" || a == "--verbose");
                 log::set_max_level(if verbose {
                     log::LevelFilter::Debug
@@ -253,16 +298,27 @@ pub fn run() {
                 None
             };
 
-            let dictionary = if no_dictionary {
-                Vec::new()
+            let (dictionary, weights) = if no_dictionary {
+                (Vec::new(), HashMap::new())
             } else if let Some(path) = &dictionary_file {
-                match std::fs::read_to_string(path) {
-                    Ok(content) => content
-                        .lines()
-                        .map(str::trim)
-                        .filter(|l| !l.is_empty())
-                        .map(str::to_string)
-                        .collect(),
+                let entries = std::fs::read_to_string(path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        content
+                            .lines()
+                            .map(str::trim)
+                            .filter(|l| !l.is_empty())
+                            .map(|l| dictionary::split_entry_weight(l).map_err(|e| e.to_string()))
+                            .collect::<Result<Vec<_>, _>>()
+                    });
+                match entries {
+                    Ok(entries) => {
+                        let weights = entries
+                            .iter()
+                            .filter_map(|(word, weight)| weight.map(|w| (word.clone(), w)))
+                            .collect();
+                        (entries.into_iter().map(|(word, _)| word).collect(), weights)
+                    }
                     Err(e) => {
                         eprintln!("Error: cannot read --dictionary-file {}: {}", path, e);
                         app.handle().exit(1);
@@ -272,27 +328,105 @@ pub fn run() {
             } else if !s.dictionary.is_empty() {
                 let dictionary_from_settings = s.dictionary.clone();
                 s = settings::remove_dictionary_from_settings(app.handle(), s)?;
-                dictionary::migrate_and_load(app.handle(), dictionary_from_settings)?
+                (
+                    dictionary::migrate_and_load(app.handle(), dictionary_from_settings)?,
+                    dictionary::load_weights(app.handle())?,
+                )
             } else {
-                dictionary::load(app.handle())?
+                (
+                    dictionary::load(app.handle())?,
+                    dictionary::load_weights(app.handle())?,
+                )
             };
-            app.manage(Dictionary::new(dictionary.clone()));
+            let suppression = if no_dictionary {
+                dictionary::SuppressionList::default()
+            } else {
+                dictionary::load_suppression(app.handle())?
+            };
+            app.manage(Dictionary::new(dictionary.clone(), weights, suppression));
 
-            if let Some(cli::CliCommand::Transcribe { file_path }) = &pending_cli_action {
+            if let Some(cli::CliCommand::Transcribe {
+                file_path, format, ..
+            }) = &pending_cli_action
+            {
                 if let Some(main_window) = app.get_webview_window("main") {
                     let _ = main_window.hide();
                 }
-                match audio::pipeline::transcribe_file_chunked(
+                match audio::pipeline::transcribe_file_timed(
                     app.handle(),
                     std::path::Path::new(file_path),
                 ) {
-                    Ok(text) if text.is_empty() => {
+                    Ok(transcript) if transcript.text.is_empty() => {
                         eprintln!("Transcription produced no text");
                         app.handle().exit(1);
                     }
-                    Ok(text) => {
-                        println!("{}", text);
-                        app.handle().exit(0);
+                    Ok(transcript) => match format {
+                        cli::TranscribeFormat::Text => {
+                            println!("{}", transcript.text);
+                            app.handle().exit(0);
+                        }
+                        cli::TranscribeFormat::Json => {
+                            match serde_json::to_string_pretty(&transcript) {
+                                Ok(json) => {
+                                    println!("{}", json);
+                                    app.handle().exit(0);
+                                }
+                                Err(e) => {
+                                    eprintln!("Failed to serialize transcript: {}", e);
+                                    app.handle().exit(1);
+                                }
+                            }
+                        }
+                        cli::TranscribeFormat::Srt | cli::TranscribeFormat::Vtt => {
+                            let subtitle_format = if *format == cli::TranscribeFormat::Srt {
+                                audio::subtitles::SubtitleFormat::Srt
+                            } else {
+                                audio::subtitles::SubtitleFormat::Vtt
+                            };
+                            print!(
+                                "{}",
+                                audio::subtitles::render_subtitles(
+                                    &transcript.words,
+                                    subtitle_format
+                                )
+                            );
+                            app.handle().exit(0);
+                        }
+                    },
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        app.handle().exit(1);
+                    }
+                }
+                return Ok(());
+            }
+
+            if let Some(cli::CliCommand::Process { text, options }) = &pending_cli_action {
+                if let Some(main_window) = app.get_webview_window("main") {
+                    let _ = main_window.hide();
+                }
+                let input = match text {
+                    Some(text) => text.clone(),
+                    None => match std::io::read_to_string(std::io::stdin()) {
+                        Ok(input) => input,
+                        Err(e) => {
+                            eprintln!("Error: cannot read text from stdin: {}", e);
+                            app.handle().exit(1);
+                            return Ok(());
+                        }
+                    },
+                };
+                match audio::pipeline::process_text(app.handle(), &input, options) {
+                    Ok(result) => {
+                        // The text is still printed, without the LLM stage.
+                        println!("{}", result.text);
+                        match &result.llm_error {
+                            Some(e) => {
+                                eprintln!("LLM processing failed: {}", e);
+                                app.handle().exit(1);
+                            }
+                            None => app.handle().exit(0),
+                        }
                     }
                     Err(e) => {
                         eprintln!("{}", e);
@@ -310,6 +444,7 @@ pub fn run() {
                 Ok(_) => info!("Transcription engine initialized and ready"),
                 Err(e) => info!("Transcription engine will be loaded on first use: {}", e),
             }
+            audio::idle_unload::spawn_idle_unload(app.handle().clone());
 
             // Open `/dev/uinput` during setup so the first paste does
             // not race init. The ~500 ms cost is hidden behind model
@@ -374,6 +509,15 @@ pub fn run() {
                 });
             }
 
+            // Same delay: the microphone cache is filled in the background.
+            if s.pre_roll_ms > 0 {
+                let app_handle = app.handle().clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    audio::pre_roll::apply(&app_handle);
+                });
+            }
+
             if !is_transcribe {
                 crate::utils::log_watchdog::spawn(app.handle());
             }
@@ -410,6 +554,9 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             is_model_available,
             get_model_path,
+            list_models,
+            import_model,
+            set_active_model,
             read_murmure_file,
             write_murmure_file,
             get_all_settings,
@@ -426,6 +573,10 @@ pub fn run() {
             set_record_shortcut,
             set_dictionary,
             get_dictionary,
+            get_dictionary_weights,
+            set_dictionary_weights,
+            get_suppression_list,
+            set_suppression_list,
             export_dictionary,
             import_dictionary,
             get_last_transcript_shortcut,
@@ -434,6 +585,8 @@ pub fn run() {
             set_command_shortcut,
             get_cancel_shortcut,
             set_cancel_shortcut,
+            get_pause_shortcut,
+            set_pause_shortcut,
             cancel_recording,
             get_llm_mode_1_shortcut,
             set_llm_mode_1_shortcut,
@@ -457,6 +610,13 @@ pub fn run() {
             set_api_enabled,
             get_api_port,
             set_api_port,
+            get_api_bind_address,
+            set_api_bind_address,
+            get_api_tls_enabled,
+            set_api_tls_enabled,
+            has_http_api_token,
+            generate_http_api_token,
+            revoke_http_api_token,
             start_http_api_server,
             stop_http_api_server,
             set_copy_to_clipboard,
@@ -496,6 +656,17 @@ pub fn run() {
             set_log_level,
             set_keep_recordings,
             get_recordings_dir,
+            set_audio_enhancement,
+            set_dual_capture,
+            set_speaker_labels,
+            set_pre_roll_ms,
+            get_decoder_beam_width,
+            set_decoder_beam_width,
+            set_whisper_language,
+            set_model_quantization,
+            set_inference_threads,
+            set_model_idle_unload,
+            is_model_warming,
             open_accessibility_settings,
             check_accessibility_permission,
             get_wake_word_enabled,
@@ -514,6 +685,7 @@ pub fn run() {
             set_wake_word_submit,
             get_silence_timeout_ms,
             set_silence_timeout_ms,
+            set_vad_provider,
             get_auto_enter_after_wake_word,
             set_auto_enter_after_wake_word,
             get_smartmic_enabled,
@@ -215,7 +215,26 @@ pub async fn post_process_with_llm(
     }
 
     let settings = load_llm_connect_settings(app);
+    run_mode_prompt(app, &settings, transcription).await
+}
 
+/// Like `post_process_with_llm` with mode `index` (0-based) instead of the
+/// active one. The stored active mode is left untouched.
+pub async fn post_process_with_llm_mode(
+    app: &AppHandle,
+    transcription: String,
+    index: usize,
+) -> Result<String, String> {
+    let mut settings = load_llm_connect_settings(app);
+    settings.active_mode_index = index;
+    run_mode_prompt(app, &settings, transcription).await
+}
+
+async fn run_mode_prompt(
+    app: &AppHandle,
+    settings: &LLMConnectSettings,
+    transcription: String,
+) -> Result<String, String> {
     let active_mode = settings
         .modes
         .get(settings.active_mode_index)
@@ -235,7 +254,7 @@ pub async fn post_process_with_llm(
         .replace("{dictionary}", &dictionary_words);
 
     let (system_prompt, user_prompt) = extract_system_prompt(&prompt);
-    dispatch_to_llm(app, &settings, system_prompt.as_deref(), &user_prompt).await
+    dispatch_to_llm(app, settings, system_prompt.as_deref(), &user_prompt).await
 }
 
 pub async fn process_command_with_llm(
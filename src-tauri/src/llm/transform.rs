@@ -57,6 +57,11 @@ pub fn transform_selection_with_mode(app: &AppHandle, index: usize) {
         }
         Err(e) => {
             error!("Transform: failed to capture selection: {}", e);
+            crate::http_api::events::publish_event(
+                app,
+                "llm-error",
+                &serde_json::json!({ "message": e }),
+            );
             let _ = app.emit("llm-error", e);
             end_transform(app);
             return;
@@ -89,6 +94,11 @@ pub fn transform_selection_with_mode(app: &AppHandle, index: usize) {
         }
         Err(e) => {
             warn!("Transform: LLM processing failed: {}", e);
+            crate::http_api::events::publish_event(
+                app,
+                "llm-error",
+                &serde_json::json!({ "message": e }),
+            );
             let _ = app.emit("llm-error", e);
         }
     }
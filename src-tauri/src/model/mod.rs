@@ -1,2 +1,4 @@
 pub mod model;
+pub mod registry;
 pub use model::*;
+pub use registry::ModelInfo;
@@ -1,23 +1,77 @@
+use super::registry::{self, ModelInfo};
+use crate::engine::{AsrBackend, AsrEngine, SessionOptions};
 use anyhow::Result;
-use log::debug;
-use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
 
 const MODEL_FILENAME: &str = "parakeet-tdt-0.6b-v3-int8";
+// Optional Whisper model (sherpa-onnx export: `*-encoder.onnx`,
+// `*-decoder.onnx`, `*-tokens.txt`), looked up in the same locations.
+const WHISPER_MODEL_DIRNAME: &str = "whisper";
+
+// BPE tokenizer that maps dictionary words to the model's token ids.
+const TOKENIZER_FILENAME: &str = "tokenizer.json";
+
+// Imported models, one directory each, under the app data directory.
+const IMPORTED_MODELS_DIRNAME: &str = "models";
+
+fn bundled_model_id(backend: AsrBackend) -> &'static str {
+    match backend {
+        AsrBackend::Parakeet => MODEL_FILENAME,
+        AsrBackend::Whisper => WHISPER_MODEL_DIRNAME,
+    }
+}
 
 pub struct Model {
     app_handle: AppHandle,
+    // Model directories whose checksums already matched in this session.
+    verified: Mutex<HashSet<PathBuf>>,
+    session_options: Mutex<SessionOptions>,
 }
 
 impl Model {
     pub fn new(app_handle: AppHandle) -> Result<Self> {
-        Ok(Self { app_handle })
+        Ok(Self {
+            app_handle,
+            verified: Mutex::new(HashSet::new()),
+            session_options: Mutex::new(SessionOptions::default()),
+        })
+    }
+
+    /// Quantization and threads of the next engine load.
+    pub fn set_session_options(&self, options: SessionOptions) {
+        *self.session_options.lock() = options;
+    }
+
+    pub fn session_options(&self) -> SessionOptions {
+        *self.session_options.lock()
     }
 
     pub fn get_model_path(&self) -> Result<PathBuf> {
+        Ok(self.active_model()?.path)
+    }
+
+    pub fn get_model_path_for(&self, backend: AsrBackend) -> Result<PathBuf> {
+        let model_path = self.resolve_model_dir(bundled_model_id(backend))?;
+        if backend == AsrBackend::Whisper
+            && !crate::engine::whisper::is_whisper_model_dir(&model_path)
+        {
+            anyhow::bail!(
+                "'{}' is not a Whisper model: expected *-encoder.onnx, *-decoder.onnx \
+                and *-tokens.txt files (sherpa-onnx export).",
+                model_path.display()
+            );
+        }
+        Ok(model_path)
+    }
+
+    fn resolve_model_dir(&self, model_dirname: &str) -> Result<PathBuf> {
         // Essayer plusieurs emplacements possibles pour le modèle
         if let Some(model_path) =
-            crate::utils::resources::resolve_resource_path(&self.app_handle, MODEL_FILENAME)
+            crate::utils::resources::resolve_resource_path(&self.app_handle, model_dirname)
         {
             debug!("Model found at: {}", model_path.display());
             return Ok(model_path);
@@ -25,7 +79,7 @@ impl Model {
 
         // Si aucun chemin ne fonctionne, essayer le chemin absolu depuis AppData/Exe
         let exe_dir = self.app_handle.path().app_data_dir()?;
-        let fallback_path = exe_dir.join("resources").join(MODEL_FILENAME);
+        let fallback_path = exe_dir.join("resources").join(model_dirname);
 
         if fallback_path.exists() {
             debug!(
@@ -38,7 +92,7 @@ impl Model {
         // Dernier recours : chemin relatif depuis le binaire
         if let Ok(exe_path) = std::env::current_exe() {
             if let Some(exe_dir) = exe_path.parent() {
-                let dev_path = exe_dir.join("_up_").join("resources").join(MODEL_FILENAME);
+                let dev_path = exe_dir.join("_up_").join("resources").join(model_dirname);
                 if dev_path.exists() {
                     debug!("Model found at dev location: {}", dev_path.display());
                     return Ok(dev_path);
@@ -49,15 +103,176 @@ impl Model {
         anyhow::bail!(
             "Model '{}' not found in any expected location. \
             Please ensure the model is in the resources folder.",
-            MODEL_FILENAME
+            model_dirname
+        )
+    }
+
+    fn imported_models_dir(&self) -> Result<PathBuf> {
+        Ok(self
+            .app_handle
+            .path()
+            .app_data_dir()?
+            .join(IMPORTED_MODELS_DIRNAME))
+    }
+
+    /// Bundled models first, then the imported ones, the active one flagged.
+    pub fn list_models(&self) -> Vec<ModelInfo> {
+        let mut models: Vec<ModelInfo> = [AsrBackend::Parakeet, AsrBackend::Whisper]
+            .into_iter()
+            .filter_map(|backend| {
+                let path = self.get_model_path_for(backend).ok()?;
+                registry::describe(bundled_model_id(backend), &path, true)
+            })
+            .collect();
+
+        if let Ok(entries) = self
+            .imported_models_dir()
+            .and_then(|d| Ok(std::fs::read_dir(d)?))
+        {
+            let mut imported: Vec<ModelInfo> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.is_dir())
+                .filter_map(|path| {
+                    let id = path.file_name()?.to_str()?.to_string();
+                    if id.starts_with('.') {
+                        return None;
+                    }
+                    registry::describe(&id, &path, false)
+                })
+                .collect();
+            imported.sort_by(|a, b| a.name.cmp(&b.name));
+            models.extend(imported);
+        }
+
+        let active_id = self.active_model_id();
+        for model in &mut models {
+            model.active = model.id == active_id;
+        }
+        models
+    }
+
+    fn active_model_id(&self) -> String {
+        let settings = crate::settings::load_settings(&self.app_handle);
+        settings
+            .active_model
+            .unwrap_or_else(|| bundled_model_id(settings.asr_backend).to_string())
+    }
+
+    pub fn active_model(&self) -> Result<ModelInfo> {
+        let settings = crate::settings::load_settings(&self.app_handle);
+        let id = self.active_model_id();
+        if let Some(model) = self.list_models().into_iter().find(|m| m.id == id) {
+            return Ok(model);
+        }
+        if settings.active_model.is_none() {
+            // Surface why the bundled model is unusable.
+            self.get_model_path_for(settings.asr_backend)?;
+        }
+        anyhow::bail!(
+            "Model '{}' is not installed. Import it again or select another \
+            model in Settings > System > Advanced > Speech model.",
+            id
         )
     }
 
-    pub fn get_tokenizer_path(&self) -> Option<PathBuf> {
-        crate::utils::resources::resolve_resource_path(&self.app_handle, "tokenizer.json")
+    /// Copy the model directory at `source` into the imported models.
+    pub fn import_model(&self, source: &Path) -> Result<ModelInfo> {
+        let name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let id = registry::sanitize_id(&name);
+        if id.is_empty() {
+            anyhow::bail!("'{}' is not a model directory.", source.display());
+        }
+        if self.list_models().iter().any(|m| m.id == id) {
+            anyhow::bail!("A model named '{}' is already installed.", name);
+        }
+        let models_dir = self.imported_models_dir()?;
+        std::fs::create_dir_all(&models_dir)?;
+        let target = models_dir.join(&id);
+        registry::import(source, &target).map_err(anyhow::Error::msg)?;
+        self.verified.lock().insert(target.clone());
+        registry::describe(&id, &target, false)
+            .ok_or_else(|| anyhow::anyhow!("Imported model '{}' is unreadable", id))
+    }
+
+    fn load_model(&self, model: &ModelInfo) -> Result<AsrEngine> {
+        if !self.verified.lock().contains(&model.path) {
+            registry::verify(&model.path, model.backend).map_err(|e| {
+                anyhow::anyhow!(
+                    "Model '{}' is corrupted: {} Import it again or select another \
+                    model in Settings > System > Advanced > Speech model.",
+                    model.name,
+                    e
+                )
+            })?;
+            self.verified.lock().insert(model.path.clone());
+        }
+        let mut options = self.session_options();
+        let precision = registry::loaded_precision(options.quantization, &model.precisions);
+        if precision != options.quantization {
+            warn!(
+                "Model '{}' has no {:?} files, loading {:?} instead",
+                model.name, options.quantization, precision
+            );
+            options.quantization = precision;
+        }
+        AsrEngine::load(
+            model.backend,
+            &model.path,
+            options,
+            self.tokenizer_path_for(model),
+            crate::settings::load_settings(&self.app_handle).whisper_language,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to load model: {}", e))
+    }
+
+    /// Load the engine of the active model. An imported model that is
+    /// missing or corrupted is deselected in favor of the bundled one, and
+    /// the reason is emitted as `model-fallback`.
+    pub fn load_engine(&self) -> Result<AsrEngine> {
+        let mut settings = crate::settings::load_settings(&self.app_handle);
+        let error = match self
+            .active_model()
+            .and_then(|model| self.load_model(&model))
+        {
+            Ok(engine) => return Ok(engine),
+            Err(e) if settings.active_model.is_none() => return Err(e),
+            Err(e) => e,
+        };
+
+        let reason = format!("{:#}", error);
+        warn!("Falling back to the bundled model: {}", reason);
+        let _ = self.app_handle.emit("model-fallback", &reason);
+        settings.active_model = None;
+        settings.asr_backend = AsrBackend::Parakeet;
+        crate::settings::save_settings(&self.app_handle, &settings).map_err(anyhow::Error::msg)?;
+        let model = self
+            .active_model()
+            .map_err(|e| anyhow::anyhow!("Failed to get model path: {}", e))?;
+        self.load_model(&model)
+    }
+
+    /// The model's own `tokenizer.json`, else the bundled one, which only
+    /// matches the bundled model. `None` turns off dictionary boosting.
+    fn tokenizer_path_for(&self, model: &ModelInfo) -> Option<PathBuf> {
+        let own = model.path.join(TOKENIZER_FILENAME);
+        if own.exists() {
+            return Some(own);
+        }
+        if !model.bundled {
+            warn!(
+                "Model '{}' has no {}, dictionary boosting disabled",
+                model.name, TOKENIZER_FILENAME
+            );
+            return None;
+        }
+        crate::utils::resources::resolve_resource_path(&self.app_handle, TOKENIZER_FILENAME)
     }
 
     pub fn is_available(&self) -> bool {
-        self.get_model_path().is_ok()
+        // load_engine falls back to the bundled model.
+        self.get_model_path().is_ok() || self.get_model_path_for(AsrBackend::Parakeet).is_ok()
     }
 }
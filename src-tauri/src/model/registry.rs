@@ -0,0 +1,352 @@
+use crate::engine::{AsrBackend, QuantizationType};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Optional description of a model directory, written on import.
+pub const MANIFEST_FILENAME: &str = "murmure-model.json";
+
+// Languages of parakeet-tdt-0.6b-v3, the bundled model.
+const PARAKEET_V3_LANGUAGES: &[&str] = &[
+    "bg", "cs", "da", "de", "el", "en", "es", "et", "fi", "fr", "hr", "hu", "it", "lt", "lv", "mt",
+    "nl", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "uk",
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelManifest {
+    pub name: Option<String>,
+    pub backend: Option<AsrBackend>,
+    pub quantization: Option<String>,
+    pub languages: Vec<String>,
+    /// SHA-256 of each model file, keyed by file name.
+    pub checksums: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelInfo {
+    pub id: String,
+    pub name: String,
+    pub backend: AsrBackend,
+    pub path: PathBuf,
+    pub size_bytes: u64,
+    pub quantization: String,
+    /// Precisions the directory has complete files for.
+    pub precisions: Vec<QuantizationType>,
+    /// Empty when the languages are unknown.
+    pub languages: Vec<String>,
+    pub bundled: bool,
+    /// False when no manifest checksums exist: loading only checks that the
+    /// files are present, not that they are intact.
+    pub verified: bool,
+    pub active: bool,
+}
+
+fn has_onnx(dir: &Path, name: &str) -> bool {
+    dir.join(format!("{}.onnx", name)).exists() || dir.join(format!("{}.int8.onnx", name)).exists()
+}
+
+pub fn is_parakeet_model_dir(dir: &Path) -> bool {
+    dir.join("vocab.txt").exists()
+        && dir.join("nemo128.onnx").exists()
+        && has_onnx(dir, "encoder-model")
+        && has_onnx(dir, "decoder_joint-model")
+}
+
+/// Precisions `dir` has every model file for; the engine loads the other
+/// one when the requested precision is missing.
+pub fn available_precisions(dir: &Path, backend: AsrBackend) -> Vec<QuantizationType> {
+    let complete = |suffix: &str| match backend {
+        AsrBackend::Parakeet => ["encoder-model", "decoder_joint-model"]
+            .iter()
+            .all(|part| dir.join(format!("{}{}", part, suffix)).exists()),
+        AsrBackend::Whisper => ["-encoder", "-decoder"].iter().all(|part| {
+            crate::engine::whisper::find_model_file(dir, &format!("{}{}", part, suffix)).is_some()
+        }),
+    };
+    [
+        (QuantizationType::Int8, ".int8.onnx"),
+        (QuantizationType::FP32, ".onnx"),
+    ]
+    .into_iter()
+    .filter(|(_, suffix)| complete(suffix))
+    .map(|(precision, _)| precision)
+    .collect()
+}
+
+/// Precision the engine actually loads when `requested` is asked for.
+pub fn loaded_precision(
+    requested: QuantizationType,
+    available: &[QuantizationType],
+) -> QuantizationType {
+    match available {
+        [only] => *only,
+        _ => requested,
+    }
+}
+
+pub fn detect_backend(dir: &Path) -> Option<AsrBackend> {
+    if is_parakeet_model_dir(dir) {
+        Some(AsrBackend::Parakeet)
+    } else if crate::engine::whisper::is_whisper_model_dir(dir) {
+        Some(AsrBackend::Whisper)
+    } else {
+        None
+    }
+}
+
+pub fn read_manifest(dir: &Path) -> Result<Option<ModelManifest>, String> {
+    let path = dir.join(MANIFEST_FILENAME);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Invalid {}: {}", path.display(), e))
+}
+
+// Model files sit flat in their directory; the manifest is not one of them.
+fn model_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("Cannot read {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| path.file_name().is_some_and(|n| n != MANIFEST_FILENAME))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+pub fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+pub fn compute_checksums(dir: &Path) -> Result<BTreeMap<String, String>, String> {
+    model_files(dir)?
+        .into_iter()
+        .map(|path| {
+            sha256_file(&path)
+                .map(|hash| (file_name(&path), hash))
+                .map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+        })
+        .collect()
+}
+
+/// Check that `dir` holds a complete model of `backend` and that its files
+/// match the manifest checksums, if any.
+pub fn verify(dir: &Path, backend: AsrBackend) -> Result<(), String> {
+    if detect_backend(dir) != Some(backend) {
+        return Err(format!(
+            "'{}' is not a complete {:?} model: files are missing.",
+            dir.display(),
+            backend
+        ));
+    }
+    let Some(manifest) = read_manifest(dir)? else {
+        return Ok(());
+    };
+    for (name, expected) in &manifest.checksums {
+        let path = dir.join(name);
+        let actual = sha256_file(&path).map_err(|_| format!("{} is missing.", name))?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(format!("{} does not match its checksum.", name));
+        }
+    }
+    Ok(())
+}
+
+/// Describe the model stored in `dir`, preferring the manifest over what
+/// can be inferred from the file names.
+pub fn describe(id: &str, dir: &Path, bundled: bool) -> Option<ModelInfo> {
+    let backend = detect_backend(dir)?;
+    let manifest = read_manifest(dir).ok().flatten().unwrap_or_default();
+    let files = model_files(dir).unwrap_or_default();
+    let size_bytes = files
+        .iter()
+        .filter_map(|path| path.metadata().ok())
+        .map(|m| m.len())
+        .sum();
+    let quantization = manifest.quantization.unwrap_or_else(|| {
+        let int8 = files.iter().any(|p| file_name(p).ends_with(".int8.onnx"));
+        if int8 { "int8" } else { "fp32" }.to_string()
+    });
+    let languages = if !manifest.languages.is_empty() {
+        manifest.languages
+    } else if bundled && backend == AsrBackend::Parakeet {
+        PARAKEET_V3_LANGUAGES
+            .iter()
+            .map(|l| l.to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let verified = !manifest.checksums.is_empty();
+
+    Some(ModelInfo {
+        id: id.to_string(),
+        name: manifest.name.unwrap_or_else(|| id.to_string()),
+        backend,
+        path: dir.to_path_buf(),
+        size_bytes,
+        quantization,
+        precisions: available_precisions(dir, backend),
+        languages,
+        bundled,
+        verified,
+        active: false,
+    })
+}
+
+pub fn sanitize_id(name: &str) -> String {
+    let id: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    id.trim_matches(|c| c == '.' || c == '-').to_string()
+}
+
+/// Copy the model in `source` to `target`, recording the checksums of the
+/// source files in the manifest and checking the copy against them.
+pub fn import(source: &Path, target: &Path) -> Result<AsrBackend, String> {
+    let backend = detect_backend(source).ok_or_else(|| {
+        format!(
+            "'{}' is not a Parakeet or Whisper model directory.",
+            source.display()
+        )
+    })?;
+    if target.exists() {
+        return Err(format!(
+            "A model named '{}' is already installed.",
+            file_name(target)
+        ));
+    }
+
+    let mut manifest = read_manifest(source)?.unwrap_or_default();
+    if manifest.checksums.is_empty() {
+        manifest.checksums = compute_checksums(source)?;
+    } else {
+        verify(source, backend).map_err(|e| format!("The model to import is corrupted: {}", e))?;
+    }
+    manifest.backend = Some(backend);
+
+    let partial = target.with_file_name(format!(".{}.partial", file_name(target)));
+    let result = copy_model(source, &partial, &manifest)
+        .and_then(|_| verify(&partial, backend))
+        .and_then(|_| fs::rename(&partial, target).map_err(|e| e.to_string()));
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&partial);
+    }
+    result.map(|_| backend)
+}
+
+fn copy_model(source: &Path, target: &Path, manifest: &ModelManifest) -> Result<(), String> {
+    let _ = fs::remove_dir_all(target);
+    fs::create_dir_all(target).map_err(|e| e.to_string())?;
+    for path in model_files(source)? {
+        fs::copy(&path, target.join(file_name(&path)))
+            .map_err(|e| format!("Cannot copy {}: {}", path.display(), e))?;
+    }
+    let content = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+    fs::write(target.join(MANIFEST_FILENAME), content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parakeet_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in [
+            "vocab.txt",
+            "nemo128.onnx",
+            "encoder-model.int8.onnx",
+            "decoder_joint-model.int8.onnx",
+        ] {
+            fs::write(dir.path().join(file), file).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_describe_infers_backend_and_quantization() {
+        let dir = parakeet_dir();
+        let dir = dir.path();
+        let info = describe("parakeet", dir, true).unwrap();
+        assert_eq!(info.backend, AsrBackend::Parakeet);
+        assert_eq!(info.quantization, "int8");
+        assert_eq!(info.precisions, vec![QuantizationType::Int8]);
+        assert_eq!(
+            loaded_precision(QuantizationType::FP32, &info.precisions),
+            QuantizationType::Int8
+        );
+        assert!(info.languages.contains(&"fr".to_string()));
+        assert!(info.size_bytes > 0);
+        assert!(!info.verified);
+
+        fs::remove_file(dir.join("vocab.txt")).unwrap();
+        assert!(describe("parakeet", dir, true).is_none());
+    }
+
+    #[test]
+    fn test_import_records_checksums_and_detects_corruption() {
+        let source = parakeet_dir();
+        let source = source.path();
+        let models = tempfile::tempdir().unwrap();
+        let target = models.path().join("imported");
+
+        assert_eq!(import(source, &target), Ok(AsrBackend::Parakeet));
+        let manifest = read_manifest(&target).unwrap().unwrap();
+        assert_eq!(manifest.checksums.len(), 4);
+        assert!(verify(&target, AsrBackend::Parakeet).is_ok());
+        assert!(describe("imported", &target, false).unwrap().verified);
+        assert!(import(source, &target).is_err());
+
+        fs::write(target.join("nemo128.onnx"), "truncated").unwrap();
+        let err = verify(&target, AsrBackend::Parakeet).unwrap_err();
+        assert!(err.contains("nemo128.onnx"));
+    }
+
+    #[test]
+    fn test_import_rejects_unknown_directory() {
+        let source = tempfile::tempdir().unwrap();
+        let models = tempfile::tempdir().unwrap();
+        let target = models.path().join("imported");
+        assert!(import(source.path(), &target).is_err());
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_sanitize_id() {
+        assert_eq!(sanitize_id("My Model (v2)"), "my-model--v2");
+        assert_eq!(sanitize_id(".hidden"), "hidden");
+    }
+}
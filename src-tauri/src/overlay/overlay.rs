@@ -301,12 +301,7 @@ fn present_recording_overlay(app_handle: &AppHandle) {
         return;
     };
     let state = app_handle.state::<crate::audio::types::AudioState>();
-    let mode_str = match state.get_recording_mode() {
-        crate::audio::types::RecordingMode::Standard => "standard",
-        crate::audio::types::RecordingMode::Llm => "llm",
-        crate::audio::types::RecordingMode::Command => "command",
-    };
-    let _ = window.emit("recording-mode", mode_str);
+    let _ = window.emit("recording-mode", state.get_recording_mode().as_str());
     let _ = window.show();
     let _ = window.set_always_on_top(true);
     crate::overlay::input_region::on_overlay_shown(&window);
@@ -1,3 +1,6 @@
+use crate::audio::system_audio::DEFAULT_SPEAKER_LABELS;
+use crate::audio::vad::VadProvider;
+use crate::engine::{AsrBackend, QuantizationType, SessionOptions, SessionThreads};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
@@ -45,6 +48,8 @@ pub struct AppSettings {
     pub overlay_position: String, // "top" | "bottom"
     pub api_enabled: bool,
     pub api_port: u16,
+    pub api_bind_address: Option<String>, // None means 127.0.0.1 only
+    pub api_tls_enabled: bool,
     pub copy_to_clipboard: bool, // Keeps transcription in clipboard after recording finishes
     pub paste_method: PasteMethod,
     pub persist_history: bool, // Persists last 5 transcriptions to disk
@@ -55,6 +60,7 @@ pub struct AppSettings {
     pub output_volume_while_recording: u8,
     pub onboarding: OnboardingState,
     pub cancel_shortcut: String,
+    pub pause_shortcut: String,
     pub mic_id: Option<String>,
     pub mic_label: Option<String>, // Persisted so the disconnected-mic UI can still show a friendly name
     pub log_level: String,         // "info" | "debug" | "trace" | "warn" | "error"
@@ -69,6 +75,17 @@ pub struct AppSettings {
     pub wake_word_submit: String,
     pub auto_enter_after_wake_word: bool,
     pub silence_timeout_ms: u64,
+    // Voice detection of chunking, wake word and silence timeout.
+    pub vad_provider: VadProvider,
+    // Noise suppression and gain control of the audio before transcription.
+    pub audio_enhancement: bool,
+    // Record the system output next to the microphone and label the speakers.
+    pub dual_capture: bool,
+    // Line prefixes of the two sides of a dual capture transcript.
+    pub speaker_label_me: String,
+    pub speaker_label_remote: String,
+    // Audio kept from before the shortcut and prepended to each recording, 0 when off.
+    pub pre_roll_ms: u64,
     pub show_in_dock: bool,
     pub smartmic_enabled: bool,
     pub smartmic_port: u16,
@@ -79,6 +96,19 @@ pub struct AppSettings {
     pub smartmic_token_ttl_hours: Option<u64>, // None or 0 means infinite
     pub smartmic_bind_address: Option<String>, // None means auto-detect
     pub streaming_preview: bool,
+    // Beam width of the final decode, 1 keeps the greedy decoder.
+    pub decoder_beam_width: usize,
+    pub asr_backend: AsrBackend,
+    // Id of the imported model in use, None for the bundled one of asr_backend.
+    pub active_model: Option<String>,
+    // ISO code of the language Whisper transcribes, None to detect it.
+    pub whisper_language: Option<String>,
+    pub model_quantization: QuantizationType,
+    // ONNX Runtime threads, None lets it decide.
+    pub intra_op_threads: Option<usize>,
+    pub inter_op_threads: Option<usize>,
+    // Minutes without use before the model is unloaded, 0 keeps it loaded.
+    pub model_idle_unload_minutes: u64,
     pub overlay_size: String, // "small" | "medium" | "large"
     pub streaming_text_width: u32,
     pub streaming_font_size: u32,
@@ -91,6 +121,18 @@ pub struct AppSettings {
     pub wayland_clipboard_fallback_dismissed: bool,
 }
 
+impl AppSettings {
+    pub fn session_options(&self) -> SessionOptions {
+        SessionOptions {
+            quantization: self.model_quantization,
+            threads: SessionThreads {
+                intra_op: self.intra_op_threads,
+                inter_op: self.inter_op_threads,
+            },
+        }
+    }
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
@@ -144,6 +186,8 @@ impl Default for AppSettings {
             overlay_position: "bottom".to_string(),
             api_enabled: false,
             api_port: 4800,
+            api_bind_address: None,
+            api_tls_enabled: false,
             // Default to true on Wayland so transcriptions remain accessible
             // via manual Ctrl+V when enigo's key injection cannot reach native
             // Wayland apps. Users remain free to disable it.
@@ -166,6 +210,7 @@ impl Default for AppSettings {
             output_volume_while_recording: 50,
             onboarding: OnboardingState::default(),
             cancel_shortcut: "ctrl+backspace".to_string(),
+            pause_shortcut: "ctrl+alt+space".to_string(),
             mic_id: None,
             mic_label: None,
             log_level: "info".to_string(),
@@ -178,6 +223,12 @@ impl Default for AppSettings {
             wake_word_submit: "thank you alix".to_string(),
             auto_enter_after_wake_word: false,
             silence_timeout_ms: 1500,
+            vad_provider: VadProvider::default(),
+            audio_enhancement: false,
+            dual_capture: false,
+            speaker_label_me: DEFAULT_SPEAKER_LABELS.0.to_string(),
+            speaker_label_remote: DEFAULT_SPEAKER_LABELS.1.to_string(),
+            pre_roll_ms: 0,
             show_in_dock: true,
             smartmic_enabled: false,
             smartmic_port: 4801,
@@ -188,6 +239,14 @@ impl Default for AppSettings {
             smartmic_token_ttl_hours: None,
             smartmic_bind_address: None,
             streaming_preview: true,
+            decoder_beam_width: 1,
+            asr_backend: AsrBackend::default(),
+            active_model: None,
+            whisper_language: None,
+            model_quantization: QuantizationType::default(),
+            intra_op_threads: None,
+            inter_op_threads: None,
+            model_idle_unload_minutes: 0,
             overlay_size: "small".to_string(),
             streaming_text_width: 450,
             streaming_font_size: 11,
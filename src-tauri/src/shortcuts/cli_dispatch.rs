@@ -19,6 +19,7 @@ pub fn dispatch(app: &AppHandle, cmd: &CliCommand) {
         CliCommand::TranscriptionCommand => cli_toggle_recording(app, RecordingMode::Command),
         CliCommand::PasteLast => paste_last(app),
         CliCommand::Cancel => cancel(app),
+        CliCommand::Pause => crate::audio::toggle_pause_recording(app),
         CliCommand::VoiceMode => {
             let _ = app.emit("voice-mode-toggle-requested", ());
         }
@@ -41,6 +42,9 @@ pub fn dispatch(app: &AppHandle, cmd: &CliCommand) {
         CliCommand::Transcribe { .. } => {
             warn!("cli_dispatch::dispatch called with Transcribe; handled separately");
         }
+        CliCommand::Process { .. } => {
+            warn!("cli_dispatch::dispatch called with Process; handled separately");
+        }
     }
 }
 
@@ -226,9 +226,12 @@ extern "C" fn suppress_callback(
             .filter(|k| pressed_vks.contains(k))
             .all(|k| binding.keys.contains(k));
         if all_match && no_extra {
-            // Only suppress the cancel shortcut while actively recording.
-            // Otherwise keys like Escape must pass through to other apps.
-            if binding.action == ShortcutAction::CancelRecording {
+            // Only suppress the cancel and pause shortcuts while actively
+            // recording. Otherwise keys like Escape must pass through to other apps.
+            if matches!(
+                binding.action,
+                ShortcutAction::CancelRecording | ShortcutAction::PauseRecording
+            ) {
                 let source = recording_state().source.lock();
                 if *source == RecordingSource::None {
                     continue;
@@ -44,6 +44,11 @@ impl ShortcutRegistry {
             ShortcutAction::CancelRecording,
             ActivationMode::PushToTalk,
         );
+        push_if_set(
+            &settings.pause_shortcut,
+            ShortcutAction::PauseRecording,
+            ActivationMode::PushToTalk,
+        );
 
         let mode_shortcuts = [
             (&settings.llm_mode_1_shortcut, 0),
@@ -119,6 +119,15 @@ pub fn handle_shortcut_event(
                 }
             }
         }
+        ShortcutAction::PauseRecording => {
+            if event_type == KeyEventType::Pressed {
+                let recording_source = recording_state().source.lock();
+                if *recording_source != RecordingSource::None {
+                    drop(recording_source);
+                    crate::audio::toggle_pause_recording(app);
+                }
+            }
+        }
         ShortcutAction::ToggleVoiceMode => {
             if event_type == KeyEventType::Pressed {
                 let mut last_switch = recording_state().last_mode_switch.lock();
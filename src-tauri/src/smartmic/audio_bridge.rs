@@ -1,3 +1,4 @@
+use crate::audio::enhancement::enhance_if;
 use crate::audio::helpers::resample;
 
 const SMARTMIC_CHUNK_SAMPLES: usize = 960_000;
@@ -23,8 +24,9 @@ pub fn accumulate_pcm(buffer: &mut Vec<i16>, payload: &[u8]) -> bool {
     true
 }
 
-/// Convert the accumulated Int16 buffer to Vec<f32>, resampling if needed
-pub fn finalize_buffer(buffer: Vec<i16>, source_sample_rate: u32) -> Vec<f32> {
+/// Convert the accumulated Int16 buffer to Vec<f32>, resampling if needed and
+/// cleaning up the audio when `enhance` is set
+pub fn finalize_buffer(buffer: Vec<i16>, source_sample_rate: u32, enhance: bool) -> Vec<f32> {
     // Convert i16 to f32 (normalize to -1.0..1.0)
     let samples_f32: Vec<f32> = buffer
         .into_iter()
@@ -32,17 +34,22 @@ pub fn finalize_buffer(buffer: Vec<i16>, source_sample_rate: u32) -> Vec<f32> {
         .collect();
 
     // Resample to 16kHz if needed
-    if source_sample_rate != 16000 {
+    let samples_16k = if source_sample_rate != 16000 {
         resample(&samples_f32, source_sample_rate as usize, 16000)
     } else {
         samples_f32
-    }
+    };
+    enhance_if(enhance, samples_16k)
 }
 
-pub fn split_into_chunks(buffer: Vec<i16>, source_sample_rate: u32) -> Vec<Vec<f32>> {
+pub fn split_into_chunks(
+    buffer: Vec<i16>,
+    source_sample_rate: u32,
+    enhance: bool,
+) -> Vec<Vec<f32>> {
     buffer
         .chunks(SMARTMIC_CHUNK_SAMPLES)
-        .map(|c| finalize_buffer(c.to_vec(), source_sample_rate))
+        .map(|c| finalize_buffer(c.to_vec(), source_sample_rate, enhance))
         .collect()
 }
 
@@ -16,6 +16,17 @@ use tauri::AppHandle;
 use tauri::Manager;
 use tauri_plugin_dialog::DialogExt;
 
+/// Send `message` to the connected device, if any. Dropped when the
+/// device's queue is full.
+pub fn notify_connected_device(app: &AppHandle, message: &types::ServerMessage) {
+    let Some(state) = app.try_state::<SmartMicState>() else {
+        return;
+    };
+    if let Some(device) = state.connected_device.lock().as_ref() {
+        let _ = device.tx.try_send(message.to_json());
+    }
+}
+
 /// Resolve the shared SmartMic data directory, creating it if needed.
 pub fn smartmic_data_dir(app: &AppHandle) -> Result<PathBuf> {
     let dir = app
@@ -167,6 +167,12 @@ pub enum ServerMessage {
     MicLevel {
         level: f32,
     },
+    /// Committed text of the desktop streaming preview for chunk `seq`.
+    /// Empty when the preview is cleared.
+    Preview {
+        seq: u64,
+        text: String,
+    },
     Modes {
         modes: Vec<String>,
     },
@@ -437,7 +437,8 @@ fn process_recording(
     sample_rate: u32,
     should_paste: bool,
 ) {
-    let chunks = audio_bridge::split_into_chunks(buffer, sample_rate);
+    let enhance = crate::settings::load_settings(&app).audio_enhancement;
+    let chunks = audio_bridge::split_into_chunks(buffer, sample_rate, enhance);
 
     // For Translation and LLM modes: always transcribe in Standard mode first,
     // then apply the LLM prompt once on the full joined text.
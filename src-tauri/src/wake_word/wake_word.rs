@@ -1,7 +1,6 @@
 use crate::audio::helpers::resample;
 use crate::audio::types::{AudioState, RecordingMode, RecordingTrigger};
-use crate::audio::vad::{AdaptiveVad, VoiceActivity};
-use crate::engine::transcription_engine::TranscriptionEngine;
+use crate::audio::vad::{VoiceActivity, VoiceDetector};
 use crate::shortcuts::types::{recording_state, RecordingSource};
 use crate::wake_word::types::{WakeWordAction, WakeWordEntry, WakeWordState};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
@@ -331,56 +330,68 @@ fn listener_loop(
 
     let base_config: cpal::StreamConfig = config.clone().into();
 
-    let try_build = |stream_config: &cpal::StreamConfig| -> Result<
-        (cpal::Stream, SharedBuffer),
-        cpal::BuildStreamError,
-    > {
-        let sb = new_shared_buffer(max_samples);
-        let sb_ret = sb.clone();
-        let mut vad_state =
-            VadState::new(max_samples, pre_buffer_capacity, max_overlap_samples, sb);
-        let tx_clone = tx.clone();
-        let stop_clone = stop.clone();
-        let se = stream_error.clone();
-        let err_fn = move |err| {
-            error!("Wake word stream error: {}", err);
-            se.store(true, Ordering::SeqCst);
-        };
-        let stream = match config.sample_format() {
-            cpal::SampleFormat::F32 => device.build_input_stream(
-                stream_config,
-                move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                    if stop_clone.load(Ordering::SeqCst) {
-                        return;
-                    }
-                    process_audio_callback(data, channels, &mut vad_state, &tx_clone);
-                },
-                err_fn,
-                None,
-            )?,
-            cpal::SampleFormat::I16 => device.build_input_stream(
-                stream_config,
-                move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                    if stop_clone.load(Ordering::SeqCst) {
-                        return;
-                    }
-                    let f32_data: Vec<f32> =
-                        data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
-                    process_audio_callback(&f32_data, channels, &mut vad_state, &tx_clone);
-                },
-                err_fn,
-                None,
-            )?,
-            f => {
-                error!("Unsupported sample format: {:?}", f);
-                return Err(cpal::BuildStreamError::StreamConfigNotSupported);
+    let shared_buffer = new_shared_buffer(max_samples);
+    let mut vad_state = VadState::new(
+        max_samples,
+        pre_buffer_capacity,
+        max_overlap_samples,
+        shared_buffer.clone(),
+        VoiceDetector::from_settings(app, sample_rate as u32),
+    );
+    // Voice detection, the neural model included, runs on its own thread:
+    // like the recorder's, the audio callback only downmixes.
+    let (sample_tx, sample_rx) = mpsc::channel::<Vec<f32>>();
+    let vad_thread = std::thread::Builder::new()
+        .name("wake-word-vad".into())
+        .spawn(move || {
+            while let Ok(mono) = sample_rx.recv() {
+                process_samples(&mono, &mut vad_state, &tx);
             }
+        })?;
+
+    let try_build =
+        |stream_config: &cpal::StreamConfig| -> Result<cpal::Stream, cpal::BuildStreamError> {
+            let sample_tx = sample_tx.clone();
+            let stop_clone = stop.clone();
+            let se = stream_error.clone();
+            let err_fn = move |err| {
+                error!("Wake word stream error: {}", err);
+                se.store(true, Ordering::SeqCst);
+            };
+            let stream = match config.sample_format() {
+                cpal::SampleFormat::F32 => device.build_input_stream(
+                    stream_config,
+                    move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                        if stop_clone.load(Ordering::SeqCst) {
+                            return;
+                        }
+                        let _ = sample_tx.send(downmix(data, channels));
+                    },
+                    err_fn,
+                    None,
+                )?,
+                cpal::SampleFormat::I16 => device.build_input_stream(
+                    stream_config,
+                    move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                        if stop_clone.load(Ordering::SeqCst) {
+                            return;
+                        }
+                        let f32_data: Vec<f32> =
+                            data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                        let _ = sample_tx.send(downmix(&f32_data, channels));
+                    },
+                    err_fn,
+                    None,
+                )?,
+                f => {
+                    error!("Unsupported sample format: {:?}", f);
+                    return Err(cpal::BuildStreamError::StreamConfigNotSupported);
+                }
+            };
+            Ok(stream)
         };
-        Ok((stream, sb_ret))
-    };
 
-    let (stream, shared_buffer) =
-        crate::audio::helpers::build_input_with_buffer_fallback(&base_config, try_build)?;
+    let stream = crate::audio::helpers::build_input_with_buffer_fallback(&base_config, try_build)?;
 
     stream
         .play()
@@ -498,10 +509,12 @@ fn listener_loop(
     }
 
     drop(stream);
+    drop(sample_tx);
+    let _ = vad_thread.join();
     Ok(())
 }
 
-/// Shared buffer state between the audio callback thread and the listener loop,
+/// Shared buffer state between the VAD thread and the listener loop,
 /// allowing the listener to periodically snapshot the in-progress buffer for
 /// early partial transcription without waiting for silence or max duration.
 struct SharedBufferInner {
@@ -529,7 +542,7 @@ struct VadState {
     silence_start_time: Option<std::time::Instant>,
     acc_sum_squares: f32,
     acc_count: usize,
-    vad: AdaptiveVad,
+    vad: VoiceDetector,
     last_check: std::time::Instant,
     last_heartbeat: std::time::Instant,
     shared_buffer: SharedBuffer,
@@ -541,6 +554,7 @@ impl VadState {
         pre_buffer_capacity: usize,
         max_overlap_samples: usize,
         shared_buffer: SharedBuffer,
+        vad: VoiceDetector,
     ) -> Self {
         Self {
             buffer: Vec::with_capacity(max_samples),
@@ -553,7 +567,7 @@ impl VadState {
             silence_start_time: None,
             acc_sum_squares: 0.0,
             acc_count: 0,
-            vad: AdaptiveVad::new(),
+            vad,
             last_check: std::time::Instant::now(),
             last_heartbeat: std::time::Instant::now(),
             shared_buffer,
@@ -584,19 +598,22 @@ enum AudioMessage {
     Heartbeat,
 }
 
-fn process_audio_callback(
-    data: &[f32],
-    channels: usize,
-    state: &mut VadState,
-    tx: &mpsc::Sender<AudioMessage>,
-) {
-    for frame in data.chunks_exact(channels) {
-        let sample = if channels == 1 {
-            frame[0]
-        } else {
-            frame.iter().sum::<f32>() / channels as f32
-        };
+fn downmix(data: &[f32], channels: usize) -> Vec<f32> {
+    data.chunks_exact(channels)
+        .map(|frame| {
+            if channels == 1 {
+                frame[0]
+            } else {
+                frame.iter().sum::<f32>() / channels as f32
+            }
+        })
+        .collect()
+}
 
+/// Run the voice detector over the mono samples of one callback and send
+/// the completed speech segments to the listener loop.
+fn process_samples(mono: &[f32], state: &mut VadState, tx: &mpsc::Sender<AudioMessage>) {
+    for &sample in mono {
         state.acc_sum_squares += sample * sample;
         state.acc_count += 1;
 
@@ -611,6 +628,7 @@ fn process_audio_callback(
             state.pre_buffer.push_back(sample);
         }
     }
+    state.vad.push_samples(mono);
 
     if state.last_check.elapsed() < std::time::Duration::from_millis(VAD_CHECK_INTERVAL_MS) {
         return;
@@ -664,7 +682,7 @@ fn process_audio_callback(
         // Sync the shared buffer so the listener loop can snapshot it
         state.sync_shared_buffer();
 
-        if rms < state.vad.silence_threshold() {
+        if state.vad.is_silent(rms) {
             match state.silence_start_time {
                 Some(start) => {
                     if start.elapsed() >= std::time::Duration::from_millis(SPEECH_END_DELAY_MS) {
@@ -734,22 +752,7 @@ fn resample_and_transcribe(
 fn transcribe_segment(app: &AppHandle, samples: Vec<f32>) -> anyhow::Result<String> {
     let audio_state = app.state::<AudioState>();
 
-    {
-        let mut engine_guard = audio_state.engine.lock();
-        if engine_guard.is_none() {
-            let model = app.state::<Arc<crate::model::Model>>();
-            let model_path = model
-                .get_model_path()
-                .map_err(|e| anyhow::anyhow!("Failed to get model path: {}", e))?;
-
-            let new_engine =
-                crate::engine::ParakeetEngine::load_int8(&model_path, model.get_tokenizer_path())
-                    .map_err(|e| anyhow::anyhow!("Failed to load model: {}", e))?;
-
-            *engine_guard = Some(new_engine);
-            debug!("Model loaded for wake word detection");
-        }
-    }
+    crate::audio::preload_engine(app)?;
 
     let mut engine_guard = audio_state.engine.lock();
     let engine = engine_guard
@@ -758,9 +761,12 @@ fn transcribe_segment(app: &AppHandle, samples: Vec<f32>) -> anyhow::Result<Stri
 
     // The engine is shared with dictation; resync so the boost tree reflects
     // the current dictionary instead of whatever the last dictation armed.
+    let dictionary = app.state::<crate::dictionary::Dictionary>();
     crate::dictionary::sync_boost_words(
         engine,
-        &app.state::<crate::dictionary::Dictionary>().get(),
+        &dictionary.get(),
+        &dictionary.get_weights(),
+        &dictionary.get_suppression().words(),
     );
 
     let result = engine
@@ -843,7 +849,8 @@ fn trigger_cancel(app: &AppHandle) {
     info!("Recording cancelled by cancel wake word");
 }
 
-fn get_device(app: &AppHandle) -> anyhow::Result<cpal::Device> {
+/// The selected microphone when it is cached, the system default otherwise.
+pub(crate) fn get_device(app: &AppHandle) -> anyhow::Result<cpal::Device> {
     let audio_state = app.state::<AudioState>();
 
     if let Some(device) = audio_state.get_cached_device() {
@@ -868,13 +875,14 @@ mod tests {
             TEST_SAMPLE_RATE * 400 / 1000,
             TEST_SAMPLE_RATE,
             new_shared_buffer(max_samples),
+            VoiceDetector::adaptive(),
         )
     }
 
     fn process_vad_tick(state: &mut VadState, rms: f32, tx: &mpsc::Sender<AudioMessage>) {
         state.last_check = std::time::Instant::now() - std::time::Duration::from_millis(34);
         let samples = vec![rms; TEST_SAMPLE_RATE * VAD_CHECK_INTERVAL_MS as usize / 1000];
-        process_audio_callback(&samples, 1, state, tx);
+        process_samples(&samples, state, tx);
     }
 
     fn calibrate_vad(state: &mut VadState, rms: f32) {
@@ -0,0 +1,22 @@
+import { useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+
+export const ModelFallbackListener = () => {
+    const { t } = useTranslation();
+
+    useEffect(() => {
+        const unlisten = listen<string>('model-fallback', (event) => {
+            toast.error(t('Switched back to the default speech model: {{error}}', { error: event.payload }), {
+                autoClose: 8000,
+            });
+        });
+
+        return () => {
+            unlisten.then((fn) => fn());
+        };
+    }, [t]);
+
+    return null;
+};
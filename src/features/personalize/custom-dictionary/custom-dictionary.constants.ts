@@ -0,0 +1,21 @@
+import { SuppressionFallback } from './dictionary-suppression-list/dictionary-suppression-list.types';
+
+// Longest entry accepted, in words. Mirrors MAX_PHRASE_WORDS on the Rust side.
+export const MAX_PHRASE_WORDS = 6;
+
+export const DEFAULT_WORD_WEIGHT = 1;
+
+export const WORD_WEIGHT_PRESETS = [
+    { value: 0.5, label: 'Weak boost' },
+    { value: DEFAULT_WORD_WEIGHT, label: 'Normal boost' },
+    { value: 2, label: 'Strong boost' },
+];
+
+// Mask keeps the first letter of a suppressed word that got through: "Bob" -> "B**".
+export const SUPPRESSION_FALLBACKS: { value: SuppressionFallback; label: string }[] = [
+    { value: 'mask', label: 'Mask (B**)' },
+    { value: 'remove', label: 'Remove' },
+    { value: 'off', label: 'Keep' },
+];
+
+export const countWords = (entry: string) => entry.trim().split(/\s+/).filter(Boolean).length;
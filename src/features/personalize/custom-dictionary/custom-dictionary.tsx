@@ -9,10 +9,13 @@ import { DictionaryToolbar } from './dictionary-toolbar/dictionary-toolbar';
 import { DictionaryCounter } from './dictionary-counter/dictionary-counter';
 import { DictionaryEmptyState } from './dictionary-empty-state/dictionary-empty-state';
 import { DictionaryWordList } from './dictionary-word-list/dictionary-word-list';
+import { DictionarySuppressionList } from './dictionary-suppression-list/dictionary-suppression-list';
 import { useMarkRevampSeen } from './hooks/use-mark-revamp-seen';
+import { DEFAULT_WORD_WEIGHT, MAX_PHRASE_WORDS, countWords } from './custom-dictionary.constants';
 
 export const CustomDictionary = () => {
     const [customWords, setCustomWords] = useState<string[]>([]);
+    const [weights, setWeights] = useState<Record<string, number>>({});
     const [dismissed, setDismissed] = useState(false);
     const { t } = useTranslation();
     const { showRevampNotice } = useMarkRevampSeen();
@@ -21,11 +24,19 @@ export const CustomDictionary = () => {
         invoke<string[]>('get_dictionary').then((words) => {
             setCustomWords(words ?? []);
         });
+        invoke<Record<string, number>>('get_dictionary_weights').then((stored) => {
+            setWeights(stored ?? {});
+        });
     }, []);
 
-    const persist = (next: string[]) => {
+    const refreshWeights = () =>
+        invoke<Record<string, number>>('get_dictionary_weights').then((stored) => setWeights(stored ?? {}));
+
+    const persist = (next: string[], nextWeights?: Record<string, number>) => {
         setCustomWords(next);
         invoke('set_dictionary', { dictionary: next })
+            .then(() => (nextWeights == null ? undefined : invoke('set_dictionary_weights', { weights: nextWeights })))
+            .then(refreshWeights)
             .then(() =>
                 toast.info(t('Dictionary updated'), {
                     autoClose: 1500,
@@ -34,7 +45,7 @@ export const CustomDictionary = () => {
             .catch(() => toast.error(t('Failed to update dictionary')));
     };
 
-    const addWord = (word: string) => {
+    const addWord = (word: string, weight = DEFAULT_WORD_WEIGHT) => {
         const trimmed = word.trim();
         if (trimmed.length === 0) return;
         if (customWords.some((w) => w.toLowerCase() === trimmed.toLowerCase())) {
@@ -45,11 +56,11 @@ export const CustomDictionary = () => {
             toast.error(t('Numbers are not supported in the dictionary.'));
             return;
         }
-        if (trimmed.split('').filter((c) => c === ' ').length > 1) {
-            toast.error(t('Only single words or two-word pairs are allowed.'));
+        if (countWords(trimmed) > MAX_PHRASE_WORDS) {
+            toast.error(t('Entries are limited to {{count}} words.', { count: MAX_PHRASE_WORDS }));
             return;
         }
-        persist([...customWords, trimmed]);
+        persist([...customWords, trimmed], weight === DEFAULT_WORD_WEIGHT ? undefined : { [trimmed]: weight });
     };
 
     const handleRemoveWord = (word: string) => {
@@ -98,7 +109,10 @@ export const CustomDictionary = () => {
                 <DictionaryToolbar
                     wordCount={customWords.length}
                     onAdd={addWord}
-                    onWordsChanged={setCustomWords}
+                    onWordsChanged={(words) => {
+                        setCustomWords(words);
+                        refreshWeights();
+                    }}
                     onClear={() => persist([])}
                 />
                 <DictionaryCounter wordCount={customWords.length} />
@@ -109,8 +123,12 @@ export const CustomDictionary = () => {
                         </div>
                     </div>
                 )}
-                {sortedWords.length > 0 && <DictionaryWordList words={sortedWords} onRemove={handleRemoveWord} />}
+                {sortedWords.length > 0 && (
+                    <DictionaryWordList words={sortedWords} weights={weights} onRemove={handleRemoveWord} />
+                )}
             </div>
+
+            <DictionarySuppressionList words={sortedWords} />
         </main>
     );
 };
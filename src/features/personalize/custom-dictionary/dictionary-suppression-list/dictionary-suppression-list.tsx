@@ -0,0 +1,147 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { toast } from 'react-toastify';
+import { Input } from '@/components/input';
+import { Page } from '@/components/page';
+import { Typography } from '@/components/typography';
+import { WordTag } from '@/components/word-tag';
+import { useTranslation } from '@/i18n';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { MAX_PHRASE_WORDS, SUPPRESSION_FALLBACKS, countWords } from '../custom-dictionary.constants';
+import { SuppressionFallback, SuppressionList } from './dictionary-suppression-list.types';
+
+interface DictionarySuppressionListProps {
+    // Dictionary entries, sorted. Suppressed terms are attached to one of them.
+    words: string[];
+}
+
+export const DictionarySuppressionList = ({ words }: DictionarySuppressionListProps) => {
+    const { t } = useTranslation();
+    const [suppression, setSuppression] = useState<SuppressionList>({ entries: {}, fallback: 'mask' });
+    const [newWord, setNewWord] = useState('');
+    const [selectedEntry, setSelectedEntry] = useState('');
+    const entry = words.includes(selectedEntry) ? selectedEntry : (words[0] ?? '');
+
+    // Removing a dictionary word drops its suppressed terms, so reload with the words.
+    useEffect(() => {
+        invoke<SuppressionList>('get_suppression_list').then((stored) => {
+            if (stored != null) setSuppression(stored);
+        });
+    }, [words]);
+
+    const persist = (next: SuppressionList) => {
+        setSuppression(next);
+        invoke('set_suppression_list', { suppression: next })
+            .then(() => toast.info(t('Suppressed words updated'), { autoClose: 1500 }))
+            .catch(() => toast.error(t('Failed to update suppressed words')));
+    };
+
+    const setEntryTerms = (word: string, terms: string[]) => {
+        const entries = { ...suppression.entries, [word]: terms };
+        if (terms.length === 0) delete entries[word];
+        persist({ ...suppression, entries });
+    };
+
+    const handleAddWord = () => {
+        const trimmed = newWord.trim();
+        if (trimmed.length === 0 || entry === '') return;
+        const terms = suppression.entries[entry] ?? [];
+        if (terms.some((w) => w.toLowerCase() === trimmed.toLowerCase())) {
+            toast.warning(t('Word already exists in the suppressed words'));
+            return;
+        }
+        if (/\d/.test(trimmed)) {
+            toast.error(t('Numbers are not supported in the dictionary.'));
+            return;
+        }
+        if (countWords(trimmed) > MAX_PHRASE_WORDS) {
+            toast.error(t('Entries are limited to {{count}} words.', { count: MAX_PHRASE_WORDS }));
+            return;
+        }
+        setEntryTerms(entry, [...terms, trimmed]);
+        setNewWord('');
+    };
+
+    const handleKeyDown = (e: React.KeyboardEvent) => {
+        if (e.key === 'Enter') {
+            e.preventDefault();
+            handleAddWord();
+        }
+    };
+
+    const suppressedEntries = words.filter((word) => (suppression.entries[word] ?? []).length > 0);
+
+    return (
+        <div className="flex flex-col space-y-2 w-full" data-testid="dictionary-suppression-list">
+            <Typography.Title>{t('Suppressed words')}</Typography.Title>
+            <Typography.Paragraph className="text-muted-foreground text-sm">
+                {t(
+                    'Words the transcription must never produce, attached to the dictionary entry they keep replacing. If one still gets through, it is handled as chosen below.'
+                )}
+            </Typography.Paragraph>
+            <div className="flex items-center gap-2">
+                <Input
+                    type="text"
+                    value={newWord}
+                    onChange={(e) => setNewWord(e.target.value)}
+                    onKeyDown={handleKeyDown}
+                    placeholder={t('Add a word to suppress')}
+                    disabled={entry === ''}
+                    data-testid="suppression-input"
+                />
+                <Select value={entry} onValueChange={setSelectedEntry} disabled={entry === ''}>
+                    <SelectTrigger className="w-48 shrink-0" data-testid="suppression-entry-select">
+                        <SelectValue placeholder={t('Add a dictionary word first')} />
+                    </SelectTrigger>
+                    <SelectContent>
+                        {words.map((word) => (
+                            <SelectItem key={word} value={word}>
+                                {t('Instead of {{word}}', { word })}
+                            </SelectItem>
+                        ))}
+                    </SelectContent>
+                </Select>
+                <Select
+                    value={suppression.fallback}
+                    onValueChange={(value) => persist({ ...suppression, fallback: value as SuppressionFallback })}
+                >
+                    <SelectTrigger className="w-40 shrink-0" data-testid="suppression-fallback-select">
+                        <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                        {SUPPRESSION_FALLBACKS.map((fallback) => (
+                            <SelectItem key={fallback.value} value={fallback.value}>
+                                {t(fallback.label)}
+                            </SelectItem>
+                        ))}
+                    </SelectContent>
+                </Select>
+                <Page.PrimaryButton
+                    size="default"
+                    className="!px-4"
+                    onClick={handleAddWord}
+                    disabled={!newWord.trim() || entry === ''}
+                    data-testid="suppression-add-button"
+                >
+                    {t('Add')}
+                </Page.PrimaryButton>
+            </div>
+            {suppressedEntries.map((word) => (
+                <div key={word} className="flex flex-wrap items-center gap-2">
+                    <Typography.Paragraph className="text-muted-foreground text-sm">
+                        {t('Instead of {{word}}', { word })}
+                    </Typography.Paragraph>
+                    {suppression.entries[word].map((term) => (
+                        <WordTag
+                            key={term}
+                            word={term}
+                            variant="removable"
+                            onClick={() => setEntryTerms(word, suppression.entries[word].filter((w) => w !== term))}
+                            data-testid={`suppression-remove-button-${term}`}
+                        />
+                    ))}
+                </div>
+            ))}
+        </div>
+    );
+};
@@ -0,0 +1,8 @@
+// Mirrors SuppressionList on the Rust side.
+export type SuppressionFallback = 'mask' | 'remove' | 'off';
+
+export interface SuppressionList {
+    // Suppressed terms keyed by the dictionary entry they get mistaken for.
+    entries: Record<string, string[]>;
+    fallback: SuppressionFallback;
+}
@@ -9,6 +9,8 @@ import { Button } from '@/components/button';
 import { ExternalLink } from '@/components/external-link';
 import { InternalLink } from '@/components/internal-link';
 import { useTranslation } from '@/i18n';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { DEFAULT_WORD_WEIGHT, MAX_PHRASE_WORDS, WORD_WEIGHT_PRESETS, countWords } from '../custom-dictionary.constants';
 import {
     DropdownMenu,
     DropdownMenuTrigger,
@@ -29,7 +31,7 @@ import {
 
 interface DictionaryToolbarProps {
     wordCount: number;
-    onAdd: (word: string) => void;
+    onAdd: (word: string, weight: number) => void;
     onWordsChanged: (words: string[]) => void;
     onClear: () => void;
 }
@@ -37,17 +39,15 @@ interface DictionaryToolbarProps {
 export const DictionaryToolbar = ({ wordCount, onAdd, onWordsChanged, onClear }: DictionaryToolbarProps) => {
     const { t } = useTranslation();
     const [newWord, setNewWord] = useState('');
+    const [weight, setWeight] = useState(DEFAULT_WORD_WEIGHT);
     const [clearDialogOpen, setClearDialogOpen] = useState(false);
     const containsDigit = /\d/.test(newWord);
-    const hasMultipleSpaces =
-        newWord
-            .trim()
-            .split('')
-            .filter((c) => c === ' ').length > 1;
+    const hasTooManyWords = countWords(newWord) > MAX_PHRASE_WORDS;
 
     const handleAddWord = () => {
-        onAdd(newWord);
+        onAdd(newWord, weight);
         setNewWord('');
+        setWeight(DEFAULT_WORD_WEIGHT);
     };
 
     const handleKeyDown = (e: React.KeyboardEvent) => {
@@ -138,6 +138,18 @@ export const DictionaryToolbar = ({ wordCount, onAdd, onWordsChanged, onClear }:
                     placeholder={t('Add a word')}
                     data-testid="custom-dictionary-input"
                 />
+                <Select value={String(weight)} onValueChange={(value) => setWeight(Number(value))}>
+                    <SelectTrigger className="w-40 shrink-0" data-testid="custom-dictionary-weight-select">
+                        <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                        {WORD_WEIGHT_PRESETS.map((preset) => (
+                            <SelectItem key={preset.value} value={String(preset.value)}>
+                                {t(preset.label)}
+                            </SelectItem>
+                        ))}
+                    </SelectContent>
+                </Select>
                 <Page.PrimaryButton
                     size="default"
                     className="!px-4"
@@ -206,7 +218,9 @@ export const DictionaryToolbar = ({ wordCount, onAdd, onWordsChanged, onClear }:
                 </Dialog>
             </div>
             <p className="text-xs text-muted-foreground">
-                {t('Letters, accents, punctuation and hyphens are supported. Use one space for two-word terms.')}
+                {t(
+                    'Letters, accents, punctuation and hyphens are supported. Phrases of up to 6 words are allowed. A strong boost suits rare terms such as drug names, a weak one common words such as surnames.'
+                )}
             </p>
             {containsDigit && (
                 <div
@@ -226,13 +240,13 @@ export const DictionaryToolbar = ({ wordCount, onAdd, onWordsChanged, onClear }:
                     </span>
                 </div>
             )}
-            {hasMultipleSpaces && (
+            {hasTooManyWords && (
                 <div
                     className="flex items-start gap-1.5 text-xs text-yellow-300/90"
                     data-testid="custom-dictionary-space-warning"
                 >
                     <AlertTriangle className="w-3 h-3 shrink-0 mt-0.5" />
-                    <span>{t('Only single words or two-word pairs are allowed.')}</span>
+                    <span>{t('Entries are limited to {{count}} words.', { count: MAX_PHRASE_WORDS })}</span>
                 </div>
             )}
         </>
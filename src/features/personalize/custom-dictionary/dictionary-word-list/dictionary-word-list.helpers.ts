@@ -15,3 +15,7 @@ export const groupByLetter = (words: string[]) => {
         .map(([letter, groupedWords]) => ({ letter, words: groupedWords }))
         .sort((a, b) => a.letter.localeCompare(b.letter, undefined, { sensitivity: 'base' }));
 };
+
+// Same `word | weight` notation as the dictionary text files.
+export const formatWeightedWord = (word: string, weight: number | undefined) =>
+    weight == null ? word : `${word} | ${weight}`;
@@ -1,12 +1,13 @@
 import { WordTag } from '@/components/word-tag';
-import { GROUPING_THRESHOLD, groupByLetter } from './dictionary-word-list.helpers';
+import { GROUPING_THRESHOLD, formatWeightedWord, groupByLetter } from './dictionary-word-list.helpers';
 
 interface DictionaryWordListProps {
     words: string[];
+    weights: Record<string, number>;
     onRemove: (word: string) => void;
 }
 
-export const DictionaryWordList = ({ words, onRemove }: DictionaryWordListProps) => {
+export const DictionaryWordList = ({ words, weights, onRemove }: DictionaryWordListProps) => {
     if (words.length > GROUPING_THRESHOLD) {
         return (
             <div className="space-y-4">
@@ -17,7 +18,7 @@ export const DictionaryWordList = ({ words, onRemove }: DictionaryWordListProps)
                             {group.words.map((word) => (
                                 <WordTag
                                     key={word}
-                                    word={word}
+                                    word={formatWeightedWord(word, weights[word])}
                                     variant="removable"
                                     onClick={() => onRemove(word)}
                                     data-testid={`custom-dictionary-remove-button-${word}`}
@@ -35,7 +36,7 @@ export const DictionaryWordList = ({ words, onRemove }: DictionaryWordListProps)
             {words.map((word) => (
                 <WordTag
                     key={word}
-                    word={word}
+                    word={formatWeightedWord(word, weights[word])}
                     variant="removable"
                     onClick={() => onRemove(word)}
                     data-testid={`custom-dictionary-remove-button-${word}`}
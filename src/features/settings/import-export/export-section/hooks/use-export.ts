@@ -120,13 +120,21 @@ export const useExport = () => {
 
             if (selectedCategories.includes('dictionary')) {
                 fetchPromises.push(
-                    invoke<string[]>('get_dictionary').then((words) => {
+                    Promise.all([
+                        invoke<string[]>('get_dictionary'),
+                        invoke<Record<string, number>>('get_dictionary_weights'),
+                    ]).then(([words, weights]) => {
                         const subItems = getSubItems('dictionary');
                         if (subItems == null) {
                             categories.dictionary = words;
                         } else {
                             categories.dictionary = words.filter((word) => subItems[SUB_ITEM_KEY.word(word)] !== false);
                         }
+                        const exported = categories.dictionary;
+                        const exportedWeights = Object.entries(weights).filter(([word]) => exported.includes(word));
+                        if (exportedWeights.length > 0) {
+                            categories.dictionary_weights = Object.fromEntries(exportedWeights);
+                        }
                     })
                 );
             }
@@ -68,6 +68,7 @@ export const extractSystemSettings = (all: AppSettings): ExportedSystemSettings
         overlay_position: all.overlay_position,
         api_enabled: all.api_enabled,
         api_port: all.api_port,
+        api_tls_enabled: all.api_tls_enabled,
         copy_to_clipboard: all.copy_to_clipboard,
         paste_method: all.paste_method,
         persist_history: all.persist_history,
@@ -83,6 +84,15 @@ export const extractSystemSettings = (all: AppSettings): ExportedSystemSettings
         streaming_text_width: all.streaming_text_width,
         streaming_font_size: all.streaming_font_size,
         streaming_max_lines: all.streaming_max_lines,
+        decoder_beam_width: all.decoder_beam_width,
+        whisper_language: all.whisper_language,
+        model_quantization: all.model_quantization,
+        model_idle_unload_minutes: all.model_idle_unload_minutes,
+        vad_provider: all.vad_provider,
+        audio_enhancement: all.audio_enhancement,
+        speaker_label_me: all.speaker_label_me,
+        speaker_label_remote: all.speaker_label_remote,
+        pre_roll_ms: all.pre_roll_ms,
     };
 };
 
@@ -127,6 +137,7 @@ export const extractShortcuts = (all: AppSettings): ShortcutSettings => {
         llm_transform_4_shortcut: all.llm_transform_4_shortcut,
         voice_mode_toggle_shortcut: all.voice_mode_toggle_shortcut,
         cancel_shortcut: all.cancel_shortcut,
+        pause_shortcut: all.pause_shortcut,
     };
 };
 
@@ -224,6 +235,7 @@ export const buildFilteredCategories = (
     if (selection.dictionary?.selected && categories.dictionary != null) {
         const subItems = selection.dictionary.subItems;
         filtered.dictionary = categories.dictionary.filter((word) => subItems[SUB_ITEM_KEY.word(word)] === true);
+        filtered.dictionary_weights = categories.dictionary_weights;
     }
 
     return filtered;
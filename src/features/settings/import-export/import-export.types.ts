@@ -13,7 +13,17 @@ import type { SystemSettings, ShortcutSettings, VoiceModeSettings, SmartMicSetti
 
 export type ExportedSystemSettings = Omit<
     SystemSettings,
-    'wayland_notice_dismissed' | 'wayland_clipboard_fallback_dismissed' | 'keep_recordings'
+    | 'wayland_notice_dismissed'
+    | 'wayland_clipboard_fallback_dismissed'
+    | 'keep_recordings'
+    | 'api_bind_address'
+    // Depend on the models installed and the CPU of this machine.
+    | 'asr_backend'
+    | 'active_model'
+    | 'intra_op_threads'
+    | 'inter_op_threads'
+    // Needs PulseAudio or PipeWire on this machine.
+    | 'dual_capture'
 >;
 
 export interface MurmureExportData {
@@ -31,6 +41,8 @@ export interface ExportedCategories {
     formatting_rules?: ExportedFormattingSettings;
     llm_connect?: ExportedLlmConnect;
     dictionary?: string[];
+    // Separate from `dictionary` so older versions still import the words.
+    dictionary_weights?: Record<string, number>;
 }
 
 export interface ExportedFormattingSettings {
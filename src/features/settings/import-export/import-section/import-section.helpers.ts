@@ -14,6 +14,7 @@ const applySettings = async (categories: ExportedCategories): Promise<void> => {
     await invoke('set_overlay_position', { position: settings.overlay_position });
     await invoke('set_api_enabled', { enabled: settings.api_enabled });
     await invoke('set_api_port', { port: settings.api_port });
+    await invoke('set_api_tls_enabled', { enabled: settings.api_tls_enabled ?? false });
     await invoke('set_copy_to_clipboard', { enabled: settings.copy_to_clipboard });
     await invoke('set_paste_method', { method: settings.paste_method });
     await invoke('set_persist_history', { enabled: settings.persist_history });
@@ -47,6 +48,31 @@ const applySettings = async (categories: ExportedCategories): Promise<void> => {
             maxLines: settings.streaming_max_lines,
         });
     }
+    if (settings.decoder_beam_width != null) {
+        await invoke('set_decoder_beam_width', { width: settings.decoder_beam_width });
+    }
+    if (settings.whisper_language !== undefined) {
+        await invoke('set_whisper_language', { language: settings.whisper_language ?? '' });
+    }
+    if (settings.model_quantization != null) {
+        await invoke('set_model_quantization', { quantization: settings.model_quantization });
+    }
+    if (settings.model_idle_unload_minutes != null) {
+        await invoke('set_model_idle_unload', { minutes: settings.model_idle_unload_minutes });
+    }
+    if (settings.vad_provider != null) {
+        // The neural model may not be installed on this machine.
+        await invoke('set_vad_provider', { provider: settings.vad_provider }).catch(() => {});
+    }
+    if (settings.audio_enhancement != null) {
+        await invoke('set_audio_enhancement', { enabled: settings.audio_enhancement });
+    }
+    if (settings.speaker_label_me != null && settings.speaker_label_remote != null) {
+        await invoke('set_speaker_labels', { me: settings.speaker_label_me, remote: settings.speaker_label_remote });
+    }
+    if (settings.pre_roll_ms != null) {
+        await invoke('set_pre_roll_ms', { value: settings.pre_roll_ms });
+    }
 };
 
 const applyVoiceMode = async (categories: ExportedCategories): Promise<void> => {
@@ -115,6 +141,9 @@ const applyShortcuts = async (categories: ExportedCategories): Promise<void> =>
         await invoke('set_llm_transform_shortcut', { index: 3, binding: shortcuts.llm_transform_4_shortcut });
     }
     await invoke('set_cancel_shortcut', { binding: shortcuts.cancel_shortcut });
+    if (shortcuts.pause_shortcut != null) {
+        await invoke('set_pause_shortcut', { binding: shortcuts.pause_shortcut });
+    }
 };
 
 const applyFormattingRules = async (categories: ExportedCategories, strategy: ImportStrategy): Promise<void> => {
@@ -229,11 +258,19 @@ const applyDictionary = async (categories: ExportedCategories, strategy: ImportS
         return;
     }
 
+    const importedWeights = categories.dictionary_weights ?? {};
     if (strategy === 'merge') {
         const current = await invoke<string[]>('get_dictionary');
         await invoke('set_dictionary', { dictionary: mergeDictionaries(current, imported) });
+        const weights = Object.fromEntries(
+            imported.filter((word) => importedWeights[word] != null).map((word) => [word, importedWeights[word]])
+        );
+        await invoke('set_dictionary_weights', { weights });
     } else {
         await invoke('set_dictionary', { dictionary: imported });
+        // Replacing also resets the weights the file does not set.
+        const weights = Object.fromEntries(imported.map((word) => [word, importedWeights[word] ?? 1]));
+        await invoke('set_dictionary_weights', { weights });
     }
 };
 
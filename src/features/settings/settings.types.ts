@@ -4,6 +4,8 @@ export interface SystemSettings {
     overlay_position: string;
     api_enabled: boolean;
     api_port: number;
+    api_bind_address: string | null;
+    api_tls_enabled: boolean;
     copy_to_clipboard: boolean;
     paste_method: string;
     persist_history: boolean;
@@ -20,6 +22,20 @@ export interface SystemSettings {
     streaming_text_width: number;
     streaming_font_size: number;
     streaming_max_lines: number;
+    decoder_beam_width: number;
+    asr_backend: 'parakeet' | 'whisper';
+    active_model: string | null;
+    whisper_language: string | null;
+    model_quantization: 'int8' | 'fp32';
+    intra_op_threads: number | null;
+    inter_op_threads: number | null;
+    model_idle_unload_minutes: number;
+    vad_provider: 'adaptive' | 'neural';
+    audio_enhancement: boolean;
+    dual_capture: boolean;
+    speaker_label_me: string;
+    speaker_label_remote: string;
+    pre_roll_ms: number;
     wayland_notice_dismissed: boolean;
     wayland_clipboard_fallback_dismissed: boolean;
 }
@@ -38,6 +54,7 @@ export interface ShortcutSettings {
     llm_transform_4_shortcut: string;
     voice_mode_toggle_shortcut: string;
     cancel_shortcut: string;
+    pause_shortcut: string;
 }
 
 export interface VoiceModeSettings {
@@ -119,6 +119,11 @@ export const SHORTCUT_CONFIGS = {
         getCommand: 'get_cancel_shortcut',
         setCommand: 'set_cancel_shortcut',
     },
+    pause: {
+        defaultShortcut: 'ctrl+alt+space',
+        getCommand: 'get_pause_shortcut',
+        setCommand: 'set_pause_shortcut',
+    },
     voiceModeToggle: {
         defaultShortcut: 'ctrl+shift+0',
         getCommand: 'get_voice_mode_toggle_shortcut',
@@ -33,6 +33,12 @@ export const ShortcutsRegular = () => {
         resetShortcut: resetCancelShortcut,
     } = useShortcut(SHORTCUT_CONFIGS.cancel);
 
+    const {
+        shortcut: pauseShortcut,
+        setShortcut: setPauseShortcut,
+        resetShortcut: resetPauseShortcut,
+    } = useShortcut(SHORTCUT_CONFIGS.pause);
+
     const {
         shortcut: commandShortcut,
         setShortcut: setCommandShortcut,
@@ -86,6 +92,7 @@ export const ShortcutsRegular = () => {
         { id: 'record', name: recordTitle, value: recordShortcut },
         { id: 'lastTranscript', name: t('Paste last transcript'), value: lastTranscriptShortcut },
         { id: 'cancel', name: t('Cancel recording'), value: cancelShortcut },
+        { id: 'pause', name: t('Pause recording'), value: pauseShortcut },
         { id: 'voiceModeToggle', name: t('Toggle Voice Mode'), value: voiceModeToggleShortcut },
         { id: 'command', name: t('Command, free prompt'), value: commandShortcut },
         { id: 'llmMode1', name: dictateLabel(0), value: llmMode1.shortcut },
@@ -179,6 +186,25 @@ export const ShortcutsRegular = () => {
                                 existingShortcuts={othersOf('cancel')}
                             />
                         </SettingsUI.Item>
+                        <SettingsUI.Separator />
+                        <SettingsUI.Item>
+                            <SettingsUI.Description>
+                                <Typography.Title>{t('Pause recording')}</Typography.Title>
+                                <Typography.Paragraph>
+                                    {t('Press ')}
+                                    <RenderKeys keyString={pauseShortcut} />
+                                    {t(' to pause the current recording, and again to resume it.')}
+                                </Typography.Paragraph>
+                            </SettingsUI.Description>
+                            <ShortcutButton
+                                keyName={t('Pause recording')}
+                                shortcut={pauseShortcut}
+                                saveShortcut={setPauseShortcut}
+                                resetShortcut={resetPauseShortcut}
+                                dataTestId="pause-recording-button"
+                                existingShortcuts={othersOf('pause')}
+                            />
+                        </SettingsUI.Item>
                     </SettingsUI.Container>
                 </section>
 
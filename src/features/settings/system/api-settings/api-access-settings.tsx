@@ -0,0 +1,114 @@
+import { Copy, KeyRound } from 'lucide-react';
+import { toast } from 'react-toastify';
+import { Button } from '@/components/button';
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Switch } from '@/components/switch';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { useTranslation } from '@/i18n';
+import { useApiAccess } from './hooks/use-api-access';
+
+const BIND_ADDRESS_LOCALHOST = 'localhost';
+const BIND_ADDRESS_ALL = '0.0.0.0';
+
+interface ApiAccessSettingsProps {
+    apiEnabled: boolean;
+    restartServer: () => Promise<void>;
+}
+
+export const ApiAccessSettings = ({ apiEnabled, restartServer }: ApiAccessSettingsProps) => {
+    const { t } = useTranslation();
+    const access = useApiAccess({ apiEnabled, restartServer });
+    const isExposed = access.bindAddress != null;
+
+    const handleBindAddressChange = (value: string) => {
+        access.saveBindAddress(value === BIND_ADDRESS_LOCALHOST ? null : value);
+    };
+
+    const handleCopyToken = async () => {
+        if (access.newToken == null || navigator.clipboard?.writeText === undefined) {
+            return;
+        }
+        await navigator.clipboard.writeText(access.newToken);
+        toast.success(t('Copied to clipboard'));
+    };
+
+    return (
+        <>
+            <SettingsUI.Separator />
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title className="flex items-center gap-2">
+                        <KeyRound className="w-4 h-4 text-muted-foreground" />
+                        {t('API token')}
+                    </Typography.Title>
+                    <Typography.Paragraph className="space-y-2">
+                        <div>
+                            {t(
+                                'When set, every request must send the header "Authorization: Bearer <token>". Required to expose the API on the network.'
+                            )}
+                        </div>
+                        {access.newToken != null && (
+                            <>
+                                <div className="flex items-center gap-2">
+                                    <code className="text-xs block border p-2 break-all">{access.newToken}</code>
+                                    <Button variant="ghost" size="sm" onClick={handleCopyToken} title={t('Copy')}>
+                                        <Copy className="w-3.5 h-3.5" />
+                                    </Button>
+                                </div>
+                                <div className="text-xs">{t('Copy this token now, it will not be shown again.')}</div>
+                            </>
+                        )}
+                    </Typography.Paragraph>
+                </SettingsUI.Description>
+                <div className="flex items-center gap-2">
+                    <Button variant="outline" size="sm" onClick={access.generateToken}>
+                        {access.hasToken ? t('Regenerate') : t('Generate')}
+                    </Button>
+                    {access.hasToken && (
+                        <Button variant="outline" size="sm" onClick={access.revokeToken} disabled={isExposed}>
+                            {t('Revoke')}
+                        </Button>
+                    )}
+                </div>
+            </SettingsUI.Item>
+            <SettingsUI.Separator />
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title>{t('Network access')}</Typography.Title>
+                    <Typography.Paragraph>
+                        {t(
+                            'Listen on a network interface so other computers or containers can use the API. Requires an API token.'
+                        )}
+                    </Typography.Paragraph>
+                </SettingsUI.Description>
+                <Select value={access.bindAddress ?? BIND_ADDRESS_LOCALHOST} onValueChange={handleBindAddressChange}>
+                    <SelectTrigger className="w-72">
+                        <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent className="max-h-96">
+                        <SelectItem value={BIND_ADDRESS_LOCALHOST}>{t('This computer only (127.0.0.1)')}</SelectItem>
+                        <SelectItem value={BIND_ADDRESS_ALL} disabled={!access.hasToken}>
+                            {t('All interfaces (0.0.0.0)')}
+                        </SelectItem>
+                        {access.availableInterfaces.map((iface) => (
+                            <SelectItem key={iface.ip} value={iface.ip} disabled={!access.hasToken}>
+                                {iface.ip} ({iface.name})
+                            </SelectItem>
+                        ))}
+                    </SelectContent>
+                </Select>
+            </SettingsUI.Item>
+            <SettingsUI.Separator />
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title>{t('HTTPS')}</Typography.Title>
+                    <Typography.Paragraph>
+                        {t('Serve the API over HTTPS with the self-signed certificate also used by Smart Mic.')}
+                    </Typography.Paragraph>
+                </SettingsUI.Description>
+                <Switch checked={access.tlsEnabled} onCheckedChange={access.saveTlsEnabled} />
+            </SettingsUI.Item>
+        </>
+    );
+};
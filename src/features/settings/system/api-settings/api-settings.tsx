@@ -2,13 +2,14 @@ import { NumberInput } from '@/components/number-input';
 import { SettingsUI } from '@/components/settings-ui';
 import { Typography } from '@/components/typography';
 import { useApiState } from './hooks/use-api-state';
+import { ApiAccessSettings } from './api-access-settings';
 import { FileCode2, Zap } from 'lucide-react';
 import { Switch } from '@/components/switch';
 import { ExternalLink } from '@/components/external-link';
 import { useTranslation } from '@/i18n';
 
 export const APISettings = () => {
-    const { apiEnabled, setApiEnabled, apiPort, setApiPort } = useApiState();
+    const { apiEnabled, setApiEnabled, apiPort, setApiPort, restartServer } = useApiState();
     const { t } = useTranslation();
 
     return (
@@ -53,6 +54,7 @@ export const APISettings = () => {
                             onValueChange={(value) => setApiPort(value ?? 4800)}
                         />
                     </SettingsUI.Item>
+                    <ApiAccessSettings apiEnabled={apiEnabled} restartServer={restartServer} />
                 </>
             )}
         </>
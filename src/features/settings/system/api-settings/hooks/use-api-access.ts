@@ -0,0 +1,107 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useState, useEffect, useCallback } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+
+export interface NetworkInterface {
+    name: string;
+    ip: string;
+}
+
+interface UseApiAccessOptions {
+    apiEnabled: boolean;
+    restartServer: () => Promise<void>;
+}
+
+export const useApiAccess = ({ apiEnabled, restartServer }: UseApiAccessOptions) => {
+    const [bindAddress, setBindAddress] = useState<string | null>(null);
+    const [availableInterfaces, setAvailableInterfaces] = useState<NetworkInterface[]>([]);
+    const [tlsEnabled, setTlsEnabled] = useState<boolean>(false);
+    const [hasToken, setHasToken] = useState<boolean>(false);
+    // Shown once right after generation; the keyring keeps it afterwards.
+    const [newToken, setNewToken] = useState<string | null>(null);
+    const { t } = useTranslation();
+
+    const load = useCallback(async () => {
+        try {
+            setBindAddress(await invoke<string | null>('get_api_bind_address'));
+            setAvailableInterfaces(await invoke<NetworkInterface[]>('list_smartmic_network_interfaces'));
+            setTlsEnabled(await invoke<boolean>('get_api_tls_enabled'));
+            setHasToken(await invoke<boolean>('has_http_api_token'));
+        } catch (error) {
+            console.error('Failed to load API access settings:', error);
+        }
+    }, []);
+
+    useEffect(() => {
+        load();
+    }, [load]);
+
+    const restartIfEnabled = async () => {
+        if (!apiEnabled) {
+            return;
+        }
+        try {
+            await restartServer();
+        } catch (error) {
+            console.error('Failed to restart HTTP API server:', error);
+            toast.error(t('Failed to restart API server'));
+        }
+    };
+
+    const saveBindAddress = async (address: string | null) => {
+        try {
+            await invoke('set_api_bind_address', { address });
+            setBindAddress(address);
+            await restartIfEnabled();
+        } catch (error) {
+            console.error('Failed to set API bind address:', error);
+            toast.error(t('Failed to save API bind address'));
+        }
+    };
+
+    const saveTlsEnabled = async (enabled: boolean) => {
+        try {
+            await invoke('set_api_tls_enabled', { enabled });
+            setTlsEnabled(enabled);
+            await restartIfEnabled();
+        } catch (error) {
+            console.error('Failed to set API TLS:', error);
+            toast.error(t('Failed to save HTTPS setting'));
+        }
+    };
+
+    const generateToken = async () => {
+        try {
+            const token = await invoke<string>('generate_http_api_token');
+            setNewToken(token);
+            setHasToken(true);
+        } catch (error) {
+            console.error('Failed to generate API token:', error);
+            toast.error(t('Failed to generate API token'));
+        }
+    };
+
+    const revokeToken = async () => {
+        try {
+            await invoke('revoke_http_api_token');
+            setNewToken(null);
+            setHasToken(false);
+        } catch (error) {
+            console.error('Failed to revoke API token:', error);
+            toast.error(t('Failed to revoke API token'));
+        }
+    };
+
+    return {
+        bindAddress,
+        availableInterfaces,
+        tlsEnabled,
+        hasToken,
+        newToken,
+        saveBindAddress,
+        saveTlsEnabled,
+        generateToken,
+        revokeToken,
+    };
+};
@@ -55,6 +55,12 @@ export const useApiState = () => {
         }
     };
 
+    const restartServer = async () => {
+        await invoke('stop_http_api_server');
+        await new Promise((resolve) => setTimeout(resolve, 100));
+        await invoke('start_http_api_server');
+    };
+
     const handleSetApiPort = async (port: number) => {
         if (port >= 1024 && port <= 65535) {
             try {
@@ -63,9 +69,7 @@ export const useApiState = () => {
 
                 if (apiEnabled) {
                     try {
-                        await invoke('stop_http_api_server');
-                        await new Promise((resolve) => setTimeout(resolve, 100));
-                        await invoke('start_http_api_server');
+                        await restartServer();
                     } catch (error) {
                         console.error('Failed to restart HTTP API server with new port:', error);
                         toast.error(t('Failed to restart API server'));
@@ -81,6 +85,7 @@ export const useApiState = () => {
     return {
         setApiEnabled: handleSetApiEnabled,
         setApiPort: handleSetApiPort,
+        restartServer,
         apiEnabled,
         apiPort,
     };
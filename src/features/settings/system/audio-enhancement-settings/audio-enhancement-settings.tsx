@@ -0,0 +1,32 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Switch } from '@/components/switch';
+import { AudioWaveform } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { useAudioEnhancementState } from './hooks/use-audio-enhancement-state';
+
+export const AudioEnhancementSettings = () => {
+    const { audioEnhancement, setAudioEnhancement } = useAudioEnhancementState();
+    const { t } = useTranslation();
+
+    return (
+        <SettingsUI.Item>
+            <SettingsUI.Description>
+                <Typography.Title className="flex items-center gap-2">
+                    <AudioWaveform className="w-4 h-4 text-muted-foreground" />
+                    {t('Clean up audio')}
+                </Typography.Title>
+                <Typography.Paragraph>
+                    {t(
+                        'Reduce background noise and even out the volume before transcription. Helps with laptop microphones, noisy rooms and quiet voices.'
+                    )}
+                </Typography.Paragraph>
+            </SettingsUI.Description>
+            <Switch
+                checked={audioEnhancement}
+                onCheckedChange={setAudioEnhancement}
+                data-testid="audio-enhancement-switch"
+            />
+        </SettingsUI.Item>
+    );
+};
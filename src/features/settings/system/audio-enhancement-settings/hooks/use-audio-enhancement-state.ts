@@ -0,0 +1,31 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+
+export const useAudioEnhancementState = () => {
+    const [audioEnhancement, setAudioEnhancement] = useState<boolean>(false);
+    const { t } = useTranslation();
+
+    useEffect(() => {
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            setAudioEnhancement(settings.audio_enhancement);
+        });
+    }, []);
+
+    const handleSetAudioEnhancement = async (enabled: boolean) => {
+        try {
+            setAudioEnhancement(enabled);
+            await invoke('set_audio_enhancement', { enabled });
+        } catch {
+            toast.error(t('Failed to save audio cleanup setting'));
+            setAudioEnhancement(!enabled);
+        }
+    };
+
+    return {
+        audioEnhancement,
+        setAudioEnhancement: handleSetAudioEnhancement,
+    };
+};
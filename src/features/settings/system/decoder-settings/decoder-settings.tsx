@@ -0,0 +1,46 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { Binary } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { useDecoderState } from './hooks/use-decoder-state';
+
+const BEAM_WIDTHS = [
+    { value: 1, label: 'Greedy (fastest)' },
+    { value: 2, label: 'Beam search, 2 hypotheses' },
+    { value: 4, label: 'Beam search, 4 hypotheses' },
+    { value: 8, label: 'Beam search, 8 hypotheses' },
+];
+
+export const DecoderSettings = () => {
+    const { t } = useTranslation();
+    const { beamWidth, setBeamWidth } = useDecoderState();
+
+    return (
+        <SettingsUI.Item>
+            <SettingsUI.Description>
+                <Typography.Title className="flex items-center gap-2">
+                    <Binary className="w-4 h-4 text-muted-foreground" />
+                    {t('Decoder')}
+                </Typography.Title>
+                <Typography.Paragraph>
+                    {t(
+                        'Beam search keeps several hypotheses, so dictionary words can win when they are the second-best guess. Transcription gets slower as the beam grows.'
+                    )}
+                </Typography.Paragraph>
+            </SettingsUI.Description>
+            <Select value={String(beamWidth)} onValueChange={(value) => setBeamWidth(Number(value))}>
+                <SelectTrigger className="w-[240px]" data-testid="decoder-beam-width-select">
+                    <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                    {BEAM_WIDTHS.map((width) => (
+                        <SelectItem key={width.value} value={String(width.value)}>
+                            {t(width.label)}
+                        </SelectItem>
+                    ))}
+                </SelectContent>
+            </Select>
+        </SettingsUI.Item>
+    );
+};
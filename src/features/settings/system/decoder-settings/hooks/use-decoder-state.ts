@@ -0,0 +1,34 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+
+export const useDecoderState = () => {
+    const [beamWidth, setBeamWidth] = useState<number>(1);
+    const { t } = useTranslation();
+
+    useEffect(() => {
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            if (typeof settings.decoder_beam_width === 'number') {
+                setBeamWidth(settings.decoder_beam_width);
+            }
+        });
+    }, []);
+
+    const handleSetBeamWidth = async (width: number) => {
+        const previous = beamWidth;
+        try {
+            setBeamWidth(width);
+            await invoke('set_decoder_beam_width', { width });
+        } catch {
+            toast.error(t('Failed to save decoder setting'));
+            setBeamWidth(previous);
+        }
+    };
+
+    return {
+        beamWidth,
+        setBeamWidth: handleSetBeamWidth,
+    };
+};
@@ -0,0 +1,64 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Switch } from '@/components/switch';
+import { Input } from '@/components/input';
+import { MonitorSpeaker } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { useDualCaptureState } from './hooks/use-dual-capture-state';
+
+export const DualCaptureSettings = () => {
+    const { dualCapture, setDualCapture, speakerLabels, setSpeakerLabels, saveSpeakerLabels } = useDualCaptureState();
+    const { t } = useTranslation();
+
+    return (
+        <>
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title className="flex items-center gap-2">
+                        <MonitorSpeaker className="w-4 h-4 text-muted-foreground" />
+                        {t('Also record system audio')}
+                    </Typography.Title>
+                    <Typography.Paragraph>
+                        {t(
+                            'Transcribe what the computer plays next to your microphone, for calls and webinars. Each line starts with the label of its speaker. Works best with headphones.'
+                        )}
+                    </Typography.Paragraph>
+                </SettingsUI.Description>
+                <Switch checked={dualCapture} onCheckedChange={setDualCapture} data-testid="dual-capture-switch" />
+            </SettingsUI.Item>
+            {dualCapture && (
+                <>
+                    <SettingsUI.Separator />
+                    <SettingsUI.Item>
+                        <SettingsUI.Description>
+                            <Typography.Title>{t('Speaker labels')}</Typography.Title>
+                            <Typography.Paragraph>
+                                {t('Names written before the lines of your microphone and of the computer.')}
+                            </Typography.Paragraph>
+                        </SettingsUI.Description>
+                        <div className="flex items-center gap-2">
+                            <Input
+                                value={speakerLabels.me}
+                                onChange={(e) => setSpeakerLabels({ ...speakerLabels, me: e.target.value })}
+                                onBlur={saveSpeakerLabels}
+                                maxLength={30}
+                                aria-label={t('Microphone label')}
+                                data-testid="speaker-label-me"
+                                className="w-32"
+                            />
+                            <Input
+                                value={speakerLabels.remote}
+                                onChange={(e) => setSpeakerLabels({ ...speakerLabels, remote: e.target.value })}
+                                onBlur={saveSpeakerLabels}
+                                maxLength={30}
+                                aria-label={t('System audio label')}
+                                data-testid="speaker-label-remote"
+                                className="w-32"
+                            />
+                        </div>
+                    </SettingsUI.Item>
+                </>
+            )}
+        </>
+    );
+};
@@ -0,0 +1,62 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useRef, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+
+export interface SpeakerLabels {
+    me: string;
+    remote: string;
+}
+
+export const useDualCaptureState = () => {
+    const [dualCapture, setDualCapture] = useState<boolean>(false);
+    const [speakerLabels, setSpeakerLabels] = useState<SpeakerLabels>({ me: '', remote: '' });
+    const savedLabels = useRef<SpeakerLabels>({ me: '', remote: '' });
+    const { t } = useTranslation();
+
+    const loadSettings = () =>
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            setDualCapture(settings.dual_capture);
+            const labels = { me: settings.speaker_label_me, remote: settings.speaker_label_remote };
+            setSpeakerLabels(labels);
+            savedLabels.current = labels;
+        });
+
+    useEffect(() => {
+        loadSettings();
+    }, []);
+
+    const handleSetDualCapture = async (enabled: boolean) => {
+        try {
+            setDualCapture(enabled);
+            await invoke('set_dual_capture', { enabled });
+            // The first activation may switch the labels to the UI language.
+            await loadSettings();
+        } catch (error) {
+            toast.error(t('Failed to save system audio setting') + ' : ' + error);
+            setDualCapture(!enabled);
+        }
+    };
+
+    const saveSpeakerLabels = async () => {
+        if (speakerLabels.me === savedLabels.current.me && speakerLabels.remote === savedLabels.current.remote) {
+            return;
+        }
+        try {
+            await invoke('set_speaker_labels', { me: speakerLabels.me, remote: speakerLabels.remote });
+            savedLabels.current = speakerLabels;
+        } catch (error) {
+            toast.error(t('Failed to save speaker labels') + ' : ' + error);
+            setSpeakerLabels(savedLabels.current);
+        }
+    };
+
+    return {
+        dualCapture,
+        setDualCapture: handleSetDualCapture,
+        speakerLabels,
+        setSpeakerLabels,
+        saveSpeakerLabels,
+    };
+};
@@ -0,0 +1,84 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+import { ModelInfo } from '@/features/settings/system/speech-model-settings/speech-model-settings.types';
+
+type Quantization = AppSettings['model_quantization'];
+
+// Empty input means automatic.
+const parseThreads = (value: string) => {
+    const n = Number.parseInt(value, 10);
+    return Number.isFinite(n) && n > 0 ? n : null;
+};
+
+export const useInferenceState = () => {
+    const [quantization, setQuantization] = useState<Quantization>('int8');
+    const [intraOpThreads, setIntraOpThreads] = useState('');
+    const [interOpThreads, setInterOpThreads] = useState('');
+    const [idleUnloadMinutes, setIdleUnloadMinutes] = useState(0);
+    // Precisions the active model has files for; empty until loaded.
+    const [precisions, setPrecisions] = useState<Quantization[]>([]);
+    const { t } = useTranslation();
+
+    useEffect(() => {
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            if (settings.model_quantization != null) {
+                setQuantization(settings.model_quantization);
+            }
+            setIntraOpThreads(settings.intra_op_threads?.toString() ?? '');
+            setInterOpThreads(settings.inter_op_threads?.toString() ?? '');
+            setIdleUnloadMinutes(settings.model_idle_unload_minutes ?? 0);
+        });
+        invoke<ModelInfo[]>('list_models').then((models) => {
+            setPrecisions(models.find((model) => model.active)?.precisions ?? []);
+        });
+    }, []);
+
+    const handleSetQuantization = async (next: Quantization) => {
+        const previous = quantization;
+        try {
+            setQuantization(next);
+            await invoke('set_model_quantization', { quantization: next });
+        } catch {
+            toast.error(t('Failed to save inference setting'));
+            setQuantization(previous);
+        }
+    };
+
+    const saveThreads = async () => {
+        try {
+            await invoke('set_inference_threads', {
+                intraOp: parseThreads(intraOpThreads),
+                interOp: parseThreads(interOpThreads),
+            });
+        } catch (error) {
+            toast.error(t('Failed to save inference setting') + ' : ' + error);
+        }
+    };
+
+    const handleSetIdleUnloadMinutes = async (minutes: number) => {
+        const previous = idleUnloadMinutes;
+        try {
+            setIdleUnloadMinutes(minutes);
+            await invoke('set_model_idle_unload', { minutes });
+        } catch {
+            toast.error(t('Failed to save inference setting'));
+            setIdleUnloadMinutes(previous);
+        }
+    };
+
+    return {
+        quantization,
+        setQuantization: handleSetQuantization,
+        precisions,
+        intraOpThreads,
+        setIntraOpThreads,
+        interOpThreads,
+        setInterOpThreads,
+        saveThreads,
+        idleUnloadMinutes,
+        setIdleUnloadMinutes: handleSetIdleUnloadMinutes,
+    };
+};
@@ -0,0 +1,142 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Input } from '@/components/input';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { Cpu } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+import { useInferenceState } from './hooks/use-inference-state';
+
+const QUANTIZATIONS: { value: AppSettings['model_quantization']; label: string }[] = [
+    { value: 'int8', label: 'INT8 (faster)' },
+    { value: 'fp32', label: 'FP32 (more accurate)' },
+];
+
+const IDLE_UNLOAD_OPTIONS: { value: number; label: string }[] = [
+    { value: 0, label: 'Never' },
+    { value: 5, label: '5 minutes' },
+    { value: 15, label: '15 minutes' },
+    { value: 30, label: '30 minutes' },
+    { value: 60, label: '1 hour' },
+];
+
+export const InferenceSettings = () => {
+    const { t } = useTranslation();
+    const {
+        quantization,
+        setQuantization,
+        precisions,
+        intraOpThreads,
+        setIntraOpThreads,
+        interOpThreads,
+        setInterOpThreads,
+        saveThreads,
+        idleUnloadMinutes,
+        setIdleUnloadMinutes,
+    } = useInferenceState();
+    // The engine loads the only precision a model ships, whatever is selected.
+    const loadedPrecision = precisions.length === 1 ? precisions[0] : quantization;
+
+    return (
+        <>
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title className="flex items-center gap-2">
+                        <Cpu className="w-4 h-4 text-muted-foreground" />
+                        {t('Model precision')}
+                    </Typography.Title>
+                    <Typography.Paragraph>
+                        {t(
+                            'FP32 is slightly more accurate but slower and uses more memory. It needs the FP32 files of the model.'
+                        )}
+                    </Typography.Paragraph>
+                    {loadedPrecision !== quantization && (
+                        <Typography.Paragraph className="text-xs text-yellow-300/90" data-testid="model-precision-warning">
+                            {t('The active model only ships {{precision}} files, so {{precision}} is used.', {
+                                precision: loadedPrecision.toUpperCase(),
+                            })}
+                        </Typography.Paragraph>
+                    )}
+                </SettingsUI.Description>
+                <Select
+                    value={quantization}
+                    onValueChange={(value) => setQuantization(value as AppSettings['model_quantization'])}
+                >
+                    <SelectTrigger className="w-[240px]" data-testid="model-quantization-select">
+                        <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                        {QUANTIZATIONS.map((option) => (
+                            <SelectItem
+                                key={option.value}
+                                value={option.value}
+                                disabled={precisions.length > 0 && !precisions.includes(option.value)}
+                            >
+                                {t(option.label)}
+                            </SelectItem>
+                        ))}
+                    </SelectContent>
+                </Select>
+            </SettingsUI.Item>
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title>{t('Inference threads')}</Typography.Title>
+                    <Typography.Paragraph>
+                        {t(
+                            'Threads used inside each operation, then the number of operations run in parallel. Fewer threads keep laptops cooler and quieter; leave empty to decide automatically.'
+                        )}
+                    </Typography.Paragraph>
+                </SettingsUI.Description>
+                <div className="flex items-center gap-2">
+                    <Input
+                        type="number"
+                        min={1}
+                        value={intraOpThreads}
+                        onChange={(e) => setIntraOpThreads(e.target.value)}
+                        onBlur={saveThreads}
+                        placeholder={t('Automatic')}
+                        title={t('Threads per operation')}
+                        className="w-[116px]"
+                        data-testid="intra-op-threads-input"
+                    />
+                    <Input
+                        type="number"
+                        min={1}
+                        value={interOpThreads}
+                        onChange={(e) => setInterOpThreads(e.target.value)}
+                        onBlur={saveThreads}
+                        placeholder={t('Automatic')}
+                        title={t('Parallel operations')}
+                        className="w-[116px]"
+                        data-testid="inter-op-threads-input"
+                    />
+                </div>
+            </SettingsUI.Item>
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title>{t('Unload model when idle')}</Typography.Title>
+                    <Typography.Paragraph>
+                        {t(
+                            'Frees the model memory after this time without transcription. It is loaded again on the next recording, which then takes a few seconds to start. Ignored while Voice Mode is on.'
+                        )}
+                    </Typography.Paragraph>
+                </SettingsUI.Description>
+                <Select
+                    value={idleUnloadMinutes.toString()}
+                    onValueChange={(value) => setIdleUnloadMinutes(Number(value))}
+                >
+                    <SelectTrigger className="w-[240px]" data-testid="model-idle-unload-select">
+                        <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                        {IDLE_UNLOAD_OPTIONS.map((option) => (
+                            <SelectItem key={option.value} value={option.value.toString()}>
+                                {t(option.label)}
+                            </SelectItem>
+                        ))}
+                    </SelectContent>
+                </Select>
+            </SettingsUI.Item>
+        </>
+    );
+};
@@ -8,6 +8,7 @@ const AUTOMATIC_MIC_ID = 'automatic';
 export interface MicInfo {
     id: string;
     label: string;
+    is_monitor?: boolean;
 }
 
 export const useMicState = () => {
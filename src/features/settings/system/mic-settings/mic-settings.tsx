@@ -2,13 +2,24 @@ import { SettingsUI } from '@/components/settings-ui';
 import { Typography } from '@/components/typography';
 import { Button } from '@/components/button';
 import { Mic, RefreshCw } from 'lucide-react';
-import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import {
+    Select,
+    SelectContent,
+    SelectGroup,
+    SelectItem,
+    SelectLabel,
+    SelectSeparator,
+    SelectTrigger,
+    SelectValue,
+} from '@/components/select';
 import { useTranslation } from '@/i18n';
 import { useMicState } from './hooks/use-mic-state';
 
 export const MicSettings = () => {
     const { t } = useTranslation();
     const { currentMic, setMic, micList, isLoading, refreshMicList } = useMicState();
+    const inputs = micList.filter((mic) => !mic.is_monitor);
+    const monitors = micList.filter((mic) => mic.is_monitor);
 
     return (
         <SettingsUI.Item>
@@ -25,11 +36,24 @@ export const MicSettings = () => {
                         <SelectValue />
                     </SelectTrigger>
                     <SelectContent className="max-h-96">
-                        {micList.map((mic) => (
+                        {inputs.map((mic) => (
                             <SelectItem key={mic.id} value={mic.id}>
                                 {mic.label}
                             </SelectItem>
                         ))}
+                        {monitors.length > 0 && (
+                            <>
+                                <SelectSeparator />
+                                <SelectGroup>
+                                    <SelectLabel>{t('System audio')}</SelectLabel>
+                                    {monitors.map((mic) => (
+                                        <SelectItem key={mic.id} value={mic.id}>
+                                            {mic.label}
+                                        </SelectItem>
+                                    ))}
+                                </SelectGroup>
+                            </>
+                        )}
                     </SelectContent>
                 </Select>
                 <Button
@@ -0,0 +1,34 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+
+export const usePreRollState = () => {
+    const [preRollMs, setPreRollMs] = useState(0);
+    const { t } = useTranslation();
+
+    useEffect(() => {
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            if (settings.pre_roll_ms != null) {
+                setPreRollMs(settings.pre_roll_ms);
+            }
+        });
+    }, []);
+
+    const handleSetPreRollMs = async (value: number) => {
+        const previous = preRollMs;
+        try {
+            setPreRollMs(value);
+            await invoke('set_pre_roll_ms', { value });
+        } catch (error) {
+            toast.error(t('Failed to save pre-roll setting') + ' : ' + error);
+            setPreRollMs(previous);
+        }
+    };
+
+    return {
+        preRollMs,
+        setPreRollMs: handleSetPreRollMs,
+    };
+};
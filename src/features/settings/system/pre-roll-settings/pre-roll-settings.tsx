@@ -0,0 +1,46 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { Rewind } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { usePreRollState } from './hooks/use-pre-roll-state';
+
+const DURATIONS: { value: number; label: string }[] = [
+    { value: 0, label: 'Off' },
+    { value: 300, label: '300 ms' },
+    { value: 500, label: '500 ms' },
+    { value: 1000, label: '1 s' },
+];
+
+export const PreRollSettings = () => {
+    const { t } = useTranslation();
+    const { preRollMs, setPreRollMs } = usePreRollState();
+
+    return (
+        <SettingsUI.Item>
+            <SettingsUI.Description>
+                <Typography.Title className="flex items-center gap-2">
+                    <Rewind className="w-4 h-4 text-muted-foreground" />
+                    {t('Pre-roll')}
+                </Typography.Title>
+                <Typography.Paragraph>
+                    {t(
+                        'Keeps the start of words said just before the shortcut. The microphone stays open while Murmure runs, so the system shows it as in use. Nothing is saved outside of a recording.'
+                    )}
+                </Typography.Paragraph>
+            </SettingsUI.Description>
+            <Select value={String(preRollMs)} onValueChange={(value) => setPreRollMs(Number(value))}>
+                <SelectTrigger className="w-[240px]" data-testid="pre-roll-select">
+                    <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                    {DURATIONS.map((option) => (
+                        <SelectItem key={option.value} value={String(option.value)}>
+                            {t(option.label)}
+                        </SelectItem>
+                    ))}
+                </SelectContent>
+            </Select>
+        </SettingsUI.Item>
+    );
+};
@@ -0,0 +1,70 @@
+import { invoke } from '@tauri-apps/api/core';
+import { open } from '@tauri-apps/plugin-dialog';
+import { useEffect, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+import { ModelInfo } from '../speech-model-settings.types';
+
+export const useSpeechModelState = () => {
+    const [models, setModels] = useState<ModelInfo[]>([]);
+    const [language, setLanguage] = useState('');
+    const [isImporting, setIsImporting] = useState(false);
+    const { t } = useTranslation();
+
+    const loadModels = () => invoke<ModelInfo[]>('list_models').then(setModels);
+
+    useEffect(() => {
+        invoke<ModelInfo[]>('list_models').then(setModels);
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            setLanguage(settings.whisper_language ?? '');
+        });
+    }, []);
+
+    const activeModel = models.find((model) => model.active);
+
+    const handleSetActiveModel = async (id: string) => {
+        try {
+            await invoke('set_active_model', { id });
+            await loadModels();
+        } catch (error) {
+            toast.error(t('Failed to switch speech model') + ' : ' + error);
+        }
+    };
+
+    const importModel = async () => {
+        const path = await open({ directory: true, multiple: false, title: t('Select a model folder') });
+        if (path == null) {
+            return;
+        }
+        setIsImporting(true);
+        try {
+            const model = await invoke<ModelInfo>('import_model', { path });
+            await loadModels();
+            toast.success(t('Model "{{name}}" imported', { name: model.name }));
+        } catch (error) {
+            toast.error(t('Failed to import the model') + ' : ' + error);
+        } finally {
+            setIsImporting(false);
+        }
+    };
+
+    const saveLanguage = async () => {
+        try {
+            await invoke('set_whisper_language', { language });
+        } catch {
+            toast.error(t('Failed to save speech model setting'));
+        }
+    };
+
+    return {
+        models,
+        activeModel,
+        setActiveModel: handleSetActiveModel,
+        importModel,
+        isImporting,
+        language,
+        setLanguage,
+        saveLanguage,
+    };
+};
@@ -0,0 +1,91 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Input } from '@/components/input';
+import { Button } from '@/components/button';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { AudioLines, FolderInput } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { useSpeechModelState } from './hooks/use-speech-model-state';
+import { ModelInfo } from './speech-model-settings.types';
+
+const formatSize = (bytes: number) => `${(bytes / 1_000_000).toFixed(0)} MB`;
+
+const BACKEND_LABELS: Record<ModelInfo['backend'], string> = { parakeet: 'Parakeet', whisper: 'Whisper' };
+
+const describeModel = (model: ModelInfo) =>
+    [BACKEND_LABELS[model.backend], model.quantization.toUpperCase(), formatSize(model.size_bytes)].join(' · ');
+
+export const SpeechModelSettings = () => {
+    const { t } = useTranslation();
+    const { models, activeModel, setActiveModel, importModel, isImporting, language, setLanguage, saveLanguage } =
+        useSpeechModelState();
+
+    return (
+        <>
+            <SettingsUI.Item>
+                <SettingsUI.Description>
+                    <Typography.Title className="flex items-center gap-2">
+                        <AudioLines className="w-4 h-4 text-muted-foreground" />
+                        {t('Speech model')}
+                    </Typography.Title>
+                    <Typography.Paragraph>
+                        {t(
+                            'Whisper covers more languages than Parakeet but is slower and does not use the dictionary during transcription. Import a model folder to add it to the list.'
+                        )}
+                    </Typography.Paragraph>
+                    {activeModel != null && (
+                        <Typography.Paragraph className="text-xs" data-testid="speech-model-details">
+                            {describeModel(activeModel)}
+                            {activeModel.languages.length > 0 &&
+                                ' · ' + t('Languages: {{languages}}', { languages: activeModel.languages.join(', ') })}
+                            {!activeModel.verified && ' · ' + t('Unverified: no checksums to check the files against')}
+                        </Typography.Paragraph>
+                    )}
+                </SettingsUI.Description>
+                <div className="flex items-center gap-2">
+                    <Select value={activeModel?.id ?? ''} onValueChange={setActiveModel}>
+                        <SelectTrigger className="w-[240px]" data-testid="speech-model-select">
+                            <SelectValue placeholder={t('No model installed')} />
+                        </SelectTrigger>
+                        <SelectContent>
+                            {models.map((model) => (
+                                <SelectItem key={model.id} value={model.id}>
+                                    {model.name}
+                                </SelectItem>
+                            ))}
+                        </SelectContent>
+                    </Select>
+                    <Button
+                        variant="outline"
+                        size="icon"
+                        onClick={importModel}
+                        disabled={isImporting}
+                        title={t('Import a model')}
+                        data-testid="import-model-button"
+                    >
+                        <FolderInput className="w-4 h-4" />
+                    </Button>
+                </div>
+            </SettingsUI.Item>
+            {activeModel?.backend === 'whisper' && (
+                <SettingsUI.Item>
+                    <SettingsUI.Description>
+                        <Typography.Title>{t('Spoken language')}</Typography.Title>
+                        <Typography.Paragraph>
+                            {t('Language code such as "de" or "ja". Leave empty to detect it automatically.')}
+                        </Typography.Paragraph>
+                    </SettingsUI.Description>
+                    <Input
+                        type="text"
+                        value={language}
+                        onChange={(e) => setLanguage(e.target.value)}
+                        onBlur={saveLanguage}
+                        placeholder={t('Automatic')}
+                        className="w-[240px]"
+                        data-testid="whisper-language-input"
+                    />
+                </SettingsUI.Item>
+            )}
+        </>
+    );
+};
@@ -0,0 +1,15 @@
+import { AppSettings } from '@/features/settings/settings.types';
+
+export interface ModelInfo {
+    id: string;
+    name: string;
+    backend: AppSettings['asr_backend'];
+    path: string;
+    size_bytes: number;
+    quantization: string;
+    precisions: AppSettings['model_quantization'][];
+    languages: string[];
+    bundled: boolean;
+    verified: boolean;
+    active: boolean;
+}
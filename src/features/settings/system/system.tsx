@@ -5,18 +5,25 @@ import { APISettings } from './api-settings/api-settings';
 import { OverlaySettings } from './overlay-settings/overlay-settings';
 import { StartOnBootSettings } from './start-on-boot-settings/start-on-boot-settings';
 import { DockSettings } from './dock-settings/dock-settings';
-import { isMac } from '@/utils/os';
+import { isLinux, isMac } from '@/utils/os';
 import { CopyToClipboardSettings } from './copy-to-clipboard-settings/copy-to-clipboard-settings';
 import { PasteMethodSettings } from './paste-method-settings/paste-method-settings';
 import { HistorySettings } from './history-settings/history-settings';
 import { LanguageSettings } from './language-settings/language-settings';
 import { SoundSettings } from './sound-settings/sound-settings';
 import { MicSettings } from './mic-settings/mic-settings';
+import { DualCaptureSettings } from './dual-capture-settings/dual-capture-settings';
+import { PreRollSettings } from './pre-roll-settings/pre-roll-settings';
 import { LowerOutputSettings } from './lower-output-settings/lower-output-settings';
 import { useTranslation } from '@/i18n';
 import { RecordModeSettings } from '@/features/settings/system/record-mode-settings/record-mode-settings.tsx';
 import { LogLevelSettings } from './log-level-settings/log-level-settings';
 import { KeepRecordingsSettings } from './keep-recordings-settings/keep-recordings-settings';
+import { DecoderSettings } from './decoder-settings/decoder-settings';
+import { SpeechModelSettings } from './speech-model-settings/speech-model-settings';
+import { InferenceSettings } from './inference-settings/inference-settings';
+import { VoiceDetectionSettings } from './voice-detection-settings/voice-detection-settings';
+import { AudioEnhancementSettings } from './audio-enhancement-settings/audio-enhancement-settings';
 import { Settings, Zap, Wrench, Monitor } from 'lucide-react';
 
 export const System = () => {
@@ -38,6 +45,14 @@ export const System = () => {
                             <SettingsUI.Separator />
                             <MicSettings />
                             <SettingsUI.Separator />
+                            {isLinux && (
+                                <>
+                                    <DualCaptureSettings />
+                                    <SettingsUI.Separator />
+                                </>
+                            )}
+                            <PreRollSettings />
+                            <SettingsUI.Separator />
                             <LowerOutputSettings />
                             <SettingsUI.Separator />
                             <RecordModeSettings />
@@ -70,6 +85,16 @@ export const System = () => {
                         </SettingsUI.Section>
 
                         <SettingsUI.Section title={t('Advanced')} icon={Wrench}>
+                            <SpeechModelSettings />
+                            <SettingsUI.Separator />
+                            <DecoderSettings />
+                            <SettingsUI.Separator />
+                            <InferenceSettings />
+                            <SettingsUI.Separator />
+                            <VoiceDetectionSettings />
+                            <SettingsUI.Separator />
+                            <AudioEnhancementSettings />
+                            <SettingsUI.Separator />
                             <LogLevelSettings />
                             <SettingsUI.Separator />
                             <KeepRecordingsSettings />
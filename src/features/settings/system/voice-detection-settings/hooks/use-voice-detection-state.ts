@@ -0,0 +1,36 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
+import { toast } from 'react-toastify';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+
+type VadProvider = AppSettings['vad_provider'];
+
+export const useVoiceDetectionState = () => {
+    const [provider, setProvider] = useState<VadProvider>('adaptive');
+    const { t } = useTranslation();
+
+    useEffect(() => {
+        invoke<AppSettings>('get_all_settings').then((settings) => {
+            if (settings.vad_provider != null) {
+                setProvider(settings.vad_provider);
+            }
+        });
+    }, []);
+
+    const handleSetProvider = async (next: VadProvider) => {
+        const previous = provider;
+        try {
+            setProvider(next);
+            await invoke('set_vad_provider', { provider: next });
+        } catch (error) {
+            toast.error(t('Failed to save voice detection setting') + ' : ' + error);
+            setProvider(previous);
+        }
+    };
+
+    return {
+        provider,
+        setProvider: handleSetProvider,
+    };
+};
@@ -0,0 +1,45 @@
+import { SettingsUI } from '@/components/settings-ui';
+import { Typography } from '@/components/typography';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/select';
+import { AudioLines } from 'lucide-react';
+import { useTranslation } from '@/i18n';
+import { AppSettings } from '@/features/settings/settings.types';
+import { useVoiceDetectionState } from './hooks/use-voice-detection-state';
+
+const PROVIDERS: { value: AppSettings['vad_provider']; label: string }[] = [
+    { value: 'adaptive', label: 'Volume (lightest)' },
+    { value: 'neural', label: 'Neural (ignores noise)' },
+];
+
+export const VoiceDetectionSettings = () => {
+    const { t } = useTranslation();
+    const { provider, setProvider } = useVoiceDetectionState();
+
+    return (
+        <SettingsUI.Item>
+            <SettingsUI.Description>
+                <Typography.Title className="flex items-center gap-2">
+                    <AudioLines className="w-4 h-4 text-muted-foreground" />
+                    {t('Voice detection')}
+                </Typography.Title>
+                <Typography.Paragraph>
+                    {t(
+                        'Finds pauses to split long dictations, and speech for Voice Mode and the silence timeout. The neural model tells speech from keyboard or ventilation noise, at a small CPU cost.'
+                    )}
+                </Typography.Paragraph>
+            </SettingsUI.Description>
+            <Select value={provider} onValueChange={(value) => setProvider(value as AppSettings['vad_provider'])}>
+                <SelectTrigger className="w-[240px]" data-testid="vad-provider-select">
+                    <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                    {PROVIDERS.map((option) => (
+                        <SelectItem key={option.value} value={option.value}>
+                            {t(option.label)}
+                        </SelectItem>
+                    ))}
+                </SelectContent>
+            </Select>
+        </SettingsUI.Item>
+    );
+};
@@ -13,6 +13,11 @@ import { ModeFlash } from './mode-flash/mode-flash';
 import { CancelButton } from './cancel-button';
 import { useOverlayInputRegion } from './use-overlay-input-region';
 import { useTransformProcessing } from './use-transform-processing';
+import { useModelWarming } from './use-model-warming';
+import { useRecordingPause } from './use-recording-pause';
+import { useTranscriptionAlternatives } from './use-transcription-alternatives';
+import { formatElapsed } from '@/utils/format-elapsed';
+import { i18n } from '@/i18n';
 
 export const Overlay = () => {
     const { overlaySize, overlayPosition, streamingTextSettings } = useOverlayConfig();
@@ -23,6 +28,9 @@ export const Overlay = () => {
     const { promptName } = useLlmPromptFlash();
     const { isProcessing } = useLLMState();
     const { isTransformProcessing } = useTransformProcessing();
+    const { isWarming } = useModelWarming();
+    const { pausedAtMs } = useRecordingPause();
+    const suggestions = useTranscriptionAlternatives();
     const setRoot = useOverlayInputRegion();
     const showPromptName = promptName != null && !hasStreamingText;
 
@@ -83,29 +91,43 @@ export const Overlay = () => {
             </div>
         );
 
+        const label = (text: string) => (
+            <div
+                data-interactive
+                className={clsx(
+                    'max-w-[160px]',
+                    'truncate',
+                    'text-center',
+                    'rounded',
+                    'bg-black',
+                    'px-2',
+                    'py-1',
+                    'text-[10px]',
+                    'font-normal',
+                    'text-white',
+                    'transition-opacity',
+                    'duration-200'
+                )}
+            >
+                {text}
+            </div>
+        );
+
         const textBlock = (() => {
+            if (suggestions != null) {
+                const choices = suggestions
+                    .map(({ word, alternatives }) => `${word} → ${alternatives.join(' / ')}`)
+                    .join(', ');
+                return label(i18n.t('Did you mean {{choices}}?', { choices }));
+            }
+            if (pausedAtMs != null) {
+                return label(i18n.t('Paused {{time}}', { time: formatElapsed(Math.floor(pausedAtMs / 1000)) }));
+            }
             if (showPromptName) {
-                return (
-                    <div
-                        data-interactive
-                        className={clsx(
-                            'max-w-[160px]',
-                            'truncate',
-                            'text-center',
-                            'rounded',
-                            'bg-black',
-                            'px-2',
-                            'py-1',
-                            'text-[10px]',
-                            'font-normal',
-                            'text-white',
-                            'transition-opacity',
-                            'duration-200'
-                        )}
-                    >
-                        {promptName}
-                    </div>
-                );
+                return label(promptName);
+            }
+            if (isWarming && !hasStreamingText) {
+                return label(i18n.t('Warming up...'));
             }
             if (hasStreamingText) {
                 return (
@@ -2,6 +2,7 @@ import type { FrozenSegment, HighlightRange, ProvisionalText } from './use-strea
 
 export enum SegmentTone {
     Frozen = 'frozen',
+    Committed = 'committed',
     Provisional = 'provisional',
 }
 
@@ -59,6 +60,15 @@ interface SegmentUnit {
     keyPrefix: string;
 }
 
+// Highlights of text.slice(start, end), relative to start.
+const sliceHighlights = (highlights: HighlightRange[], start: number, end: number): HighlightRange[] =>
+    highlights
+        .filter((highlight) => highlight.end > start && highlight.start < end)
+        .map((highlight) => ({
+            start: Math.max(highlight.start, start) - start,
+            end: Math.min(highlight.end, end) - start,
+        }));
+
 const needsSeparator = (previousText: string, currentText: string) =>
     !/\s$/.test(previousText) && !currentText.startsWith(' ');
 
@@ -71,12 +81,23 @@ export const buildSegments = (frozenSegments: FrozenSegment[], provisional: Prov
     }));
 
     if (provisional != null && provisional.text.length > 0) {
-        units.push({
-            text: provisional.text,
-            highlights: provisional.highlights,
-            tone: SegmentTone.Provisional,
-            keyPrefix: `p-${provisional.seq}`,
-        });
+        const committedLen = Math.min(provisional.committed_len, provisional.text.length);
+        if (committedLen > 0) {
+            units.push({
+                text: provisional.text.slice(0, committedLen),
+                highlights: sliceHighlights(provisional.highlights, 0, committedLen),
+                tone: SegmentTone.Committed,
+                keyPrefix: `c-${provisional.seq}`,
+            });
+        }
+        if (committedLen < provisional.text.length) {
+            units.push({
+                text: provisional.text.slice(committedLen),
+                highlights: sliceHighlights(provisional.highlights, committedLen, provisional.text.length),
+                tone: SegmentTone.Provisional,
+                keyPrefix: `p-${provisional.seq}`,
+            });
+        }
     }
 
     const segments: TextSegment[] = [];
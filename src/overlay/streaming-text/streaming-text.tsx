@@ -18,6 +18,8 @@ const segmentClassName = (segment: TextSegment) =>
     clsx(
         segment.tone === SegmentTone.Frozen && segment.highlighted && 'text-cyan-400',
         segment.tone === SegmentTone.Frozen && !segment.highlighted && 'text-white',
+        segment.tone === SegmentTone.Committed && segment.highlighted && 'text-cyan-500',
+        segment.tone === SegmentTone.Committed && !segment.highlighted && 'text-neutral-200',
         segment.tone === SegmentTone.Provisional && segment.highlighted && 'text-cyan-700',
         segment.tone === SegmentTone.Provisional && !segment.highlighted && 'text-neutral-400'
     );
@@ -10,7 +10,11 @@ export interface Segment {
 }
 
 export type FrozenSegment = Segment;
-export type ProvisionalText = Segment;
+
+export interface ProvisionalText extends Segment {
+    /** Characters at the start of `text` that no longer change until the segment is frozen. */
+    committed_len: number;
+}
 
 export interface StreamingState {
     frozenSegments: FrozenSegment[];
@@ -44,12 +48,17 @@ export const applyFreeze = (state: StreamingState, payload: Segment): StreamingS
     return { frozenSegments, provisional };
 };
 
-export const applyProvisional = (state: StreamingState, payload: Segment): StreamingState => {
+export const applyProvisional = (state: StreamingState, payload: ProvisionalText): StreamingState => {
     if (payload.seq <= lastFrozenSeq(state.frozenSegments)) {
         return state;
     }
     return {
         frozenSegments: state.frozenSegments,
-        provisional: { seq: payload.seq, text: payload.text, highlights: payload.highlights },
+        provisional: {
+            seq: payload.seq,
+            text: payload.text,
+            highlights: payload.highlights,
+            committed_len: payload.committed_len,
+        },
     };
 };
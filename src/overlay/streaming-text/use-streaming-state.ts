@@ -4,6 +4,7 @@ import {
     applyFreeze,
     applyProvisional,
     EMPTY_STATE,
+    type ProvisionalText,
     type Segment,
     type StreamingState,
 } from './use-streaming-state.helpers';
@@ -17,7 +18,7 @@ export const useStreamingState = () => {
         const unlistenFreeze = listen<Segment>('freeze-segment', (event) => {
             setState((current) => applyFreeze(current, event.payload));
         });
-        const unlistenProvisional = listen<Segment>('preview-provisional', (event) => {
+        const unlistenProvisional = listen<ProvisionalText>('preview-provisional', (event) => {
             setState((current) => applyProvisional(current, event.payload));
         });
         const unlistenReset = listen('recording-mode', () => {
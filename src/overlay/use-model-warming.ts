@@ -0,0 +1,23 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useEffect, useState } from 'react';
+
+export const useModelWarming = () => {
+    const [isWarming, setIsWarming] = useState(false);
+
+    useEffect(() => {
+        invoke<boolean>('is_model_warming')
+            .then(setIsWarming)
+            .catch(() => {});
+
+        const unlistenStart = listen('model-warming-start', () => setIsWarming(true));
+        const unlistenEnd = listen('model-warming-end', () => setIsWarming(false));
+
+        return () => {
+            unlistenStart.then((u) => u()).catch(() => {});
+            unlistenEnd.then((u) => u()).catch(() => {});
+        };
+    }, []);
+
+    return { isWarming };
+};
@@ -0,0 +1,16 @@
+import { listen } from '@tauri-apps/api/event';
+import { useEffect, useState } from 'react';
+
+// Recorded time in ms, pauses excluded, while the recording is paused. Null otherwise.
+export const useRecordingPause = () => {
+    const [pausedAtMs, setPausedAtMs] = useState<number | null>(null);
+
+    useEffect(() => {
+        const unlisten = listen<number | null>('recording-paused', (event) => setPausedAtMs(event.payload));
+        return () => {
+            unlisten.then((u) => u()).catch(() => {});
+        };
+    }, []);
+
+    return { pausedAtMs };
+};
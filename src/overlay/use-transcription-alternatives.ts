@@ -0,0 +1,34 @@
+import { listen } from '@tauri-apps/api/event';
+import { useEffect, useState } from 'react';
+
+const DISMISS_MS = 3000;
+
+// Mirrors the `transcription-alternatives` payload: the hesitant words of the
+// last dictation, each with the spellings the model also considered.
+interface WordAlternatives {
+    word: string;
+    alternatives: string[];
+}
+
+export const useTranscriptionAlternatives = () => {
+    const [suggestions, setSuggestions] = useState<WordAlternatives[] | null>(null);
+
+    useEffect(() => {
+        const unlistenAlternatives = listen<WordAlternatives[]>('transcription-alternatives', (event) =>
+            setSuggestions(event.payload)
+        );
+        const unlistenReset = listen('recording-mode', () => setSuggestions(null));
+        return () => {
+            unlistenAlternatives.then((u) => u());
+            unlistenReset.then((u) => u());
+        };
+    }, []);
+
+    useEffect(() => {
+        if (suggestions == null) return;
+        const timer = setTimeout(() => setSuggestions(null), DISMISS_MS);
+        return () => clearTimeout(timer);
+    }, [suggestions]);
+
+    return suggestions;
+};
@@ -1,7 +1,7 @@
 import { useEffect, useRef, useState } from 'react';
 import type { Mode } from '../smartmic.types';
 import { t } from '../i18n';
-import { formatElapsed } from './rec-area.helpers';
+import { formatElapsed } from '@/utils/format-elapsed';
 
 interface RecAreaProps {
     isRecording: boolean;
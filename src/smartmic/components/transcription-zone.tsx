@@ -7,9 +7,10 @@ const REMOTE_VISIBLE_COUNT = 3;
 
 interface TranscriptionZoneProps {
     transcriptions: TranscriptionEntry[];
+    livePreview: string;
 }
 
-export const TranscriptionZone = ({ transcriptions }: TranscriptionZoneProps) => {
+export const TranscriptionZone = ({ transcriptions, livePreview }: TranscriptionZoneProps) => {
     const [copiedIndex, setCopiedIndex] = useState<number | null>(null);
 
     const copyText = useCallback((text: string, index: number) => {
@@ -26,12 +27,17 @@ export const TranscriptionZone = ({ transcriptions }: TranscriptionZoneProps) =>
 
     return (
         <div className="flex-1 overflow-y-auto border-b border-[#222]">
-            {entries.length === 0 ? (
+            {entries.length === 0 && livePreview === '' ? (
                 <div className="h-full flex items-center justify-center px-3">
                     <span className="text-[#555] italic text-sm">{t('remote.empty')}</span>
                 </div>
             ) : (
                 <div className="p-3 flex flex-col gap-2">
+                    {livePreview !== '' && (
+                        <p className="text-sm p-2 text-[#888] italic" data-testid="live-preview">
+                            {livePreview}
+                        </p>
+                    )}
                     {entries.map((entry, i) => (
                         <button
                             key={`${entry.timestamp}-${i}`}
@@ -42,7 +42,7 @@ export const SmartMic = () => {
             {viewMode === 'remote' && (
                 <>
                     <StatusBar connected={connected} statusText={statusText} pcName={pcName} />
-                    <TranscriptionZone transcriptions={state.transcriptions} />
+                    <TranscriptionZone transcriptions={state.transcriptions} livePreview={state.livePreview} />
                     <div className="h-24 px-3 flex items-center border-b border-[#222] shrink-0">
                         <AudioVisualizer
                             bars={28}
@@ -39,6 +39,7 @@ export type ServerMessage =
       }
     | { type: 'status'; recording: boolean }
     | { type: 'mic_level'; level: number }
+    | { type: 'preview'; seq: number; text: string }
     | { type: 'modes'; modes: string[] }
     | { type: 'error'; message: string }
     | { type: 'device_already_connected'; device_name: string }
@@ -12,6 +12,8 @@ export interface SmartMicState {
     isTranslating: boolean;
     micLevel: number;
     transcriptions: TranscriptionEntry[];
+    /** Committed text of the dictation running on the computer, empty when there is none. */
+    livePreview: string;
     modes: Mode[];
     modeIndex: number;
     error: { title: string; message: string } | null;
@@ -39,6 +41,7 @@ export const initialState: SmartMicState = {
     isTranslating: false,
     micLevel: 0,
     transcriptions: [],
+    livePreview: '',
     modes: DEFAULT_MODES,
     modeIndex: 0,
     error: null,
@@ -66,6 +69,7 @@ export const smartMicReducer = (state: SmartMicState, action: SmartMicAction): S
         case 'disconnected':
             return {
                 ...state,
+                livePreview: '',
                 isRecording: false,
                 micLevel: 0,
                 isTranslating: false,
@@ -128,6 +132,8 @@ const handleServerMessage = (state: SmartMicState, msg: ServerMessage): SmartMic
         }
         case 'mic_level':
             return typeof msg.level === 'number' ? { ...state, micLevel: msg.level } : state;
+        case 'preview':
+            return typeof msg.text === 'string' ? { ...state, livePreview: msg.text } : state;
         case 'modes': {
             if (!Array.isArray(msg.modes)) return state;
             const newModes: Mode[] = [{ id: 'stt', name: 'STT' }];
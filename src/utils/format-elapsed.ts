@@ -1,4 +1,4 @@
-// Helpers specific to rec-area.tsx. Promote to ../helpers/ if a second consumer appears.
+// Shared by the SmartMic record button and the desktop overlay's paused label.
 export const formatElapsed = (sec: number): string => {
     const mm = Math.floor(sec / 60);
     const ss = sec % 60;
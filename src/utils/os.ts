@@ -1 +1,3 @@
 export const isMac = globalThis.window !== undefined && /Mac|iPhone|iPod|iPad/.test(navigator.userAgent);
+export const isLinux =
+    globalThis.window !== undefined && /Linux/.test(navigator.userAgent) && !/Android/.test(navigator.userAgent);